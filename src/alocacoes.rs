@@ -0,0 +1,191 @@
+// ============================================================================
+// ALOCAÇÕES: um alocador global que conta o que cada seção pede à heap
+// ============================================================================
+//
+// Os capítulos de ownership afirmam coisas sobre a heap: `String::from`
+// aloca, `[u8; 5]` e `b"..."` não, `.clone()` copia os dados e um move não.
+// `ContadorDeAlocacoes` deixa o programa conferir isso: ele repassa tudo para
+// o alocador do sistema e, só dentro de `medir`, conta por thread quantas
+// alocações, liberações e bytes passaram por ele.
+//
+// É opt-in: o contador só vale no executável que o declarar como alocador
+// global (o `resolution` declara com a feature `alocacoes`; os testes de
+// `tests/alocacoes.rs` declaram sempre):
+//
+//   #[global_allocator]
+//   static ALOCADOR: ContadorDeAlocacoes = ContadorDeAlocacoes;
+//
+// e os números só aparecem com `run <capítulo> --allocs`:
+//
+//   cargo run --features alocacoes -- run 05 --allocs
+//
+// JS/TS equivalente: não tem. O mais perto é `process.memoryUsage()`, que
+// mostra o total do processo e não quem alocou.
+//
+// ============================================================================
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// O que passou pelo alocador durante uma medição. Uma realocação (ex.: uma
+/// `String` que cresce) conta como uma alocação do bloco novo e uma
+/// liberação do antigo.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Alocacoes {
+    pub alocacoes: u64,
+    pub liberacoes: u64,
+    /// Soma dos tamanhos pedidos (o bloco novo, numa realocação)
+    pub bytes: u64,
+}
+
+thread_local! {
+    /// Se esta thread está medindo agora
+    static MEDINDO: Cell<bool> = const { Cell::new(false) };
+    /// O que esta thread alocou desde o começo da medição
+    static CONTAGEM: Cell<Alocacoes> = const {
+        Cell::new(Alocacoes {
+            alocacoes: 0,
+            liberacoes: 0,
+            bytes: 0,
+        })
+    };
+}
+
+/// Repassa para `System`, contando quando a thread está dentro de `medir`.
+///
+/// As `thread_local!` acima são `const` e sem `Drop`, então consultá-las aqui
+/// dentro nunca aloca (o que faria o alocador chamar a si mesmo).
+pub struct ContadorDeAlocacoes;
+
+fn registrar(alocacoes: u64, liberacoes: u64, bytes: usize) {
+    let medindo = MEDINDO.try_with(Cell::get).unwrap_or(false);
+    if !medindo {
+        return;
+    }
+
+    let _ = CONTAGEM.try_with(|contagem| {
+        let mut atual = contagem.get();
+        atual.alocacoes += alocacoes;
+        atual.liberacoes += liberacoes;
+        atual.bytes += bytes as u64;
+        contagem.set(atual);
+    });
+}
+
+unsafe impl GlobalAlloc for ContadorDeAlocacoes {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        registrar(1, 0, layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        registrar(1, 0, layout.size());
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        registrar(0, 1, 0);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        novo_tamanho: usize,
+    ) -> *mut u8 {
+        registrar(1, 1, novo_tamanho);
+        unsafe { System.realloc(ptr, layout, novo_tamanho) }
+    }
+}
+
+/// Volta a thread ao estado de antes, mesmo se a seção medida entrar em
+/// pânico (o `executor` segue rodando os próximos capítulos): o `MEDINDO`
+/// anterior e, se havia uma medição guardada, a contagem de fora somada ao
+/// que foi contado até aqui.
+struct Restaurar {
+    medindo: bool,
+    antes: Option<Alocacoes>,
+}
+
+impl Drop for Restaurar {
+    fn drop(&mut self) {
+        MEDINDO.with(|medindo| medindo.set(self.medindo));
+        if let Some(antes) = self.antes {
+            CONTAGEM.with(|contagem| {
+                let durante = contagem.get();
+                contagem.set(Alocacoes {
+                    alocacoes: antes.alocacoes + durante.alocacoes,
+                    liberacoes: antes.liberacoes + durante.liberacoes,
+                    bytes: antes.bytes + durante.bytes,
+                });
+            });
+        }
+    }
+}
+
+/// Roda `f` contando as alocações desta thread. Medições aninhadas somam na
+/// de fora (a de dentro só devolve a sua parte).
+pub fn medir<R>(f: impl FnOnce() -> R) -> (R, Alocacoes) {
+    let _restaurar = Restaurar {
+        medindo: MEDINDO.with(|medindo| medindo.replace(true)),
+        antes: Some(
+            CONTAGEM.with(|contagem| contagem.replace(Alocacoes::default())),
+        ),
+    };
+
+    let resultado = f();
+    (resultado, CONTAGEM.with(Cell::get))
+}
+
+/// Roda `f` sem contar nada: é o que a `saida` usa para que o buffer do
+/// `println!` não entre na conta da seção
+pub fn sem_contar<R>(f: impl FnOnce() -> R) -> R {
+    let _restaurar = Restaurar {
+        medindo: MEDINDO.with(|medindo| medindo.replace(false)),
+        antes: None,
+    };
+    f()
+}
+
+/// Se o alocador global deste executável é o `ContadorDeAlocacoes`
+pub fn instalado() -> bool {
+    let (_, contagem) = medir(|| std::hint::black_box(Box::new(0_u8)));
+    contagem.alocacoes > 0
+}
+
+static MOSTRAR: AtomicBool = AtomicBool::new(false);
+
+/// Liga a linha de alocações depois de cada seção (`run --allocs`)
+pub fn mostrar(ligado: bool) {
+    MOSTRAR.store(ligado, Ordering::Relaxed);
+}
+
+pub fn mostrando() -> bool {
+    MOSTRAR.load(Ordering::Relaxed)
+}
+
+/// A linha impressa depois da seção: o que foi medido e, se a seção afirma
+/// alguma coisa sobre a heap, se a afirmação bate
+pub fn relatorio(medido: Alocacoes, esperado: Option<u64>) -> String {
+    let plural = |n: u64, um: &str, varios: &str| {
+        format!("{n} {}", if n == 1 { um } else { varios })
+    };
+    let contagem = format!(
+        "{}, {}, {} bytes",
+        plural(medido.alocacoes, "alocação", "alocações"),
+        plural(medido.liberacoes, "liberação", "liberações"),
+        medido.bytes
+    );
+
+    match esperado {
+        None => format!("🔎 heap: {contagem}"),
+        Some(n) if n == medido.alocacoes => {
+            format!("🔎 heap: {contagem} ✅ (a seção afirma {n})")
+        }
+        Some(n) => format!("🔎 heap: {contagem} ❌ (a seção afirma {n})"),
+    }
+}
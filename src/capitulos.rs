@@ -0,0 +1,212 @@
+// ============================================================================
+// REGISTRO DE CAPÍTULOS
+// ============================================================================
+//
+// Liga cada módulo de estudo ao capítulo correspondente em `docs/`.
+// A ordem de `CAPITULOS` é a ordem de leitura (e a de `run --all`).
+//
+// Alguns capítulos da documentação têm mais de um módulo (ex.: o 05 é
+// `ownership::memoria` + `ownership::o_que_e`), então o número não é único:
+// `run 05` roda os dois, `run ownership::memoria` roda só um.
+//
+// Cada módulo declara as suas seções em `SECOES` (título + função). O número
+// e o cabeçalho "--- N. Título ---" são gerados a partir da posição na lista.
+// Uma seção pode afirmar quantas alocações na heap faz (`.aloca(n)`): com
+// `run --allocs` a afirmação é conferida pelo `alocacoes::ContadorDeAlocacoes`.
+//
+// ============================================================================
+
+use crate::{alocacoes, conceitos_comuns, enums, ownership, structs};
+
+/// Um módulo de estudo e o capítulo da documentação que ele acompanha
+#[derive(Debug)]
+pub struct Capitulo {
+    /// Número do capítulo em `docs/` (7 para `docs/07-slices.md`)
+    pub numero: u8,
+    /// Caminho do módulo a partir da raiz do crate (`ownership::slice`)
+    pub modulo: &'static str,
+    pub titulo: &'static str,
+    /// Arquivo da documentação, relativo à raiz do projeto
+    pub doc: &'static str,
+    /// Seções do módulo, na ordem em que o `main` do capítulo as roda
+    pub secoes: &'static [Secao],
+}
+
+/// Uma seção de um capítulo: o título do cabeçalho e a função de exemplo
+#[derive(Debug)]
+pub struct Secao {
+    pub titulo: &'static str,
+    pub executar: fn(),
+    /// Quantas alocações na heap a seção afirma fazer (`None` = não afirma)
+    pub alocacoes: Option<u64>,
+}
+
+impl Secao {
+    pub const fn new(titulo: &'static str, executar: fn()) -> Self {
+        Self {
+            titulo,
+            executar,
+            alocacoes: None,
+        }
+    }
+
+    /// Afirma que a seção faz exatamente `n` alocações (0 = nenhuma)
+    pub const fn aloca(self, n: u64) -> Self {
+        Self {
+            alocacoes: Some(n),
+            ..self
+        }
+    }
+}
+
+impl Capitulo {
+    /// Número formatado com dois dígitos, igual ao nome do arquivo em `docs/`
+    pub fn id(&self) -> String {
+        format!("{:02}", self.numero)
+    }
+
+    /// Busca uma seção pelo número (começando em 1)
+    pub fn secao(&self, numero: usize) -> Option<&'static Secao> {
+        numero.checked_sub(1).and_then(|i| self.secoes.get(i))
+    }
+}
+
+pub const CAPITULOS: &[Capitulo] = &[
+    Capitulo {
+        numero: 1,
+        modulo: "conceitos_comuns::variaveis_e_mutabilidade",
+        titulo: "Variáveis e mutabilidade",
+        doc: "docs/01-variaveis-e-mutabilidade.md",
+        secoes: conceitos_comuns::variaveis_e_mutabilidade::SECOES,
+    },
+    Capitulo {
+        numero: 2,
+        modulo: "conceitos_comuns::tipos_de_dados",
+        titulo: "Tipos de dados",
+        doc: "docs/02-tipos-de-dados.md",
+        secoes: conceitos_comuns::tipos_de_dados::SECOES,
+    },
+    Capitulo {
+        numero: 3,
+        modulo: "conceitos_comuns::funcoes",
+        titulo: "Funções",
+        doc: "docs/03-funcoes.md",
+        secoes: conceitos_comuns::funcoes::SECOES,
+    },
+    Capitulo {
+        numero: 4,
+        modulo: "conceitos_comuns::controle_de_fluxo",
+        titulo: "Controle de fluxo",
+        doc: "docs/04-controle-de-fluxo.md",
+        secoes: conceitos_comuns::controle_de_fluxo::SECOES,
+    },
+    Capitulo {
+        numero: 5,
+        modulo: "ownership::memoria",
+        titulo: "Ownership - stack, heap e memória estática",
+        doc: "docs/05-ownership.md",
+        secoes: ownership::memoria::SECOES,
+    },
+    Capitulo {
+        numero: 5,
+        modulo: "ownership::o_que_e",
+        titulo: "Ownership - move, copy e clone",
+        doc: "docs/05-ownership.md",
+        secoes: ownership::o_que_e::SECOES,
+    },
+    Capitulo {
+        numero: 6,
+        modulo: "ownership::referencias_e_borrowing",
+        titulo: "Referências e borrowing",
+        doc: "docs/06-referencias-e-borrowing.md",
+        secoes: ownership::referencias_e_borrowing::SECOES,
+    },
+    Capitulo {
+        numero: 7,
+        modulo: "ownership::slice",
+        titulo: "Slices",
+        doc: "docs/07-slices.md",
+        secoes: ownership::slice::SECOES,
+    },
+    Capitulo {
+        numero: 8,
+        modulo: "structs::definindo_structs",
+        titulo: "Structs",
+        doc: "docs/08-structs.md",
+        secoes: structs::definindo_structs::SECOES,
+    },
+    Capitulo {
+        numero: 8,
+        modulo: "structs::exemplo_retangulo",
+        titulo: "Structs - Exemplo Retângulo",
+        doc: "docs/08-structs.md",
+        secoes: structs::exemplo_retangulo::SECOES,
+    },
+    Capitulo {
+        numero: 9,
+        modulo: "structs::metodos",
+        titulo: "Métodos",
+        doc: "docs/09-metodos.md",
+        secoes: structs::metodos::SECOES,
+    },
+    Capitulo {
+        numero: 10,
+        modulo: "enums::definindo_enums",
+        titulo: "Enums",
+        doc: "docs/10-enums.md",
+        secoes: enums::definindo_enums::SECOES,
+    },
+    Capitulo {
+        numero: 11,
+        modulo: "enums::option_enum",
+        titulo: "Option<T>",
+        doc: "docs/11-enum-option.md",
+        secoes: enums::option_enum::SECOES,
+    },
+    Capitulo {
+        numero: 12,
+        modulo: "enums::if_let",
+        titulo: "if let e let...else",
+        doc: "docs/12-if-let.md",
+        secoes: enums::if_let::SECOES,
+    },
+    Capitulo {
+        numero: 13,
+        modulo: "conceitos_comuns::conversoes",
+        titulo: "Conversões entre tipos",
+        doc: "docs/13-conversoes.md",
+        secoes: conceitos_comuns::conversoes::SECOES,
+    },
+];
+
+/// Busca capítulos pelo número (`07` ou `7`) ou pelo caminho do módulo
+/// (`enums::if_let`). Pelo número podem vir vários módulos.
+pub fn buscar(chave: &str) -> Vec<&'static Capitulo> {
+    match chave.parse::<u8>() {
+        Ok(numero) => CAPITULOS.iter().filter(|c| c.numero == numero).collect(),
+        Err(_) => CAPITULOS.iter().filter(|c| c.modulo == chave).collect(),
+    }
+}
+
+/// Roda todas as seções em ordem, cada uma com o seu cabeçalho numerado
+pub fn executar_secoes(secoes: &[Secao]) {
+    for (i, secao) in secoes.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        executar_secao(i + 1, secao);
+    }
+}
+
+/// Imprime o cabeçalho "--- N. Título ---" e roda a seção. Com `--allocs`,
+/// imprime depois o que a seção alocou.
+pub fn executar_secao(numero: usize, secao: &Secao) {
+    println!("--- {}. {} ---", numero, secao.titulo);
+
+    if alocacoes::mostrando() {
+        let ((), medido) = alocacoes::medir(secao.executar);
+        println!("{}", alocacoes::relatorio(medido, secao.alocacoes));
+    } else {
+        (secao.executar)();
+    }
+}
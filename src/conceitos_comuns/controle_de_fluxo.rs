@@ -1,13 +1,19 @@
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("if / else", if_examples),
+    Secao::new("if como expressão", if_expression_result),
+    Secao::new("else if encadeado", else_if_chain),
+    Secao::new("loop com break que retorna valor", loop_with_break_return),
+    Secao::new("Loops aninhados com rótulo", labeled_loops),
+    Secao::new("while: contagem regressiva", while_loop_countdown),
+    Secao::new("while: iterando um array", while_array_iteration),
+    Secao::new("for: iterando um array", for_array_iteration),
+    Secao::new("for + rev: contagem regressiva", for_reverse_countdown),
+];
+
 pub fn main() {
-    if_examples();
-    if_expression_result();
-    else_if_chain();
-    loop_with_break_return();
-    labeled_loops();
-    while_loop_countdown();
-    while_array_iteration();
-    for_array_iteration();
-    for_reverse_countdown();
+    capitulos::executar_secoes(SECOES);
 }
 
 // ====== IF / ELSE simples ======
@@ -28,6 +34,7 @@ fn if_examples() {
     // if number {
     //     println!("Isso dá erro: `number` precisa ser bool!");
     // }
+    // error[E0308]: tests/nao_compila/controle_if_sem_bool.rs
 }
 
 fn if_expression_result() {
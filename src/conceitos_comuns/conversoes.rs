@@ -0,0 +1,181 @@
+// ============================================================================
+// CONVERSÕES ENTRE TIPOS: as, From e TryFrom
+// ============================================================================
+//
+// Rust nunca converte números sozinho: somar um `u8` com um `i32` não
+// compila. A conversão é sempre explícita, e existem três jeitos:
+//
+//   as        → sempre "funciona": trunca, troca o sinal ou satura
+//   From      → só existe quando TODO valor de origem cabe no destino
+//   TryFrom   → existe quando pode não caber, e devolve um `Result`
+//
+// JS/TS equivalente:
+//   Number("42")        // parse de texto (NaN se falhar)
+//   300 & 0xFF          // 44: o que o `as u8` faz
+//   Math.trunc(3.99)    // 3: o que o `as i32` faz com floats
+//
+// Para testar qualquer valor e par de tipos: `cargo run -- convert 300 i32 u8`
+//
+// ============================================================================
+
+use crate::capitulos::{self, Secao};
+use crate::laboratorio::conversoes;
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("as entre inteiros", as_entre_inteiros),
+    Secao::new("as de float para inteiro", as_de_float),
+    Secao::new("From e into (sem perda)", from_sem_perda),
+    Secao::new("TryFrom (pode falhar)", try_from_pode_falhar),
+    Secao::new("Parse de texto", parse_de_texto),
+    Secao::new("A matriz de conversões", matriz),
+];
+
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
+}
+
+// ============================================================================
+// AS ENTRE INTEIROS
+// ============================================================================
+
+fn as_entre_inteiros() {
+    // `as` para um tipo menor guarda só os bits que cabem
+    let x = 300_i32;
+    println!("300_i32 as u8 = {}", x as u8); // 44 (300 - 256)
+
+    // Negativo para sem sinal: os mesmos bits, lidos de outro jeito
+    let y = -1_i32;
+    println!("-1_i32 as u8 = {}", y as u8); // 255
+
+    // E o contrário: 200 não cabe em i8, vira negativo
+    let z = 200_u8;
+    println!("200_u8 as i8 = {}", z as i8); // -56
+
+    // Para um tipo maior é sempre exato
+    println!("200_u8 as u32 = {}", z as u32);
+
+    // ⚠️ Nenhum desses avisa nada: `as` nunca falha
+}
+
+// ============================================================================
+// AS DE FLOAT PARA INTEIRO
+// ============================================================================
+
+fn as_de_float() {
+    // Descarta a parte fracionária (não arredonda)
+    println!("3.99_f64 as i32 = {}", 3.99_f64 as i32); // 3
+
+    // Fora da faixa, satura no limite do tipo
+    println!("-1.5_f64 as u8 = {}", -1.5_f64 as u8); // 0
+    println!("1e10_f64 as i32 = {}", 1e10_f64 as i32); // i32::MAX
+
+    // NaN vira 0 (o mesmo NaN que um `parse` de "NaN" devolve)
+    let nan: f64 = "NaN".parse().unwrap();
+    println!("NaN as i32 = {}", nan as i32);
+
+    // E inteiro para float pode arredondar: f32 só tem 24 bits de mantissa
+    println!("16_777_217_i32 as f32 = {}", 16_777_217_i32 as f32);
+}
+
+// ============================================================================
+// FROM E INTO
+// ============================================================================
+
+fn from_sem_perda() {
+    // `From` só existe quando a conversão nunca perde nada
+    let pequeno: u8 = 200;
+    let grande = u32::from(pequeno);
+    println!("u32::from(200_u8) = {grande}");
+
+    // `.into()` é o mesmo `From`, com o destino vindo do tipo da variável
+    let largo: i64 = (-5_i32).into();
+    println!("(-5_i32).into() como i64 = {largo}");
+
+    // Não é só entre números
+    println!("char::from(65_u8) = {:?}", char::from(65_u8));
+    println!("i32::from(true) = {}", i32::from(true));
+    println!("u32::from('😻') = {}", u32::from('😻'));
+    println!("f64::from(0.1_f32) = {}", f64::from(0.1_f32));
+
+    // ❌ Não compila: nem todo i32 cabe em u8
+    // let n = u8::from(300_i32); // `u8: From<i32>` não existe
+    // error[E0277]: tests/nao_compila/conversoes_from_com_perda.rs
+}
+
+// ============================================================================
+// TRYFROM
+// ============================================================================
+
+fn try_from_pode_falhar() {
+    // Quando pode não caber, a conversão devolve um Result
+    match u8::try_from(300_i32) {
+        Ok(n) => println!("Coube: {n}"),
+        Err(erro) => println!("u8::try_from(300_i32) falhou: {erro}"),
+    }
+
+    // Se o valor cabe, vem `Ok`
+    println!("i8::try_from(-5_i64) = {:?}", i8::try_from(-5_i64));
+
+    // Nem todo número é um caractere: 0xD800 é reservado no Unicode
+    println!(
+        "char::try_from(0xD800_u32) = {:?}",
+        char::try_from(0xD800_u32)
+    );
+
+    // `.try_into()` é o `.into()` que pode falhar
+    let indice: Result<usize, _> = (-1_i32).try_into();
+    println!("(-1_i32).try_into() como usize = {indice:?}");
+}
+
+// ============================================================================
+// PARSE DE TEXTO
+// ============================================================================
+
+fn parse_de_texto() {
+    // Texto não é número: `parse` lê e devolve um Result (como o TryFrom)
+    println!("\"42\".parse::<i8>() = {:?}", "42".parse::<i8>());
+
+    // O tipo de destino decide o que cabe
+    match "300".parse::<i8>() {
+        Ok(n) => println!("Coube: {n}"),
+        Err(erro) => println!("\"300\".parse::<i8>() falhou: {erro}"),
+    }
+
+    // Como no jogo e no `acesso_invalido`: a linha lida vem com o "\n"
+    let linha = String::from("3\n");
+    let indice: usize = linha.trim().parse().expect("não é um número");
+    println!("\"3\\n\".trim().parse::<usize>() = {indice}");
+
+    // Sem o trim, o "\n" faz o parse falhar
+    println!("\"3\\n\".parse::<usize>() = {:?}", linha.parse::<usize>());
+}
+
+// ============================================================================
+// A MATRIZ
+// ============================================================================
+
+fn matriz() {
+    // Quem responde "existe From de A para B?" é o próprio compilador
+    // (veja `laboratorio::conversoes`), para os 256 pares de primitivos
+    let pares = conversoes::matriz();
+    let contar = |f: fn(&conversoes::Par) -> bool| {
+        pares
+            .iter()
+            .filter(|p| p.origem != p.destino && f(p))
+            .count()
+    };
+
+    println!("Pares de tipos diferentes: {}", contar(|_| true));
+    println!("  com From (sem perda):    {}", contar(|p| p.from));
+    println!(
+        "  só com TryFrom:          {}",
+        contar(|p| !p.from && p.try_from)
+    );
+    println!("  com as:                  {}", contar(|p| p.cast));
+    println!(
+        "  sem conversão direta:    {}",
+        contar(|p| !p.cast && !p.from && !p.try_from)
+    );
+
+    println!("\nA matriz completa: cargo run -- convert --matrix");
+}
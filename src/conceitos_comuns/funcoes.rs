@@ -1,6 +1,16 @@
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Funções com e sem parâmetros", chamadas_simples),
+    Secao::new("Funções que retornam valores", chamadas_com_retorno),
+    Secao::new("Blocos como expressão", bloco_como_expressao),
+];
+
 pub fn main() {
-    println!("Início do programa!");
+    capitulos::executar_secoes(SECOES);
+}
 
+fn chamadas_simples() {
     // Chamada de função sem parâmetros e sem retorno
     mensagem_simples();
 
@@ -9,7 +19,9 @@ pub fn main() {
 
     // Chamada de função com múltiplos parâmetros
     imprimir_medida(10, 'm');
+}
 
+fn chamadas_com_retorno() {
     // Chamada de função que retorna um valor
     let cinco = retorna_cinco(); // recebe o valor retornado (5)
     println!("A função 'retorna_cinco' retornou: {cinco}");
@@ -17,7 +29,9 @@ pub fn main() {
     // Usando o valor de retorno de outra função
     let seis = soma_um(5);
     println!("Resultado de 'soma_um(5)': {seis}");
+}
 
+fn bloco_como_expressao() {
     // Bloco de código como expressão (retorna um valor)
     let resultado = {
         let x = 2;
@@ -28,6 +42,7 @@ pub fn main() {
 
     // ERRO se tentássemos colocar ponto e vírgula no bloco acima:
     // let resultado = { let x = 2; x * 3; }; // retornaria `()` e daria erro se esperássemos `i32`
+    // error[E0308]: tests/nao_compila/funcoes_bloco_com_ponto_e_virgula.rs
 }
 
 fn mensagem_simples() {
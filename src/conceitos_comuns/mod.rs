@@ -1,4 +1,5 @@
 pub mod controle_de_fluxo;
+pub mod conversoes;
 pub mod funcoes;
 pub mod tipos_de_dados;
 pub mod variaveis_e_mutabilidade;
@@ -18,6 +18,8 @@
 // | u128   | 128  | 16 B   | Não     | 0 a 340 sextilhões...                     |
 // | usize  | 32/64| 4/8 B  | Não     | Idem acima (usado para indexação)         |
 //
+// Literais: 98_222, 0xff, 0o77, 0b1111_0000, b'A', 57u8 (`cargo run -- base`)
+//
 // ░░ Ponto flutuante ░░
 //
 // | Tipo   | Bits | Bytes | Precisão     | Exemplo     |
@@ -25,6 +27,8 @@
 // | f32    | 32   | 4 B    | 6-7 dígitos   | let x: f32 = 3.14; |
 // | f64    | 64   | 8 B    | 15 dígitos    | let y = 2.718;     |
 //
+// (por que 6-7? `cargo run -- float 3.14 --type f32` mostra os 24 bits)
+//
 // ░░ Booleano ░░
 //
 // | Tipo   | Bits | Valores possíveis |
@@ -40,17 +44,25 @@
 // | char   | 4 B    | Representa 1 caractere Unicode UTF-8 |
 //
 // Exemplo: let c = '😻'; // sempre aspas simples!
+//
+// A mesma tabela, com os intervalos completos (tirados de `MIN`/`MAX`) e o
+// tamanho de `isize`/`usize` nesta máquina: `cargo run -- types`
 // ---------------------------------------------
 
-use std::io;
+use crate::capitulos::{self, Secao};
+use crate::entrada;
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Tipo numérico explícito", tipo_numerico_explicito),
+    Secao::new("Operações básicas", operacoes_basicas),
+    Secao::new("Booleanos e caracteres", tipos_logicos_e_chars),
+    Secao::new("Tuplas", tuplas),
+    Secao::new("Arrays", arrays_validos),
+    Secao::new("Acesso inválido ao array", acesso_invalido), // ⚠️ erro
+];
 
 pub fn main() {
-    tipo_numerico_explicito();
-    operacoes_basicas();
-    tipos_logicos_e_chars();
-    tuplas();
-    arrays_validos();
-    acesso_invalido(); // ⚠️ Demonstrando erro
+    capitulos::executar_secoes(SECOES);
 }
 
 // Exemplo de tipo explícito
@@ -60,6 +72,8 @@ fn tipo_numerico_explicito() {
 }
 
 // Operações matemáticas
+// (e se o resultado não couber? `cargo run -- overflow 200 100 --type u8`)
+// (e o que `56.7 / 32.2` guarda de verdade? `cargo run -- float --surprises`)
 fn operacoes_basicas() {
     let _x = 2.0; // f64 (float padrão)
     let _y: f32 = 3.0; // float de 32 bits
@@ -109,11 +123,10 @@ fn acesso_invalido() {
     let a = [1, 2, 3, 4, 5];
 
     println!("⚠️ Por favor, insira um índice do array:");
-    let mut index = String::new();
 
-    io::stdin()
-        .read_line(&mut index)
-        .expect("Erro ao ler a linha");
+    // Lê da entrada atual (stdin por padrão, veja `crate::entrada`).
+    // Sem ninguém para responder (`--non-interactive`), usa um índice válido.
+    let index = entrada::ler_linha_ou("2");
 
     let index: usize = index
         .trim()
@@ -1,4 +1,18 @@
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Variáveis imutáveis e mutáveis", imutavel_e_mutavel),
+    Secao::new("Declarar agora, dar o valor depois", valor_depois),
+    Secao::new("Constantes", constantes),
+    Secao::new("Shadowing", shadowing),
+    Secao::new("Shadowing para mudar o tipo", shadowing_muda_tipo),
+];
+
 pub fn main() {
+    capitulos::executar_secoes(SECOES);
+}
+
+fn imutavel_e_mutavel() {
     // Declara uma variável imutável (como um `const` em TypeScript)
     let x = 5;
     println!("The value of x is: {x}");
@@ -7,6 +21,7 @@ pub fn main() {
     // Descomentar as linhas abaixo causaria erro de compilação:
     // x = 6;
     // println!("The value of x is: {x}");
+    // error[E0384]: tests/nao_compila/variaveis_reatribuir_imutavel.rs
 
     // Declara uma variável **mutável** com `mut` (como um `let` em TS)
     let mut y = 5;
@@ -15,11 +30,28 @@ pub fn main() {
     // Agora podemos alterar o valor de `y` livremente
     y = 6;
     println!("The value of y is: {y}");
+}
+
+fn valor_depois() {
+    // Dá para declarar sem valor e atribuir depois (uma vez só, sem `mut`)
+    let x: i32;
 
+    // ⚠️ Mas não dá para usar antes da atribuição:
+    // println!("The value of x is: {x}");
+    // error[E0381]: tests/nao_compila/variaveis_usar_sem_valor.rs
+
+    x = 5;
+    println!("The value of x is: {x}");
+}
+
+fn constantes() {
     // Declaração de uma constante
     // Sempre imutável, precisa de anotação de tipo e deve conter uma expressão constante
-    const _THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
+    const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
+    println!("THREE_HOURS_IN_SECONDS = {THREE_HOURS_IN_SECONDS}");
+}
 
+fn shadowing() {
     // Demonstração de shadowing
     let x = 5; // x₁
     println!("x₁ endereço: {:p}", &x);
@@ -38,7 +70,9 @@ pub fn main() {
     // Fora do escopo, x₃ morreu, volta a valer x₂
     println!("O valor de x no escopo externo é: {x}");
     println!("x₂ (ainda válido) endereço: {:p}", &x);
+}
 
+fn shadowing_muda_tipo() {
     // Shadowing para mudar tipo: de &str → usize
     let spaces = "   "; // tipo: &str
 
@@ -48,6 +82,7 @@ pub fn main() {
     // Mutável, mas tentando mudar tipo — causa erro!
     let mut _spaces = "   "; // tipo: &str
     // spaces = _spaces.len();     // ❌ Erro: não pode mudar tipo com `mut`
+    // error[E0308]: tests/nao_compila/variaveis_mut_nao_muda_tipo.rs
     // println!("spaces: {_spaces}");
 
     // O Rust não permite mudar o tipo de uma variável `mut` após sua criação.
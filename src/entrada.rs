@@ -0,0 +1,162 @@
+// ============================================================================
+// ENTRADA: de onde vêm as respostas do usuário
+// ============================================================================
+//
+// Os exemplos interativos (`tipos_de_dados::acesso_invalido`, o jogo de
+// advinhação) não leem `io::stdin()` direto: pedem a próxima linha para a
+// entrada atual da thread, que pode ser:
+//
+//   - `Terminal`: o stdin de verdade (padrão)
+//   - `Roteiro`:  uma lista de respostas prontas (ou lidas de um arquivo)
+//
+// Quando a entrada acaba, cada exemplo usa a sua resposta padrão (ou encerra,
+// no caso do jogo). Um `Roteiro` vazio é o modo `--non-interactive`: todas as
+// perguntas ficam com a resposta padrão e nada bloqueia.
+//
+// ============================================================================
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fs,
+    io::{self, BufRead},
+    path::Path,
+};
+
+/// Uma fonte de linhas digitadas pelo usuário. É `Send` para poder ser lida
+/// numa thread própria (veja `levar`).
+pub trait Entrada: Send {
+    /// Próxima linha, sem o `\n` final. `None` quando a entrada acabou.
+    fn ler_linha(&mut self) -> Option<String>;
+
+    /// Se a resposta deve ser impressa na saída (o terminal já mostra o que
+    /// foi digitado; respostas de um roteiro não aparecem sozinhas)
+    fn ecoar(&self) -> bool {
+        true
+    }
+}
+
+/// Lê do stdin do processo
+pub struct Terminal;
+
+impl Entrada for Terminal {
+    fn ler_linha(&mut self) -> Option<String> {
+        let mut linha = String::new();
+
+        // `read_line` devolve quantos bytes leu: 0 significa fim da entrada
+        // (Ctrl+D, pipe fechado), não uma linha vazia. Só o `\n` sai: o
+        // resto (um `\r` do Windows, espaços) é o que foi digitado, e quem
+        // lê decide o que ignorar.
+        match io::stdin().lock().read_line(&mut linha) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(linha.strip_suffix('\n').unwrap_or(&linha).into()),
+        }
+    }
+
+    fn ecoar(&self) -> bool {
+        false
+    }
+}
+
+/// Respostas prontas, entregues na ordem
+#[derive(Debug, Default)]
+pub struct Roteiro {
+    respostas: VecDeque<String>,
+}
+
+impl Roteiro {
+    pub fn new<I, S>(respostas: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            respostas: respostas.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Uma resposta por linha do arquivo
+    pub fn de_arquivo(caminho: impl AsRef<Path>) -> io::Result<Self> {
+        let conteudo = fs::read_to_string(caminho)?;
+        Ok(Self::new(conteudo.lines()))
+    }
+}
+
+impl Entrada for Roteiro {
+    fn ler_linha(&mut self) -> Option<String> {
+        self.respostas.pop_front()
+    }
+}
+
+impl<E: Entrada + ?Sized> Entrada for Box<E> {
+    fn ler_linha(&mut self) -> Option<String> {
+        (**self).ler_linha()
+    }
+
+    fn ecoar(&self) -> bool {
+        (**self).ecoar()
+    }
+}
+
+thread_local! {
+    static ATUAL: RefCell<Box<dyn Entrada>> = RefCell::new(Box::new(Terminal));
+}
+
+/// Troca a entrada da thread atual
+pub fn usar(entrada: impl Entrada + 'static) {
+    ATUAL.with(|atual| *atual.borrow_mut() = Box::new(entrada));
+}
+
+/// Tira a entrada da thread atual (fica um `Roteiro` vazio), para outra
+/// thread ler com `usar`. O cliente multijogador lê assim: quem espera o
+/// servidor não fica preso esperando o próximo Enter. Quem leva devolve
+/// depois, com `levar` na outra thread e `usar` nesta.
+pub fn levar() -> Box<dyn Entrada> {
+    ATUAL.with(|atual| atual.replace(Box::new(Roteiro::default())))
+}
+
+/// Roda `f` com `entrada` e depois volta para a entrada anterior
+pub fn com_entrada<R>(
+    entrada: impl Entrada + 'static,
+    f: impl FnOnce() -> R,
+) -> R {
+    let _restaurar =
+        Restaurar(Some(ATUAL.with(|atual| atual.replace(Box::new(entrada)))));
+    f()
+}
+
+/// Volta para a entrada guardada quando sai de escopo, mesmo se `f` entrar
+/// em pânico (o `executor` segue rodando os próximos capítulos)
+struct Restaurar(Option<Box<dyn Entrada>>);
+
+impl Drop for Restaurar {
+    fn drop(&mut self) {
+        if let Some(anterior) = self.0.take() {
+            ATUAL.with(|atual| *atual.borrow_mut() = anterior);
+        }
+    }
+}
+
+/// Lê a próxima linha da entrada atual (`None` no fim da entrada)
+pub fn ler_linha() -> Option<String> {
+    ATUAL.with(|atual| {
+        let mut entrada = atual.borrow_mut();
+        let linha = entrada.ler_linha();
+
+        if let Some(linha) = &linha
+            && entrada.ecoar()
+        {
+            println!("> {linha}");
+        }
+
+        linha
+    })
+}
+
+/// Lê a próxima linha ou, se a entrada acabou, usa `padrao`
+pub fn ler_linha_ou(padrao: &str) -> String {
+    ler_linha().unwrap_or_else(|| {
+        println!("> {padrao} (padrão)");
+        padrao.to_string()
+    })
+}
@@ -16,24 +16,22 @@
 // A grande vantagem: cada variante pode ter tipos DIFERENTES de dados!
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. Enum básico ---");
-    enum_basico();
-
-    println!("\n--- 2. Enum como parâmetro ---");
-    enum_como_parametro();
-
-    println!("\n--- 3. Enum com dados (simples) ---");
-    enum_com_dados_simples();
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Enum básico", enum_basico),
+    Secao::new("Enum como parâmetro", enum_como_parametro),
+    Secao::new("Enum com dados (simples)", enum_com_dados_simples),
+    Secao::new(
+        "Enum com dados diferentes por variante",
+        enum_dados_diferentes,
+    ),
+    Secao::new("Enum complexo (tipo Message)", enum_message),
+    Secao::new("Métodos em enums", metodos_em_enums),
+];
 
-    println!("\n--- 4. Enum com dados diferentes por variante ---");
-    enum_dados_diferentes();
-
-    println!("\n--- 5. Enum complexo (tipo Message) ---");
-    enum_message();
-
-    println!("\n--- 6. Métodos em enums ---");
-    metodos_em_enums();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -43,7 +41,7 @@ pub fn main() {
 /// Define as possíveis versões de IP
 /// Cada valor só pode ser V4 OU V6, nunca ambos
 #[derive(Debug)]
-enum IpAddrKind {
+pub(crate) enum IpAddrKind {
     V4,
     V6,
 }
@@ -89,13 +87,13 @@ fn enum_como_parametro() {
 /// Primeira tentativa: struct com enum
 /// Funciona, mas é verboso
 #[derive(Debug)]
-enum IpAddrKindV1 {
+pub(crate) enum IpAddrKindV1 {
     V4,
     V6,
 }
 
 #[derive(Debug)]
-struct IpAddrStruct {
+pub(crate) struct IpAddrStruct {
     kind: IpAddrKindV1,
     address: String,
 }
@@ -125,14 +123,14 @@ fn enum_com_dados_simples() {
 /// Melhor: dados associados diretamente às variantes
 /// Cada variante vira um "construtor" que retorna o tipo do enum
 #[derive(Debug)]
-enum IpAddr {
+pub(crate) enum IpAddr {
     V4(String),
     V6(String),
 }
 
 /// Ainda melhor: cada variante pode ter tipos DIFERENTES!
 #[derive(Debug)]
-enum IpAddrDiff {
+pub(crate) enum IpAddrDiff {
     V4(u8, u8, u8, u8), // 4 números separados
     V6(String),         // uma string
 }
@@ -170,7 +168,7 @@ fn enum_dados_diferentes() {
 /// Enum com variantes de tipos completamente diferentes
 /// Mostra todo o poder dos enums em Rust
 #[derive(Debug)]
-enum Message {
+pub(crate) enum Message {
     Quit,                       // sem dados (como unit struct)
     Move { x: i32, y: i32 },    // campos nomeados (como struct)
     Write(String),              // uma String (como tuple struct)
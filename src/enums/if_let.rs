@@ -15,21 +15,18 @@
 //
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. Match verboso ---");
-    match_verboso();
-
-    println!("\n--- 2. if let ---");
-    if_let_basico();
+use crate::capitulos::{self, Secao};
 
-    println!("\n--- 3. if let com else ---");
-    if_let_com_else();
+pub const SECOES: &[Secao] = &[
+    Secao::new("Match verboso", match_verboso),
+    Secao::new("if let", if_let_basico),
+    Secao::new("if let com else", if_let_com_else),
+    Secao::new("let...else", let_else_basico),
+    Secao::new("Exemplo prático", exemplo_pratico),
+];
 
-    println!("\n--- 4. let...else ---");
-    let_else_basico();
-
-    println!("\n--- 5. Exemplo prático ---");
-    exemplo_pratico();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -93,7 +90,7 @@ fn if_let_basico() {
 // ============================================================================
 
 #[derive(Debug)]
-enum Coin {
+pub(crate) enum Coin {
     Penny,
     Nickel,
     Dime,
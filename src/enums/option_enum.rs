@@ -17,21 +17,18 @@
 // O compilador não deixa você usar um Option<T> como se fosse T.
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. O problema do null ---");
-    problema_do_null();
-
-    println!("\n--- 2. Criando Option ---");
-    criando_option();
+use crate::capitulos::{self, Secao};
 
-    println!("\n--- 3. Option precisa de tratamento ---");
-    option_precisa_tratamento();
+pub const SECOES: &[Secao] = &[
+    Secao::new("O problema do null", problema_do_null),
+    Secao::new("Criando Option", criando_option),
+    Secao::new("Option precisa de tratamento", option_precisa_tratamento),
+    Secao::new("Métodos úteis de Option", metodos_option),
+    Secao::new("Quando usar Option", quando_usar_option),
+];
 
-    println!("\n--- 4. Métodos úteis de Option ---");
-    metodos_option();
-
-    println!("\n--- 5. Quando usar Option ---");
-    quando_usar_option();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -70,8 +67,8 @@ fn criando_option() {
     // Por enquanto: T pode ser qualquer tipo
 
     // Com valor
-    let some_number = Some(5);           // Option<i32>
-    let some_char = Some('e');           // Option<char>
+    let some_number = Some(5); // Option<i32>
+    let some_char = Some('e'); // Option<char>
     let some_string = Some(String::from("olá")); // Option<String>
 
     println!("some_number: {:?}", some_number);
@@ -109,6 +106,8 @@ fn option_precisa_tratamento() {
     //  |
     //  | let sum = x + y;
     //  |             ^ no implementation for `i8 + Option<i8>`
+    //
+    // error[E0277]: tests/nao_compila/option_somar_com_option.rs
 
     println!("x = {}", x);
     println!("y = {:?}", y);
@@ -183,7 +182,7 @@ fn metodos_option() {
 // ============================================================================
 
 #[derive(Debug)]
-struct User {
+pub(crate) struct User {
     name: String,
     email: String,
     age: Option<u32>, // idade é opcional
@@ -211,11 +210,7 @@ fn find_user(name: &str) -> Option<User> {
 
 /// Busca primeiro elemento de um vetor (pode estar vazio)
 fn first_element(vec: &[i32]) -> Option<&i32> {
-    if vec.is_empty() {
-        None
-    } else {
-        Some(&vec[0])
-    }
+    if vec.is_empty() { None } else { Some(&vec[0]) }
     // Nota: Vec já tem o método .first() que faz exatamente isso!
 }
 
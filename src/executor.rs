@@ -0,0 +1,165 @@
+// ============================================================================
+// EXECUTOR: roda capítulos isolados e resume o que aconteceu
+// ============================================================================
+//
+// Alguns exemplos entram em pânico de propósito (ex.: `acesso_invalido` com
+// um índice fora do array). Cada capítulo roda dentro de `catch_unwind`, então
+// um pânico encerra só aquele capítulo: os próximos continuam rodando e, no
+// fim, o resumo mostra quem passou, quem entrou em pânico (com mensagem e
+// local) e quanto tempo cada um levou.
+//
+// ============================================================================
+
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+use crate::capitulos::{self, Capitulo};
+
+/// Como terminou a execução de um capítulo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resultado {
+    Passou,
+    Panico { mensagem: String, local: String },
+}
+
+/// O resultado de um capítulo e o tempo que ele levou
+#[derive(Debug)]
+pub struct Execucao {
+    pub capitulo: &'static Capitulo,
+    pub resultado: Resultado,
+    pub duracao: Duration,
+}
+
+impl Execucao {
+    pub fn passou(&self) -> bool {
+        self.resultado == Resultado::Passou
+    }
+}
+
+thread_local! {
+    /// Mensagem e local do último pânico desta thread, guardados pelo hook
+    static ULTIMO_PANICO: RefCell<Option<(String, String)>> =
+        const { RefCell::new(None) };
+}
+
+/// Instala (uma vez só) um hook que guarda mensagem e local do pânico antes
+/// de imprimir a mensagem padrão no stderr
+fn instalar_hook() {
+    static INSTALAR: Once = Once::new();
+
+    INSTALAR.call_once(|| {
+        let padrao = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            let mensagem = info
+                .payload_as_str()
+                .unwrap_or("<pânico sem mensagem>")
+                .to_string();
+            let local = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+                .unwrap_or_else(|| "<local desconhecido>".to_string());
+
+            ULTIMO_PANICO.with(|ultimo| {
+                *ultimo.borrow_mut() = Some((mensagem, local));
+            });
+            padrao(info);
+        }));
+    });
+}
+
+/// Roda `f`, transformando um pânico em `Resultado::Panico`
+pub fn isolar(f: impl FnOnce()) -> (Resultado, Duration) {
+    instalar_hook();
+
+    let inicio = Instant::now();
+    let retorno = panic::catch_unwind(AssertUnwindSafe(f));
+    let duracao = inicio.elapsed();
+
+    let resultado = match retorno {
+        Ok(()) => Resultado::Passou,
+        Err(_) => {
+            let (mensagem, local) = ULTIMO_PANICO
+                .with(|ultimo| ultimo.borrow_mut().take())
+                .unwrap_or_else(|| {
+                    ("<pânico sem mensagem>".into(), "<desconhecido>".into())
+                });
+            Resultado::Panico { mensagem, local }
+        }
+    };
+
+    (resultado, duracao)
+}
+
+/// Imprime o cabeçalho do capítulo e roda todas as seções, isolado
+pub fn rodar(capitulo: &'static Capitulo) -> Execucao {
+    println!("\n{}. {}\n", capitulo.id(), capitulo.titulo);
+
+    let (resultado, duracao) =
+        isolar(|| capitulos::executar_secoes(capitulo.secoes));
+
+    if let Resultado::Panico { mensagem, .. } = &resultado {
+        println!("\n💥 {} entrou em pânico: {mensagem}", capitulo.modulo);
+    }
+
+    Execucao {
+        capitulo,
+        resultado,
+        duracao,
+    }
+}
+
+/// Roda todos os capítulos, mesmo que algum entre em pânico
+pub fn rodar_todos(
+    capitulos: impl IntoIterator<Item = &'static Capitulo>,
+) -> Vec<Execucao> {
+    capitulos.into_iter().map(rodar).collect()
+}
+
+/// Imprime a tabela final: um capítulo por linha e os totais
+pub fn imprimir_resumo(execucoes: &[Execucao]) {
+    println!("\n=== Resumo ===");
+    println!(
+        "{:<3} {:<45} {:<9} {:>10}",
+        "#", "Módulo", "Resultado", "Tempo"
+    );
+    println!("{}", "-".repeat(70));
+
+    for execucao in execucoes {
+        let capitulo = execucao.capitulo;
+        let estado = if execucao.passou() { "ok" } else { "PÂNICO" };
+
+        println!(
+            "{:<3} {:<45} {:<9} {:>10}",
+            capitulo.id(),
+            capitulo.modulo,
+            estado,
+            formatar_duracao(execucao.duracao)
+        );
+
+        if let Resultado::Panico { mensagem, local } = &execucao.resultado {
+            println!("    ↳ {mensagem}");
+            println!("      em {local}");
+        }
+    }
+
+    let passaram = execucoes.iter().filter(|e| e.passou()).count();
+    let total: Duration = execucoes.iter().map(|e| e.duracao).sum();
+
+    println!("{}", "-".repeat(70));
+    println!(
+        "{} rodados, {} passaram, {} em pânico, {} no total",
+        execucoes.len(),
+        passaram,
+        execucoes.len() - passaram,
+        formatar_duracao(total)
+    );
+}
+
+fn formatar_duracao(duracao: Duration) -> String {
+    format!("{:.2} ms", duracao.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,159 @@
+// Estratégias de chute para o jogo de advinhação.
+//
+// Todas jogam contra a mesma informação que o jogador humano recebe: a
+// comparação `chute.cmp(&secreto)` (`Less`, `Greater` ou `Equal`), a mesma
+// `Ordering` usada pelo motor. Cada estratégia guarda a faixa que ainda é
+// possível e decide o próximo chute dentro dela.
+use std::cmp::Ordering;
+
+use rand::{RngExt, rngs::StdRng};
+
+/// Um jeito de escolher o próximo chute
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    /// Começa uma partida nova na faixa `min..=max`
+    fn reset(&mut self, min: i64, max: i64);
+
+    /// Próximo chute. `rng` é para estratégias que sorteiam.
+    fn next_guess(&mut self, rng: &mut StdRng) -> i64;
+
+    /// Resultado do último chute: `chute.cmp(&secreto)`
+    fn observe(&mut self, guess: i64, ordering: Ordering);
+}
+
+/// Faixa ainda possível, atualizada pela comparação de cada chute
+#[derive(Debug, Clone, Copy, Default)]
+struct Faixa {
+    min: i64,
+    max: i64,
+}
+
+impl Faixa {
+    fn observar(&mut self, guess: i64, ordering: Ordering) {
+        match ordering {
+            Ordering::Less => self.min = self.min.max(guess + 1), // chute < secreto
+            Ordering::Greater => self.max = self.max.min(guess - 1), // chute > secreto
+            Ordering::Equal => {}
+        }
+    }
+
+    /// Ponto a `por_mil`/1000 do caminho entre `min` e `max`, arredondado.
+    /// A conta é em `i128` (e não em `f64`): numa faixa larga o `f64` perde
+    /// precisão e o chute podia cair fora dela.
+    fn ponto(&self, por_mil: i128) -> i64 {
+        let (min, max) = (i128::from(self.min), i128::from(self.max));
+        let ponto = min + ((max - min) * por_mil + 500) / 1000;
+        ponto.clamp(min, max) as i64
+    }
+}
+
+/// Sempre o meio da faixa: o pior caso é ⌈log₂(n + 1)⌉ chutes
+#[derive(Debug, Default)]
+pub struct BinarySearch(Faixa);
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &'static str {
+        "busca binária"
+    }
+
+    fn reset(&mut self, min: i64, max: i64) {
+        self.0 = Faixa { min, max };
+    }
+
+    fn next_guess(&mut self, _rng: &mut StdRng) -> i64 {
+        self.0.ponto(500)
+    }
+
+    fn observe(&mut self, guess: i64, ordering: Ordering) {
+        self.0.observar(guess, ordering);
+    }
+}
+
+/// Um número qualquer da faixa ainda possível
+#[derive(Debug, Default)]
+pub struct Random(Faixa);
+
+impl Strategy for Random {
+    fn name(&self) -> &'static str {
+        "aleatória"
+    }
+
+    fn reset(&mut self, min: i64, max: i64) {
+        self.0 = Faixa { min, max };
+    }
+
+    fn next_guess(&mut self, rng: &mut StdRng) -> i64 {
+        rng.random_range(self.0.min..=self.0.max)
+    }
+
+    fn observe(&mut self, guess: i64, ordering: Ordering) {
+        self.0.observar(guess, ordering);
+    }
+}
+
+/// Conta a partir do menor número, um por um
+#[derive(Debug, Default)]
+pub struct Linear(Faixa);
+
+impl Strategy for Linear {
+    fn name(&self) -> &'static str {
+        "linear"
+    }
+
+    fn reset(&mut self, min: i64, max: i64) {
+        self.0 = Faixa { min, max };
+    }
+
+    fn next_guess(&mut self, _rng: &mut StdRng) -> i64 {
+        self.0.min
+    }
+
+    fn observe(&mut self, guess: i64, ordering: Ordering) {
+        self.0.observar(guess, ordering);
+    }
+}
+
+/// Parecida com uma pessoa: mira "mais ou menos no meio", com folga, e
+/// prefere números redondos (múltiplos de 5) quando dá
+#[derive(Debug, Default)]
+pub struct HumanLike(Faixa);
+
+impl Strategy for HumanLike {
+    fn name(&self) -> &'static str {
+        "humana"
+    }
+
+    fn reset(&mut self, min: i64, max: i64) {
+        self.0 = Faixa { min, max };
+    }
+
+    fn next_guess(&mut self, rng: &mut StdRng) -> i64 {
+        let Faixa { min, max } = self.0;
+        let chute = i128::from(self.0.ponto(rng.random_range(300..=700)));
+
+        // O múltiplo de 5 mais perto, em `i128` para não estourar perto de
+        // `i64::MAX`, e de volta para dentro da faixa
+        let resto = chute.rem_euclid(5);
+        let redondo = if resto <= 2 {
+            chute - resto
+        } else {
+            chute + 5 - resto
+        };
+        redondo.clamp(i128::from(min), i128::from(max)) as i64
+    }
+
+    fn observe(&mut self, guess: i64, ordering: Ordering) {
+        self.0.observar(guess, ordering);
+    }
+}
+
+/// Uma instância de cada estratégia, na ordem da comparação
+pub fn todas() -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(BinarySearch::default()),
+        Box::new(HumanLike::default()),
+        Box::new(Random::default()),
+        Box::new(Linear::default()),
+    ]
+}
@@ -0,0 +1,269 @@
+// Forca: advinhar uma palavra escondida, letra por letra.
+//
+// É o capítulo de slices na prática: uma `String` em UTF-8 guarda `ç`, `ã` e
+// `é` em 2 bytes cada, então a palavra é sempre percorrida com `.chars()` (uma
+// letra por vez), nunca por índice de byte: `&palavra[0..1]` em "ótimo"
+// cortaria o `ó` no meio e entraria em pânico.
+//
+// Acentos não precisam ser digitados: chutar `a` revela `a`, `á`, `ã`...
+// e `c` revela `ç`, como na forca de papel.
+//
+// Como no jogo de números, o motor não faz I/O: o terminal lê os chutes da
+// entrada atual e a pontuação vai para o mesmo placar.
+
+use std::{fmt, fs, path::Path};
+
+use rand::{RngExt, SeedableRng, rngs::StdRng};
+
+use super::State;
+
+/// Erros permitidos antes de perder
+pub const MAX_ERROS_PADRAO: u32 = 6;
+
+/// Lista usada quando nenhum arquivo é informado
+pub const PALAVRAS_PADRAO: &str = include_str!("palavras.txt");
+
+/// Uma palavra por linha; linhas vazias e começando com `#` são ignoradas,
+/// assim como linhas sem nenhuma letra
+pub fn ler_palavras(texto: &str) -> Vec<String> {
+    texto
+        .lines()
+        .map(str::trim)
+        .filter(|linha| !linha.starts_with('#'))
+        .filter(|linha| linha.chars().any(char::is_alphabetic))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+pub fn carregar_palavras(caminho: &Path) -> Result<Vec<String>, String> {
+    let texto = fs::read_to_string(caminho)
+        .map_err(|e| format!("não consegui ler {}: {e}", caminho.display()))?;
+    Ok(ler_palavras(&texto))
+}
+
+/// A letra sem acento e em minúscula: `Ã` → `a`, `ç` → `c`
+pub fn sem_acento(letra: char) -> char {
+    match letra.to_lowercase().next().unwrap_or(letra) {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        outra => outra,
+    }
+}
+
+/// Resultado de um chute que contou
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// A letra aparece na palavra: quantas posições foram reveladas
+    Hit(usize),
+    /// Letra (ou palavra inteira) errada: gastou um erro
+    Miss,
+}
+
+/// Chutes que o motor recusa (e que não gastam erro)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HangmanError {
+    NotALetter,
+    AlreadyTried(char),
+    GameOver,
+}
+
+impl fmt::Display for HangmanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HangmanError::NotALetter => write!(f, "isso não é uma letra"),
+            HangmanError::AlreadyTried(letra) => {
+                write!(f, "a letra '{letra}' já foi chutada")
+            }
+            HangmanError::GameOver => write!(f, "a partida já terminou"),
+        }
+    }
+}
+
+/// As regras da forca, sem entrada nem saída
+#[derive(Debug)]
+pub struct HangmanGame {
+    word: String,
+    seed: u64,
+    /// Letras chutadas, já sem acento, na ordem
+    tried: Vec<char>,
+    misses: u32,
+    max_misses: u32,
+    attempts: u32,
+    state: State,
+}
+
+impl HangmanGame {
+    /// Partida com uma palavra sorteada da lista
+    pub fn new(words: &[String], max_misses: u32) -> Result<Self, String> {
+        Self::with_seed(words, max_misses, rand::rng().random())
+    }
+
+    /// Partida determinística: a mesma semente sorteia a mesma palavra
+    pub fn with_seed(
+        words: &[String],
+        max_misses: u32,
+        seed: u64,
+    ) -> Result<Self, String> {
+        if words.is_empty() {
+            return Err("a lista de palavras está vazia".to_string());
+        }
+        if max_misses == 0 {
+            return Err("o limite de erros precisa ser maior que 0".into());
+        }
+
+        let i = StdRng::seed_from_u64(seed).random_range(0..words.len());
+        Ok(Self {
+            word: words[i].clone(),
+            seed,
+            tried: Vec::new(),
+            misses: 0,
+            max_misses,
+            attempts: 0,
+            state: State::Playing,
+        })
+    }
+
+    /// Uma letra, ou a palavra inteira se tiver mais de uma letra
+    pub fn guess(&mut self, input: &str) -> Result<Outcome, HangmanError> {
+        let mut letras = input.chars();
+        match (letras.next(), letras.next()) {
+            (Some(letra), None) => self.guess_letter(letra),
+            _ => self.guess_word(input),
+        }
+    }
+
+    pub fn guess_letter(
+        &mut self,
+        letra: char,
+    ) -> Result<Outcome, HangmanError> {
+        if self.state != State::Playing {
+            return Err(HangmanError::GameOver);
+        }
+        if !letra.is_alphabetic() {
+            return Err(HangmanError::NotALetter);
+        }
+
+        let letra = sem_acento(letra);
+        if self.tried.contains(&letra) {
+            return Err(HangmanError::AlreadyTried(letra));
+        }
+
+        self.tried.push(letra);
+        self.attempts += 1;
+
+        let acertos = self
+            .word
+            .chars()
+            .filter(|&c| sem_acento(c) == letra)
+            .count();
+        Ok(self.contar(acertos))
+    }
+
+    /// A palavra inteira: acertar revela tudo, errar gasta um erro
+    pub fn guess_word(
+        &mut self,
+        palpite: &str,
+    ) -> Result<Outcome, HangmanError> {
+        if self.state != State::Playing {
+            return Err(HangmanError::GameOver);
+        }
+        if !palpite.chars().any(char::is_alphabetic) {
+            return Err(HangmanError::NotALetter);
+        }
+
+        self.attempts += 1;
+
+        let normalizar = |texto: &str| {
+            texto.trim().chars().map(sem_acento).collect::<String>()
+        };
+        if normalizar(palpite) != normalizar(&self.word) {
+            return Ok(self.contar(0));
+        }
+
+        let escondidas = self.hidden().count();
+        for letra in self.word.chars().filter(|c| c.is_alphabetic()) {
+            if !self.tried.contains(&sem_acento(letra)) {
+                self.tried.push(sem_acento(letra));
+            }
+        }
+        Ok(self.contar(escondidas))
+    }
+
+    /// Atualiza erros e estado depois de um chute com `acertos` posições
+    fn contar(&mut self, acertos: usize) -> Outcome {
+        if acertos == 0 {
+            self.misses += 1;
+            if self.misses >= self.max_misses {
+                self.state = State::Lost;
+            }
+            return Outcome::Miss;
+        }
+
+        if self.hidden().next().is_none() {
+            self.state = State::Won;
+        }
+        Outcome::Hit(acertos)
+    }
+
+    /// Letras da palavra que ainda não foram reveladas
+    fn hidden(&self) -> impl Iterator<Item = char> + '_ {
+        self.word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .filter(|&c| !self.tried.contains(&sem_acento(c)))
+    }
+
+    /// A palavra com `_` no lugar das letras escondidas: `c a f _`.
+    /// Espaços e hífens aparecem desde o começo.
+    pub fn mask(&self) -> String {
+        self.word
+            .chars()
+            .map(|c| {
+                if c.is_alphabetic() && !self.tried.contains(&sem_acento(c)) {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// A palavra secreta (para mostrar no fim da partida)
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn tried(&self) -> &[char] {
+        &self.tried
+    }
+
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+
+    pub fn remaining_misses(&self) -> u32 {
+        self.max_misses - self.misses
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.state == State::Won
+    }
+}
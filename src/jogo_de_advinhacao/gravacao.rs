@@ -0,0 +1,275 @@
+// Gravação de partidas: a semente, a configuração e cada linha digitada,
+// com o momento em que chegou e o que o jogo respondeu. Como o secreto só
+// depende da semente, dá para jogar a partida de novo linha por linha e
+// conferir se o jogo reage igual (útil para reproduzir bugs de entrada).
+//
+// O arquivo é texto, com os campos separados por TAB:
+//
+//   seed        <semente>
+//   faixa       <min>  <max>
+//   tentativas  <limite>            (0 = sem limite)
+//   <ms>        <retorno>  <linha>  (uma linha por evento)
+//   <ms>        fim                 (a entrada acabou)
+//
+// Linhas começando com `#` são comentários. A linha digitada vai como
+// chegou, espaços incluídos; só `\`, TAB, `\r` e `\n` viram `\\`, `\t`,
+// `\r` e `\n`, para o arquivo continuar com um evento por linha.
+
+use std::{fmt, fs, io, path::Path, str::FromStr, time::Instant};
+
+use super::{Config, GuessingGame, Hint};
+use crate::entrada::Roteiro;
+
+/// O que o jogo fez com uma linha digitada
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retorno {
+    /// O chute contou e recebeu uma dica
+    Dica(Hint),
+    /// A linha não era um número inteiro
+    Invalida,
+    /// O motor recusou o chute (fora da faixa, partida encerrada)
+    Recusada,
+    /// A entrada acabou (Ctrl+D, fim do arquivo)
+    Fim,
+}
+
+impl Retorno {
+    fn nome(self) -> &'static str {
+        match self {
+            Retorno::Dica(Hint::Higher) => "maior",
+            Retorno::Dica(Hint::Lower) => "menor",
+            Retorno::Dica(Hint::Correct) => "acertou",
+            Retorno::Invalida => "invalida",
+            Retorno::Recusada => "recusada",
+            Retorno::Fim => "fim",
+        }
+    }
+}
+
+impl fmt::Display for Retorno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.nome())
+    }
+}
+
+impl FromStr for Retorno {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Retorno::Dica(Hint::Higher),
+            Retorno::Dica(Hint::Lower),
+            Retorno::Dica(Hint::Correct),
+            Retorno::Invalida,
+            Retorno::Recusada,
+            Retorno::Fim,
+        ]
+        .into_iter()
+        .find(|r| r.nome() == s)
+        .ok_or_else(|| format!("retorno desconhecido: {s}"))
+    }
+}
+
+/// Uma linha digitada (ou o fim da entrada) e a reação do jogo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evento {
+    /// Milissegundos desde o começo da partida
+    pub ms: u64,
+    /// `None` quando a entrada acabou
+    pub linha: Option<String>,
+    pub retorno: Retorno,
+}
+
+impl Evento {
+    /// Mesma linha e mesma reação (o tempo não importa)
+    pub fn equivale(&self, outro: &Evento) -> bool {
+        self.linha == outro.linha && self.retorno == outro.retorno
+    }
+}
+
+impl fmt::Display for Evento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.linha {
+            Some(linha) => write!(f, "'{linha}' → {}", self.retorno),
+            None => write!(f, "fim da entrada"),
+        }
+    }
+}
+
+/// Uma partida gravada
+#[derive(Debug)]
+pub struct Gravacao {
+    pub seed: u64,
+    pub config: Config,
+    pub eventos: Vec<Evento>,
+    inicio: Instant,
+}
+
+impl Gravacao {
+    /// Começa a gravar a partida (o relógio começa agora)
+    pub fn new(game: &GuessingGame) -> Self {
+        Self {
+            seed: game.seed(),
+            config: *game.config(),
+            eventos: Vec::new(),
+            inicio: Instant::now(),
+        }
+    }
+
+    pub fn registrar(&mut self, linha: Option<&str>, retorno: Retorno) {
+        self.eventos.push(Evento {
+            ms: self.inicio.elapsed().as_millis() as u64,
+            linha: linha.map(str::to_string),
+            retorno,
+        });
+    }
+
+    /// Uma partida nova com a mesma semente e configuração
+    pub fn game(&self) -> GuessingGame {
+        GuessingGame::with_seed(self.config, self.seed)
+    }
+
+    /// As linhas digitadas, na ordem, para alimentar `entrada::com_entrada`.
+    /// O roteiro acaba onde a entrada original acabou.
+    pub fn roteiro(&self) -> Roteiro {
+        Roteiro::new(self.eventos.iter().filter_map(|e| e.linha.clone()))
+    }
+
+    /// Índice do primeiro evento em que `outra` difere desta gravação
+    pub fn primeira_divergencia(&self, outra: &Gravacao) -> Option<usize> {
+        let n = self.eventos.len().max(outra.eventos.len());
+        (0..n).find(|&i| match (self.eventos.get(i), outra.eventos.get(i)) {
+            (Some(a), Some(b)) => !a.equivale(b),
+            _ => true,
+        })
+    }
+
+    pub fn salvar(&self, caminho: &Path) -> io::Result<()> {
+        let mut texto =
+            String::from("# partida gravada do jogo de advinhação\n");
+        texto += &format!("seed\t{}\n", self.seed);
+        texto += &format!("faixa\t{}\t{}\n", self.config.min, self.config.max);
+        texto +=
+            &format!("tentativas\t{}\n", self.config.max_attempts.unwrap_or(0));
+
+        for evento in &self.eventos {
+            texto += &format!("{}\t{}", evento.ms, evento.retorno);
+            if let Some(linha) = &evento.linha {
+                texto += &format!("\t{}", escapar(linha));
+            }
+            texto.push('\n');
+        }
+
+        fs::write(caminho, texto)
+    }
+
+    pub fn carregar(caminho: &Path) -> Result<Self, String> {
+        let texto = fs::read_to_string(caminho).map_err(|e| {
+            format!("não consegui ler {}: {e}", caminho.display())
+        })?;
+
+        let mut seed = None;
+        let mut faixa = None;
+        let mut tentativas = None;
+        let mut eventos = Vec::new();
+
+        // Um `\r` digitado está escapado: o que `lines()` tira é só o
+        // `\r\n` de um arquivo editado no Windows
+        for (i, linha) in texto.lines().enumerate() {
+            if linha.is_empty() || linha.starts_with('#') {
+                continue;
+            }
+
+            let invalida = || {
+                format!(
+                    "{}:{}: linha inválida: {linha}",
+                    caminho.display(),
+                    i + 1
+                )
+            };
+            let campos: Vec<&str> = linha.splitn(3, '\t').collect();
+
+            match campos[..] {
+                ["seed", valor] => {
+                    seed = Some(valor.parse().map_err(|_| invalida())?)
+                }
+                ["faixa", min, max] => {
+                    let min = min.parse().map_err(|_| invalida())?;
+                    let max = max.parse().map_err(|_| invalida())?;
+                    faixa = Some((min, max));
+                }
+                ["tentativas", valor] => {
+                    let n: u32 = valor.parse().map_err(|_| invalida())?;
+                    tentativas = Some((n > 0).then_some(n));
+                }
+                [ms, retorno, ..] => {
+                    let retorno: Retorno =
+                        retorno.parse().map_err(|_| invalida())?;
+                    let linha = match campos.get(2) {
+                        Some(linha) => {
+                            Some(desescapar(linha).ok_or_else(invalida)?)
+                        }
+                        None => None,
+                    };
+                    if linha.is_none() != (retorno == Retorno::Fim) {
+                        return Err(invalida());
+                    }
+                    eventos.push(Evento {
+                        ms: ms.parse().map_err(|_| invalida())?,
+                        linha,
+                        retorno,
+                    });
+                }
+                _ => return Err(invalida()),
+            }
+        }
+
+        let faltando =
+            |campo| format!("{}: falta `{campo}`", caminho.display());
+        let seed = seed.ok_or_else(|| faltando("seed"))?;
+        let (min, max) = faixa.ok_or_else(|| faltando("faixa"))?;
+        let tentativas = tentativas.ok_or_else(|| faltando("tentativas"))?;
+
+        Ok(Self {
+            seed,
+            config: Config::new(min, max, tentativas)?,
+            eventos,
+            inicio: Instant::now(),
+        })
+    }
+}
+
+/// `\`, TAB, `\r` e `\n` como `\\`, `\t`, `\r` e `\n`
+fn escapar(linha: &str) -> String {
+    let mut texto = String::with_capacity(linha.len());
+    for c in linha.chars() {
+        match c {
+            '\\' => texto += "\\\\",
+            '\t' => texto += "\\t",
+            '\r' => texto += "\\r",
+            '\n' => texto += "\\n",
+            c => texto.push(c),
+        }
+    }
+    texto
+}
+
+/// O contrário de `escapar`; `None` se sobrar um `\` sem par
+fn desescapar(texto: &str) -> Option<String> {
+    let mut linha = String::with_capacity(texto.len());
+    let mut chars = texto.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            linha.push(c);
+            continue;
+        }
+        linha.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'r' => '\r',
+            'n' => '\n',
+            _ => return None,
+        });
+    }
+    Some(linha)
+}
@@ -0,0 +1,38 @@
+// ============================================================================
+// JOGO DE ADVINHAÇÃO
+// ============================================================================
+//
+// O jogo do capítulo 2 do livro, separado em partes:
+//
+//   - `motor`:    as regras (número secreto, comparação, tentativas), sem I/O
+//   - `terminal`: lê os chutes da entrada e imprime as dicas
+//   - `gravacao`: grava uma partida num arquivo para jogá-la de novo
+//   - `placar`:   as partidas vencidas, guardadas num arquivo local
+//   - `reverso`:  o contrário: o computador advinha o número do jogador
+//   - `forca`:    outro jogo, advinhar uma palavra letra por letra
+//   - `multijogador`: vários jogadores disputando o mesmo número via TCP
+//   - `estrategias` e `simulacao`: jeitos de chutar, comparados em lote
+//
+// O motor não sabe de onde vêm os chutes, então pode ser testado com uma
+// semente fixa e reaproveitado por outras interfaces.
+//
+// ============================================================================
+
+pub mod estrategias;
+pub mod forca;
+pub mod gravacao;
+pub mod motor;
+pub mod multijogador;
+pub mod placar;
+pub mod reverso;
+pub mod simulacao;
+pub mod terminal;
+
+pub use motor::{
+    Config, Difficulty, Feedback, GuessError, GuessingGame, Hint, State,
+};
+
+/// Joga uma partida no terminal, na dificuldade normal
+pub fn main() {
+    terminal::jogar(&mut GuessingGame::new(Config::default()));
+}
@@ -0,0 +1,266 @@
+// Importa a trait Rng (para gerar números aleatórios),
+// o gerador com semente (StdRng) e a comparação (Ordering)
+use rand::{RngExt, SeedableRng, rngs::StdRng};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// Níveis prontos de dificuldade
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] =
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Faixa e limite de tentativas de cada nível
+    pub fn config(self) -> Config {
+        match self {
+            Difficulty::Easy => Config::new(1, 50, Some(10)),
+            Difficulty::Normal => Config::new(1, 100, Some(7)),
+            Difficulty::Hard => Config::new(1, 1000, Some(10)),
+        }
+        .expect("as configurações prontas são válidas")
+    }
+
+    /// Nome usado na linha de comando (`--difficulty facil`)
+    pub fn nome(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "facil",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "dificil",
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|d| d.nome() == s)
+            .ok_or_else(|| {
+                format!(
+                    "dificuldade desconhecida: {s} \
+                     (use facil, normal ou dificil)"
+                )
+            })
+    }
+}
+
+/// Faixa do número secreto (inclusive) e limite de tentativas
+///
+/// `i64` para aceitar faixas bem maiores que o `1..=100` original
+/// (o primeiro jogo usava `i8`, que não passa de 127).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub min: i64,
+    pub max: i64,
+    /// `None` = tentativas ilimitadas
+    pub max_attempts: Option<u32>,
+}
+
+impl Config {
+    pub fn new(
+        min: i64,
+        max: i64,
+        max_attempts: Option<u32>,
+    ) -> Result<Self, String> {
+        if min > max {
+            return Err(format!("faixa vazia: {min} é maior que {max}"));
+        }
+        if max_attempts == Some(0) {
+            return Err("o limite de tentativas precisa ser maior que 0".into());
+        }
+
+        Ok(Self {
+            min,
+            max,
+            max_attempts,
+        })
+    }
+
+    /// Nome da dificuldade, ou a faixa e o limite se for personalizada
+    pub fn label(&self) -> String {
+        match Difficulty::ALL.into_iter().find(|d| d.config() == *self) {
+            Some(difficulty) => difficulty.nome().to_string(),
+            None => {
+                let tentativas = self
+                    .max_attempts
+                    .map_or("∞".to_string(), |n| n.to_string());
+                format!("{}..={}/{}", self.min, self.max, tentativas)
+            }
+        }
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        (self.min..=self.max).contains(&n)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Difficulty::Normal.config()
+    }
+}
+
+/// Dica dada depois de um chute: onde está o número secreto
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    /// O secreto é maior que o chute
+    Higher,
+    /// O secreto é menor que o chute
+    Lower,
+    Correct,
+}
+
+impl From<Ordering> for Hint {
+    /// Converte `chute.cmp(&secreto)` na dica correspondente
+    fn from(ordem: Ordering) -> Self {
+        match ordem {
+            Ordering::Less => Hint::Higher, // chute < secreto
+            Ordering::Greater => Hint::Lower, // chute > secreto
+            Ordering::Equal => Hint::Correct,
+        }
+    }
+}
+
+impl Hint {
+    /// O contrário do `From<Ordering>`: a comparação `chute.cmp(&secreto)`
+    /// que gerou esta dica
+    pub fn ordering(self) -> Ordering {
+        match self {
+            Hint::Higher => Ordering::Less,
+            Hint::Lower => Ordering::Greater,
+            Hint::Correct => Ordering::Equal,
+        }
+    }
+}
+
+/// Resultado de um chute: a dica e quantas tentativas já foram feitas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feedback {
+    pub hint: Hint,
+    pub attempts: u32,
+    /// Tentativas que ainda restam (`None` se não há limite)
+    pub remaining: Option<u32>,
+}
+
+/// Situação da partida
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Playing,
+    Won,
+    /// As tentativas acabaram sem acerto
+    Lost,
+}
+
+/// Chutes que o motor recusa (e que não contam como tentativa)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessError {
+    OutOfRange { min: i64, max: i64 },
+    GameOver,
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::OutOfRange { min, max } => {
+                write!(f, "o número precisa estar entre {min} e {max}")
+            }
+            GuessError::GameOver => write!(f, "a partida já terminou"),
+        }
+    }
+}
+
+/// As regras do jogo, sem nenhuma entrada ou saída
+#[derive(Debug)]
+pub struct GuessingGame {
+    config: Config,
+    seed: u64,
+    secret: i64,
+    attempts: u32,
+    state: State,
+}
+
+impl GuessingGame {
+    /// Partida com semente aleatória
+    pub fn new(config: Config) -> Self {
+        Self::with_seed(config, rand::rng().random())
+    }
+
+    /// Partida determinística: a mesma semente sempre gera o mesmo secreto
+    pub fn with_seed(config: Config, seed: u64) -> Self {
+        let secret =
+            StdRng::seed_from_u64(seed).random_range(config.min..=config.max);
+
+        Self {
+            config,
+            seed,
+            secret,
+            attempts: 0,
+            state: State::Playing,
+        }
+    }
+
+    /// Compara o chute com o secreto e conta a tentativa.
+    /// Chutes fora da faixa são recusados sem gastar tentativa.
+    pub fn guess(&mut self, n: i64) -> Result<Feedback, GuessError> {
+        if self.state != State::Playing {
+            return Err(GuessError::GameOver);
+        }
+        if !self.config.contains(n) {
+            return Err(GuessError::OutOfRange {
+                min: self.config.min,
+                max: self.config.max,
+            });
+        }
+
+        self.attempts += 1;
+
+        let hint = Hint::from(n.cmp(&self.secret));
+        if hint == Hint::Correct {
+            self.state = State::Won;
+        } else if self.remaining() == Some(0) {
+            self.state = State::Lost;
+        }
+
+        Ok(Feedback {
+            hint,
+            attempts: self.attempts,
+            remaining: self.remaining(),
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// O número secreto (para mostrar no fim da partida)
+    pub fn secret(&self) -> i64 {
+        self.secret
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn remaining(&self) -> Option<u32> {
+        self.config.max_attempts.map(|max| max - self.attempts)
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.state == State::Won
+    }
+}
@@ -0,0 +1,585 @@
+// Multijogador em rede local: um servidor guarda o número secreto e vários
+// clientes (na mesma máquina) disputam quem acerta primeiro.
+//
+// O protocolo é texto, uma mensagem por linha, então dá para jogar até com
+// `nc localhost 7878`:
+//
+//   cliente → servidor            servidor → clientes
+//   NOME <nome>                   OLA <nome>            (só para quem entrou)
+//   PRONTO                        ENTROU <nome> / SAIU <nome>
+//                                 NOME <antigo> <novo>
+//   CHUTE <n>  (ou só <n>)        LOBBY <prontos>/<jogadores>
+//   SAIR                          RODADA <n> <min> <max>
+//                                 DICA <nome> <chute> MAIOR|MENOR
+//                                 VENCEDOR <nome> <chute> <tentativas>
+//                                 RECUSADO <motivo> / ERRO <motivo>
+//                                 FIM
+//
+// Os nomes são únicos: um NOME já em uso ganha um sufixo (`Ana_2`).
+//
+// Entre as rodadas todos ficam no lobby; a rodada começa quando todos os
+// conectados mandam PRONTO. A comparação é a do `motor`: cada chute passa
+// por `GuessingGame::guess`, e a dica vai para todo mundo.
+//
+// Cada conexão tem uma thread que só lê linhas e as manda por um canal, e
+// outra que só escreve o que chega pelo canal dela; a thread do servidor é a
+// única que mexe no estado da sala. Assim um cliente que para de ler não
+// trava a rodada dos outros: as mensagens dele esperam na fila, e se a
+// escrita não anda por `ESPERA_DE_ESCRITA` a conexão é fechada. A thread que
+// aceita conexões olha de tempos em tempos se a sala acabou, e o servidor
+// espera ela terminar antes de voltar.
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, BufReader, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use super::{Config, GuessingGame, Hint};
+use crate::entrada::{self, Entrada};
+
+/// Porta usada quando `--port` não é informada
+pub const PORTA_PADRAO: u16 = 7878;
+
+/// Quanto uma escrita pode ficar parada antes de o cliente ser desligado
+const ESPERA_DE_ESCRITA: Duration = Duration::from_secs(5);
+
+/// De quanto em quanto tempo a thread que aceita conexões confere se a sala
+/// acabou
+const ESPERA_DE_CONEXAO: Duration = Duration::from_millis(50);
+
+/// Uma mensagem do cliente
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Comando {
+    Nome(String),
+    Pronto,
+    Chute(i64),
+    Sair,
+}
+
+impl FromStr for Comando {
+    type Err = String;
+
+    fn from_str(linha: &str) -> Result<Self, Self::Err> {
+        let linha = linha.trim();
+        let (palavra, resto) = linha.split_once(' ').unwrap_or((linha, ""));
+        let resto = resto.trim();
+
+        let chute = |texto: &str| {
+            texto
+                .parse()
+                .map(Comando::Chute)
+                .map_err(|_| format!("chute inválido: {texto}"))
+        };
+
+        match palavra.to_uppercase().as_str() {
+            "NOME" if !resto.is_empty() => {
+                // Sem espaços, para o nome continuar sendo uma palavra só
+                Ok(Comando::Nome(
+                    resto.split_whitespace().collect::<Vec<_>>().join("_"),
+                ))
+            }
+            "NOME" => Err("NOME precisa de um nome".to_string()),
+            "PRONTO" => Ok(Comando::Pronto),
+            "SAIR" => Ok(Comando::Sair),
+            "CHUTE" => chute(resto),
+            _ if resto.is_empty() && palavra.parse::<i64>().is_ok() => {
+                chute(palavra)
+            }
+            _ => Err(format!("comando desconhecido: {linha}")),
+        }
+    }
+}
+
+/// O que as threads de conexão contam para o servidor
+enum Evento {
+    Entrou(usize, TcpStream),
+    Linha(usize, String),
+    Saiu(usize),
+}
+
+struct Jogador {
+    id: usize,
+    nome: String,
+    conexao: TcpStream,
+    /// A fila da thread que escreve para este jogador
+    saida: Sender<String>,
+    escritor: JoinHandle<()>,
+    pronto: bool,
+    tentativas: u32,
+}
+
+impl Jogador {
+    fn new(id: usize, nome: String, conexao: TcpStream) -> Self {
+        let (saida, fila) = mpsc::channel::<String>();
+        let escrita = conexao.try_clone();
+        let escritor = thread::spawn(move || {
+            let Ok(mut escrita) = escrita else { return };
+            let _ = escrita.set_write_timeout(Some(ESPERA_DE_ESCRITA));
+            for mensagem in fila {
+                if writeln!(escrita, "{mensagem}").is_err() {
+                    // Parado ou caído: a leitura termina e avisa `Saiu`
+                    let _ = escrita.shutdown(Shutdown::Both);
+                    return;
+                }
+            }
+        });
+
+        Self {
+            id,
+            nome,
+            conexao,
+            saida,
+            escritor,
+            pronto: false,
+            tentativas: 0,
+        }
+    }
+
+    /// Só põe na fila: se a conexão caiu, a thread de leitura avisa com
+    /// `Evento::Saiu`
+    fn enviar(&self, mensagem: &str) {
+        let _ = self.saida.send(mensagem.to_string());
+    }
+
+    /// Espera a fila esvaziar (no máximo `ESPERA_DE_ESCRITA` por mensagem
+    /// parada) e fecha a conexão
+    fn desligar(self) {
+        drop(self.saida);
+        let _ = self.escritor.join();
+        let _ = self.conexao.shutdown(Shutdown::Both);
+    }
+}
+
+/// O estado do jogo no servidor
+struct Sala {
+    config: Config,
+    seed: Option<u64>,
+    rodadas: Option<u32>,
+    jogadores: Vec<Jogador>,
+    /// `Some` durante uma rodada, `None` no lobby
+    game: Option<GuessingGame>,
+    /// Rodadas já terminadas
+    terminadas: u32,
+}
+
+impl Sala {
+    fn transmitir(&mut self, mensagem: &str) {
+        println!("» {mensagem}");
+        for jogador in &mut self.jogadores {
+            jogador.enviar(mensagem);
+        }
+    }
+
+    fn jogador(&mut self, id: usize) -> Option<&mut Jogador> {
+        self.jogadores.iter_mut().find(|j| j.id == id)
+    }
+
+    /// `desejado`, ou `desejado_2`, `desejado_3`... se outro jogador já usa
+    fn nome_livre(&self, id: usize, desejado: &str) -> String {
+        let em_uso = |nome: &str| {
+            self.jogadores.iter().any(|j| j.id != id && j.nome == nome)
+        };
+        let mut nome = desejado.to_string();
+        for sufixo in 2.. {
+            if !em_uso(&nome) {
+                break;
+            }
+            nome = format!("{desejado}_{sufixo}");
+        }
+        nome
+    }
+
+    fn entrou(&mut self, id: usize, conexao: TcpStream) {
+        let nome = self.nome_livre(id, &format!("jogador{id}"));
+        self.transmitir(&format!("ENTROU {nome}"));
+
+        let jogador = Jogador::new(id, nome.clone(), conexao);
+        jogador.enviar(&format!("OLA {nome}"));
+        self.jogadores.push(jogador);
+
+        // Quem chega no meio de uma rodada já pode chutar
+        if let Some(game) = &self.game {
+            let config = game.config();
+            let mensagem = format!(
+                "RODADA {} {} {}",
+                self.terminadas + 1,
+                config.min,
+                config.max
+            );
+            self.jogadores.last_mut().unwrap().enviar(&mensagem);
+        } else {
+            self.lobby();
+        }
+    }
+
+    fn saiu(&mut self, id: usize) {
+        let Some(i) = self.jogadores.iter().position(|j| j.id == id) else {
+            return;
+        };
+        // Sem esperar a fila: quem saiu não precisa do resto
+        let jogador = self.jogadores.remove(i);
+        let _ = jogador.conexao.shutdown(Shutdown::Both);
+        self.transmitir(&format!("SAIU {}", jogador.nome));
+
+        if self.jogadores.is_empty() {
+            // Rodada sem ninguém não tem vencedor: volta para o lobby
+            self.game = None;
+        } else if self.game.is_none() {
+            self.lobby();
+        }
+    }
+
+    /// Mostra quantos estão prontos e começa a rodada se forem todos
+    fn lobby(&mut self) {
+        let prontos = self.jogadores.iter().filter(|j| j.pronto).count();
+        let total = self.jogadores.len();
+        self.transmitir(&format!("LOBBY {prontos}/{total}"));
+
+        if total > 0 && prontos == total {
+            self.comecar_rodada();
+        }
+    }
+
+    fn comecar_rodada(&mut self) {
+        let numero = self.terminadas + 1;
+        let game = match self.seed {
+            // Uma semente diferente (e previsível) por rodada
+            Some(seed) => GuessingGame::with_seed(
+                self.config,
+                seed.wrapping_add(u64::from(self.terminadas)),
+            ),
+            None => GuessingGame::new(self.config),
+        };
+
+        for jogador in &mut self.jogadores {
+            jogador.pronto = false;
+            jogador.tentativas = 0;
+        }
+        self.game = Some(game);
+        self.transmitir(&format!(
+            "RODADA {numero} {} {}",
+            self.config.min, self.config.max
+        ));
+    }
+
+    /// Trata uma linha do cliente. Devolve `true` quando o servidor deve
+    /// encerrar (acabaram as rodadas).
+    fn linha(&mut self, id: usize, linha: &str) -> bool {
+        let comando = match linha.parse::<Comando>() {
+            Ok(comando) => comando,
+            Err(erro) => {
+                if let Some(jogador) = self.jogador(id) {
+                    jogador.enviar(&format!("ERRO {erro}"));
+                }
+                return false;
+            }
+        };
+
+        match comando {
+            Comando::Nome(nome) => {
+                let nome = self.nome_livre(id, &nome);
+                let Some(jogador) = self.jogador(id) else {
+                    return false;
+                };
+                let antigo = std::mem::replace(&mut jogador.nome, nome);
+                let novo = jogador.nome.clone();
+                self.transmitir(&format!("NOME {antigo} {novo}"));
+            }
+            Comando::Pronto if self.game.is_some() => {
+                if let Some(jogador) = self.jogador(id) {
+                    jogador.enviar("ERRO a rodada já começou");
+                }
+            }
+            Comando::Pronto => {
+                if let Some(jogador) = self.jogador(id) {
+                    jogador.pronto = true;
+                }
+                self.lobby();
+            }
+            Comando::Chute(n) => return self.chute(id, n),
+            Comando::Sair => self.saiu(id),
+        }
+
+        false
+    }
+
+    fn chute(&mut self, id: usize, n: i64) -> bool {
+        let Some(game) = &mut self.game else {
+            if let Some(jogador) = self.jogador(id) {
+                jogador
+                    .enviar("ERRO a rodada ainda não começou (mande PRONTO)");
+            }
+            return false;
+        };
+
+        // Mesmas regras do jogo de um jogador só
+        let resultado = game.guess(n);
+        let Some(jogador) = self.jogador(id) else {
+            return false;
+        };
+
+        let feedback = match resultado {
+            Ok(feedback) => feedback,
+            Err(erro) => {
+                jogador.enviar(&format!("RECUSADO {erro}"));
+                return false;
+            }
+        };
+
+        jogador.tentativas += 1;
+        let nome = jogador.nome.clone();
+        let tentativas = jogador.tentativas;
+
+        let dica = match feedback.hint {
+            Hint::Higher => "MAIOR",
+            Hint::Lower => "MENOR",
+            Hint::Correct => {
+                self.transmitir(&format!("VENCEDOR {nome} {n} {tentativas}"));
+                return self.terminar_rodada();
+            }
+        };
+        self.transmitir(&format!("DICA {nome} {n} {dica}"));
+        false
+    }
+
+    fn terminar_rodada(&mut self) -> bool {
+        self.game = None;
+        self.terminadas += 1;
+
+        if self.rodadas.is_some_and(|limite| self.terminadas >= limite) {
+            self.transmitir("FIM");
+            return true;
+        }
+
+        self.lobby();
+        false
+    }
+}
+
+/// Roda o servidor até terminar `rodadas` rodadas (ou para sempre, com
+/// `None`). Com `seed`, a rodada `i` (a partir de 0) usa a semente
+/// `seed + i`. O limite de tentativas de `config` é ignorado: cada jogador
+/// chuta quantas vezes quiser, e ganha quem acertar primeiro.
+pub fn servir(
+    listener: TcpListener,
+    config: Config,
+    seed: Option<u64>,
+    rodadas: Option<u32>,
+) -> io::Result<()> {
+    let (tx, rx) = mpsc::channel();
+
+    // Sem bloquear no `accept`, a thread pode ver que a sala acabou
+    listener.set_nonblocking(true)?;
+    let encerrada = Arc::new(AtomicBool::new(false));
+    let aceitando = {
+        let encerrada = Arc::clone(&encerrada);
+        thread::spawn(move || aceitar(listener, tx, &encerrada))
+    };
+
+    let mut sala = Sala {
+        config: Config {
+            max_attempts: None,
+            ..config
+        },
+        seed,
+        rodadas,
+        jogadores: Vec::new(),
+        game: None,
+        terminadas: 0,
+    };
+
+    for evento in rx {
+        match evento {
+            Evento::Entrou(id, conexao) => sala.entrou(id, conexao),
+            Evento::Linha(id, linha) => {
+                if sala.linha(id, &linha) {
+                    break;
+                }
+            }
+            Evento::Saiu(id) => sala.saiu(id),
+        }
+    }
+
+    // Ninguém mais entra; quem conectou e não foi atendido é desligado
+    // junto com o canal
+    encerrada.store(true, Ordering::Relaxed);
+    let _ = aceitando.join();
+
+    // O FIM ainda pode estar na fila de alguém
+    for jogador in sala.jogadores {
+        jogador.desligar();
+    }
+    Ok(())
+}
+
+/// Aceita conexões até a sala ser `encerrada` e cria uma thread de leitura
+/// para cada uma. O `listener` não bloqueia: sem ninguém chegando, a thread
+/// dorme `ESPERA_DE_CONEXAO` e confere de novo.
+fn aceitar(listener: TcpListener, tx: Sender<Evento>, encerrada: &AtomicBool) {
+    let mut ids = 1..;
+    while !encerrada.load(Ordering::Relaxed) {
+        let conexao = match listener.accept() {
+            Ok((conexao, _)) => conexao,
+            Err(erro) if erro.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(ESPERA_DE_CONEXAO);
+                continue;
+            }
+            Err(_) => continue,
+        };
+        let id = ids.next().expect("ids acabaram");
+
+        // Em alguns sistemas a conexão herda o modo sem bloqueio do listener
+        let _ = conexao.set_nonblocking(false);
+        // Mensagens são curtas: manda cada linha na hora, sem esperar juntar
+        let _ = conexao.set_nodelay(true);
+        let Ok(leitura) = conexao.try_clone() else {
+            continue;
+        };
+
+        // O servidor já terminou: ninguém mais escuta o canal
+        if tx.send(Evento::Entrou(id, conexao)).is_err() {
+            return;
+        }
+
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for linha in BufReader::new(leitura).lines() {
+                let Ok(linha) = linha else { break };
+                if tx.send(Evento::Linha(id, linha)).is_err() {
+                    return;
+                }
+            }
+            let _ = tx.send(Evento::Saiu(id));
+        });
+    }
+}
+
+/// O que chega para o laço do cliente
+enum DoCliente {
+    Digitou(String),
+    EntradaAcabou,
+    ServidorEncerrou,
+    /// A thread de leitura terminou e devolve a entrada
+    Devolveu(Box<dyn Entrada>),
+}
+
+/// A entrada de quem chamou `conectar`, que ficou com a thread de leitura.
+/// A primeira leitura espera a thread devolver a entrada; uma linha que ela
+/// já tinha lido (e o jogo não usou) vem antes das outras.
+struct Devolucao {
+    rx: Option<Receiver<DoCliente>>,
+    pendentes: VecDeque<String>,
+    entrada: Option<Box<dyn Entrada>>,
+}
+
+impl Entrada for Devolucao {
+    fn ler_linha(&mut self) -> Option<String> {
+        if let Some(rx) = self.rx.take() {
+            for evento in rx {
+                match evento {
+                    DoCliente::Digitou(linha) => {
+                        self.pendentes.push_back(linha)
+                    }
+                    DoCliente::Devolveu(entrada) => {
+                        self.entrada = Some(entrada);
+                        break;
+                    }
+                    DoCliente::EntradaAcabou | DoCliente::ServidorEncerrou => {}
+                }
+            }
+        }
+        self.pendentes
+            .pop_front()
+            .or_else(|| self.entrada.as_mut()?.ler_linha())
+    }
+
+    fn ecoar(&self) -> bool {
+        self.entrada.as_ref().is_some_and(|e| e.ecoar())
+    }
+}
+
+/// Cliente de terminal: manda as linhas da entrada atual para o servidor e
+/// imprime tudo o que chega dele. Termina no fim da entrada (mandando SAIR)
+/// ou quando o servidor encerra, mesmo no meio de uma linha digitada. Depois
+/// a entrada volta para quem chamou, sem perder linha nenhuma.
+pub fn conectar(endereco: &str) -> io::Result<()> {
+    let mut conexao = TcpStream::connect(endereco)?;
+    let leitura = conexao.try_clone()?;
+
+    println!(
+        "Conectado a {endereco}. Comandos: NOME <nome>, PRONTO, <número>, SAIR"
+    );
+
+    let (tx, rx) = mpsc::channel();
+
+    let servidor = tx.clone();
+    let recebendo = thread::spawn(move || {
+        for linha in BufReader::new(leitura).lines() {
+            let Ok(linha) = linha else { break };
+            println!("{linha}");
+            if linha == "FIM" {
+                break;
+            }
+        }
+        let _ = servidor.send(DoCliente::ServidorEncerrou);
+    });
+
+    // A leitura da entrada bloqueia, então fica numa thread que pode ficar
+    // para trás quando o jogo acaba. Ela só lê uma linha quando o laço pede,
+    // para sobrar no máximo uma lida e não usada.
+    let (pedir, pedidos) = mpsc::channel::<()>();
+    let emprestada = entrada::levar();
+    thread::spawn(move || {
+        entrada::usar(emprestada);
+        for () in pedidos {
+            let Some(linha) = entrada::ler_linha() else {
+                let _ = tx.send(DoCliente::EntradaAcabou);
+                break;
+            };
+            if tx.send(DoCliente::Digitou(linha)).is_err() {
+                return;
+            }
+        }
+        let _ = tx.send(DoCliente::Devolveu(entrada::levar()));
+    });
+
+    let _ = pedir.send(());
+    for evento in &rx {
+        match evento {
+            DoCliente::Digitou(linha) => {
+                if writeln!(conexao, "{linha}").is_err() {
+                    break;
+                }
+                let _ = pedir.send(());
+            }
+            // Fim da entrada: avisa o servidor e espera a conexão fechar
+            DoCliente::EntradaAcabou => {
+                let _ = writeln!(conexao, "SAIR");
+                break;
+            }
+            DoCliente::ServidorEncerrou => break,
+            // Só depois do fim da entrada ou do laço, que já parou antes
+            DoCliente::Devolveu(_) => unreachable!("a entrada voltou cedo"),
+        }
+    }
+
+    // Sem mais pedidos a thread devolve a entrada (depois de terminar a
+    // linha que estiver lendo)
+    drop(pedir);
+    let _ = recebendo.join();
+    entrada::usar(Devolucao {
+        rx: Some(rx),
+        pendentes: VecDeque::new(),
+        entrada: None,
+    });
+    Ok(())
+}
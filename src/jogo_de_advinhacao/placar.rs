@@ -0,0 +1,235 @@
+// Placar do jogo: um arquivo de texto local, uma partida vencida por linha,
+// com os campos separados por TAB:
+//
+//   nome  dificuldade  tentativas  duração (ms)  data (segundos desde 1970)
+//
+// Linhas que não seguem esse formato (arquivo editado à mão, corrompido...)
+// são puladas e contadas, sem impedir a leitura do resto.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Arquivo usado quando `RESOLUTION_PLACAR` não está definida
+pub const ARQUIVO_PADRAO: &str = "placar.tsv";
+
+/// Caminho do placar: `$RESOLUTION_PLACAR` ou `placar.tsv` na pasta atual
+pub fn caminho_padrao() -> PathBuf {
+    std::env::var_os("RESOLUTION_PLACAR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(ARQUIVO_PADRAO))
+}
+
+/// Uma partida vencida
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registro {
+    pub nome: String,
+    /// `Config::label()` da partida (`facil`, `1..=500/9`...)
+    pub dificuldade: String,
+    pub tentativas: u32,
+    pub duracao: Duration,
+    /// Segundos desde 1970-01-01 (UTC)
+    pub quando: u64,
+}
+
+impl Registro {
+    /// Registro com a data de agora
+    pub fn new(
+        nome: &str,
+        dificuldade: &str,
+        tentativas: u32,
+        duracao: Duration,
+    ) -> Self {
+        let quando = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            nome: limpar(nome),
+            dificuldade: limpar(dificuldade),
+            tentativas,
+            duracao,
+            quando,
+        }
+    }
+
+    fn para_linha(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.nome,
+            self.dificuldade,
+            self.tentativas,
+            self.duracao.as_millis(),
+            self.quando
+        )
+    }
+
+    fn de_linha(linha: &str) -> Option<Self> {
+        let [nome, dificuldade, tentativas, duracao, quando] =
+            linha.split('\t').collect::<Vec<_>>()[..]
+        else {
+            return None;
+        };
+
+        Some(Self {
+            nome: nome.to_string(),
+            dificuldade: dificuldade.to_string(),
+            tentativas: tentativas.parse().ok()?,
+            duracao: Duration::from_millis(duracao.parse().ok()?),
+            quando: quando.parse().ok()?,
+        })
+    }
+
+    /// Data no formato `AAAA-MM-DD` (UTC)
+    pub fn data(&self) -> String {
+        data_utc(self.quando)
+    }
+}
+
+/// Todas as partidas lidas do arquivo
+#[derive(Debug, Default)]
+pub struct Placar {
+    pub registros: Vec<Registro>,
+    /// Quantas linhas do arquivo não puderam ser lidas
+    pub linhas_invalidas: usize,
+}
+
+impl Placar {
+    /// Lê o placar. Arquivo inexistente é um placar vazio; bytes que não
+    /// são UTF-8 e linhas mal formadas são ignorados.
+    pub fn carregar(caminho: &Path) -> io::Result<Self> {
+        let bytes = match fs::read(caminho) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut placar = Self::default();
+        for linha in String::from_utf8_lossy(&bytes).lines() {
+            if linha.trim().is_empty() {
+                continue;
+            }
+            match Registro::de_linha(linha) {
+                Some(registro) => placar.registros.push(registro),
+                None => placar.linhas_invalidas += 1,
+            }
+        }
+
+        Ok(placar)
+    }
+
+    /// Acrescenta uma partida no fim do arquivo (criando-o se preciso)
+    pub fn adicionar(caminho: &Path, registro: &Registro) -> io::Result<()> {
+        let mut arquivo =
+            OpenOptions::new().create(true).append(true).open(caminho)?;
+        writeln!(arquivo, "{}", registro.para_linha())
+    }
+
+    /// Dificuldades presentes, na ordem em que aparecem no arquivo
+    pub fn dificuldades(&self) -> Vec<&str> {
+        let mut dificuldades: Vec<&str> = Vec::new();
+        for registro in &self.registros {
+            if !dificuldades.contains(&registro.dificuldade.as_str()) {
+                dificuldades.push(&registro.dificuldade);
+            }
+        }
+        dificuldades
+    }
+
+    /// As `n` melhores partidas de uma dificuldade: menos tentativas
+    /// primeiro, e no empate a mais rápida
+    pub fn melhores(&self, dificuldade: &str, n: usize) -> Vec<&Registro> {
+        let mut melhores: Vec<&Registro> = self
+            .registros
+            .iter()
+            .filter(|r| r.dificuldade == dificuldade)
+            .collect();
+
+        melhores.sort_by_key(|r| (r.tentativas, r.duracao));
+        melhores.truncate(n);
+        melhores
+    }
+}
+
+/// Imprime as `n` melhores partidas de cada dificuldade (ou só de uma)
+pub fn imprimir(placar: &Placar, dificuldade: Option<&str>, n: usize) {
+    if placar.linhas_invalidas > 0 {
+        println!(
+            "⚠️ {} linha(s) inválida(s) no placar foram ignoradas.",
+            placar.linhas_invalidas
+        );
+    }
+
+    let dificuldades = match dificuldade {
+        Some(dificuldade) => vec![dificuldade],
+        None => placar.dificuldades(),
+    };
+
+    if dificuldades.is_empty() {
+        println!("Nenhuma partida no placar ainda.");
+        return;
+    }
+
+    for dificuldade in dificuldades {
+        println!("\n=== {dificuldade} ===");
+        println!(
+            "{:<3} {:<20} {:>10} {:>10}  Data",
+            "#", "Jogador", "Tentativas", "Tempo"
+        );
+
+        let melhores = placar.melhores(dificuldade, n);
+        if melhores.is_empty() {
+            println!("(nenhuma partida)");
+        }
+
+        for (i, registro) in melhores.iter().enumerate() {
+            println!(
+                "{:<3} {:<20} {:>10} {:>9.1}s  {}",
+                i + 1,
+                registro.nome,
+                registro.tentativas,
+                registro.duracao.as_secs_f64(),
+                registro.data()
+            );
+        }
+    }
+}
+
+/// Tira TABs e quebras de linha, que quebrariam o formato do arquivo
+fn limpar(texto: &str) -> String {
+    let limpo: String = texto
+        .trim()
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+
+    if limpo.is_empty() {
+        "anônimo".to_string()
+    } else {
+        limpo
+    }
+}
+
+/// Converte segundos desde 1970 em `AAAA-MM-DD` (calendário gregoriano, UTC)
+fn data_utc(segundos: u64) -> String {
+    // Algoritmo "days from civil" de Howard Hinnant, ao contrário
+    let dias = (segundos / 86_400) as i64 + 719_468;
+    let era = dias.div_euclid(146_097);
+    let dia_da_era = dias.rem_euclid(146_097);
+    let ano_da_era = (dia_da_era - dia_da_era / 1460 + dia_da_era / 36_524
+        - dia_da_era / 146_096)
+        / 365;
+    let dia_do_ano =
+        dia_da_era - (365 * ano_da_era + ano_da_era / 4 - ano_da_era / 100);
+    let mp = (5 * dia_do_ano + 2) / 153;
+    let dia = dia_do_ano - (153 * mp + 2) / 5 + 1;
+    let mes = if mp < 10 { mp + 3 } else { mp - 9 };
+    let ano = ano_da_era + era * 400 + i64::from(mes <= 2);
+
+    format!("{ano:04}-{mes:02}-{dia:02}")
+}
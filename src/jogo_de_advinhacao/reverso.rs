@@ -0,0 +1,117 @@
+// Modo reverso: o jogador pensa num número e o computador advinha.
+//
+// O computador faz busca binária: chuta o meio da faixa que ainda é possível
+// e a resposta do jogador (a mesma `Hint` do jogo normal: maior, menor ou
+// acertou) corta metade dela. Se as respostas deixarem a faixa vazia, alguma
+// delas era mentira.
+use std::fmt;
+
+use super::{Config, Hint};
+
+/// As respostas do jogador não batem com nenhum número da faixa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contradiction {
+    pub guess: i64,
+    pub hint: Hint,
+    /// Faixa que ainda era possível antes da resposta
+    pub min: i64,
+    pub max: i64,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direcao = match self.hint {
+            Hint::Higher => "maior",
+            Hint::Lower => "menor",
+            Hint::Correct => "igual",
+        };
+        write!(
+            f,
+            "pelas respostas anteriores o número está entre {} e {}, \
+             então não pode ser {direcao} que {}",
+            self.min, self.max, self.guess
+        )
+    }
+}
+
+/// O computador advinhando por busca binária
+#[derive(Debug)]
+pub struct ReverseGame {
+    config: Config,
+    min: i64,
+    max: i64,
+    guess: Option<i64>,
+    attempts: u32,
+    found: bool,
+}
+
+impl ReverseGame {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            min: config.min,
+            max: config.max,
+            guess: None,
+            attempts: 0,
+            found: false,
+        }
+    }
+
+    /// O próximo chute: o meio da faixa que ainda é possível.
+    /// Repete o chute atual até ele receber uma resposta.
+    pub fn next_guess(&mut self) -> i64 {
+        if let Some(guess) = self.guess {
+            return guess;
+        }
+
+        // Em i128 para `min + max` não estourar em faixas enormes
+        let meio = (i128::from(self.min) + i128::from(self.max)).div_euclid(2);
+        let guess = meio as i64;
+
+        self.guess = Some(guess);
+        self.attempts += 1;
+        guess
+    }
+
+    /// Aplica a resposta do jogador ao chute atual
+    pub fn answer(&mut self, hint: Hint) -> Result<(), Contradiction> {
+        let guess = self.next_guess();
+        let contradicao = Contradiction {
+            guess,
+            hint,
+            min: self.min,
+            max: self.max,
+        };
+
+        match hint {
+            Hint::Correct => self.found = true,
+            Hint::Higher if guess < self.max => self.min = guess + 1,
+            Hint::Lower if guess > self.min => self.max = guess - 1,
+            Hint::Higher | Hint::Lower => return Err(contradicao),
+        }
+
+        self.guess = None;
+        Ok(())
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn is_found(&self) -> bool {
+        self.found
+    }
+
+    /// Faixa que ainda é possível (inclusive)
+    pub fn remaining_range(&self) -> (i64, i64) {
+        (self.min, self.max)
+    }
+
+    /// Máximo de chutes que a busca binária precisa no pior caso para a
+    /// faixa original: ⌈log₂(n + 1)⌉, com `n` números na faixa
+    pub fn optimum(&self) -> u32 {
+        let n = (i128::from(self.config.max) - i128::from(self.config.min) + 1)
+            as u128;
+        u128::BITS - n.leading_zeros()
+    }
+}
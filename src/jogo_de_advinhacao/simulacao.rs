@@ -0,0 +1,171 @@
+// Simulação em lote: cada estratégia joga milhares de partidas com sementes
+// fixas contra o motor do jogo, e as tentativas de cada partida viram
+// estatísticas (média, mediana, máximo) e um histograma.
+//
+// Uma partida que passa de `LIMITE_DE_TENTATIVAS` é cortada e conta como
+// fracasso: a linear numa faixa de bilhões de números não termina nunca.
+use std::collections::BTreeMap;
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::estrategias::Strategy;
+use super::{Config, GuessingGame, Hint, State};
+
+/// Tentativas por partida antes de a simulação desistir dela. Cobre a
+/// linear na maior dificuldade pronta (1 a 1000).
+pub const LIMITE_DE_TENTATIVAS: u32 = 1_000;
+
+/// Tentativas de todas as partidas de uma estratégia. Média, mediana,
+/// máximo e histograma são das partidas que terminaram em acerto.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub strategy: &'static str,
+    /// Tentativas por partida acertada, em ordem crescente
+    pub attempts: Vec<u32>,
+    /// Partidas cortadas em `LIMITE_DE_TENTATIVAS` sem acerto
+    pub failures: usize,
+}
+
+impl Stats {
+    pub fn games(&self) -> usize {
+        self.attempts.len() + self.failures
+    }
+
+    pub fn mean(&self) -> f64 {
+        let total: u64 = self.attempts.iter().map(|&n| u64::from(n)).sum();
+        total as f64 / self.attempts.len().max(1) as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        let n = self.attempts.len();
+        match n {
+            0 => 0.0,
+            _ if n % 2 == 1 => f64::from(self.attempts[n / 2]),
+            _ => {
+                f64::from(self.attempts[n / 2 - 1] + self.attempts[n / 2]) / 2.0
+            }
+        }
+    }
+
+    pub fn max(&self) -> u32 {
+        self.attempts.last().copied().unwrap_or(0)
+    }
+
+    /// Quantas partidas terminaram com cada número de tentativas
+    pub fn histogram(&self) -> BTreeMap<u32, usize> {
+        let mut histograma = BTreeMap::new();
+        for &n in &self.attempts {
+            *histograma.entry(n).or_insert(0) += 1;
+        }
+        histograma
+    }
+}
+
+/// Joga `games` partidas com a estratégia. A partida `i` usa a semente
+/// `seed + i`, então todas as estratégias enfrentam os mesmos secretos.
+///
+/// O limite de tentativas da `config` é trocado por `LIMITE_DE_TENTATIVAS`.
+pub fn simulate(
+    strategy: &mut dyn Strategy,
+    config: Config,
+    games: usize,
+    seed: u64,
+) -> Stats {
+    let config = Config {
+        max_attempts: Some(LIMITE_DE_TENTATIVAS),
+        ..config
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut attempts = Vec::with_capacity(games);
+    let mut failures = 0;
+
+    for i in 0..games as u64 {
+        let mut game = GuessingGame::with_seed(config, seed.wrapping_add(i));
+        strategy.reset(config.min, config.max);
+
+        loop {
+            let guess = strategy.next_guess(&mut rng);
+            let feedback = game.guess(guess).unwrap_or_else(|erro| {
+                panic!(
+                    "a estratégia {} chutou {guess}: {erro}",
+                    strategy.name()
+                )
+            });
+
+            if feedback.hint == Hint::Correct {
+                attempts.push(feedback.attempts);
+                break;
+            }
+            if game.state() == State::Lost {
+                failures += 1;
+                break;
+            }
+            strategy.observe(guess, feedback.hint.ordering());
+        }
+    }
+
+    attempts.sort_unstable();
+    Stats {
+        strategy: strategy.name(),
+        attempts,
+        failures,
+    }
+}
+
+/// Tabela comparando as estratégias e o histograma de cada uma
+pub fn imprimir(resultados: &[Stats]) {
+    println!(
+        "{:<15} {:>8} {:>8} {:>8} {:>8} {:>8}",
+        "Estratégia", "Partidas", "Média", "Mediana", "Máximo", "Cortadas"
+    );
+    println!("{}", "-".repeat(60));
+
+    for stats in resultados {
+        println!(
+            "{:<15} {:>8} {:>8.2} {:>8.1} {:>8} {:>8}",
+            stats.strategy,
+            stats.games(),
+            stats.mean(),
+            stats.median(),
+            stats.max(),
+            stats.failures
+        );
+    }
+
+    if resultados.iter().any(|stats| stats.failures > 0) {
+        println!(
+            "\nCortadas: sem acerto em {LIMITE_DE_TENTATIVAS} tentativas \
+             (ficam fora da média, da mediana e do histograma)."
+        );
+    }
+
+    for stats in resultados {
+        println!("\nHistograma: {}", stats.strategy);
+        imprimir_histograma(stats);
+    }
+}
+
+/// Uma barra por número de tentativas; faixas com muitos valores (a linear
+/// numa faixa grande) são agrupadas para caber em ~20 linhas
+fn imprimir_histograma(stats: &Stats) {
+    const LARGURA: usize = 40;
+    const LINHAS: u32 = 20;
+
+    let passo = stats.max().div_ceil(LINHAS).max(1);
+    let mut grupos: BTreeMap<u32, usize> = BTreeMap::new();
+    for (tentativas, quantas) in stats.histogram() {
+        *grupos.entry((tentativas - 1) / passo).or_insert(0) += quantas;
+    }
+
+    let maior = grupos.values().copied().max().unwrap_or(1);
+    for (grupo, quantas) in grupos {
+        let inicio = grupo * passo + 1;
+        let rotulo = if passo == 1 {
+            inicio.to_string()
+        } else {
+            format!("{inicio}-{}", inicio + passo - 1)
+        };
+        let barra = "█".repeat((quantas * LARGURA).div_ceil(maior));
+        println!("{rotulo:>9} | {barra} {quantas}");
+    }
+}
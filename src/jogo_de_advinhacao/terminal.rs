@@ -0,0 +1,321 @@
+// Interface de terminal do jogo: lê chutes da entrada atual
+// (veja `crate::entrada`) e imprime as dicas do motor
+use std::{
+    num::IntErrorKind,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use super::forca::{HangmanGame, Outcome};
+use super::gravacao::{Evento, Gravacao, Retorno};
+use super::placar::{self, Placar, Registro};
+use super::reverso::ReverseGame;
+use super::{Config, GuessingGame, Hint, State};
+use crate::entrada;
+
+/// Joga a partida e, se o jogador acertar, grava o resultado no placar
+/// e mostra as melhores partidas daquela dificuldade
+pub fn jogar_com_placar(
+    game: &mut GuessingGame,
+    caminho: &Path,
+    gravacao: Option<&mut Gravacao>,
+) {
+    let inicio = Instant::now();
+    jogar_gravando(game, gravacao);
+    let duracao = inicio.elapsed();
+
+    if game.is_won() {
+        let dificuldade = game.config().label();
+        salvar_no_placar(caminho, &dificuldade, game.attempts(), duracao);
+    }
+}
+
+/// Pede o nome do vencedor, acrescenta a partida no placar e mostra as
+/// melhores daquela dificuldade (o mesmo placar serve para os dois jogos)
+fn salvar_no_placar(
+    caminho: &Path,
+    dificuldade: &str,
+    tentativas: u32,
+    duracao: Duration,
+) {
+    println!("Seu nome para o placar:");
+    let nome = entrada::ler_linha_ou("anônimo");
+    let registro = Registro::new(&nome, dificuldade, tentativas, duracao);
+
+    // Um placar que não pode ser gravado não deve estragar a partida
+    if let Err(erro) = Placar::adicionar(caminho, &registro) {
+        println!("⚠️ Não consegui gravar em {}: {erro}", caminho.display());
+        return;
+    }
+
+    match Placar::carregar(caminho) {
+        Ok(placar) => placar::imprimir(&placar, Some(dificuldade), 5),
+        Err(erro) => {
+            println!("⚠️ Não consegui ler {}: {erro}", caminho.display())
+        }
+    }
+}
+
+pub fn jogar(game: &mut GuessingGame) {
+    jogar_gravando(game, None);
+}
+
+/// Joga a partida anotando cada linha lida (e o que o jogo fez com ela)
+/// em `gravacao`, se houver uma
+pub fn jogar_gravando(
+    game: &mut GuessingGame,
+    mut gravacao: Option<&mut Gravacao>,
+) {
+    let mut registrar = |linha: Option<&str>, retorno| {
+        if let Some(gravacao) = gravacao.as_deref_mut() {
+            gravacao.registrar(linha, retorno);
+        }
+    };
+
+    let config = *game.config();
+    println!(
+        "Dificuldade: {}. O número secreto está entre {} e {}.",
+        config.label(),
+        config.min,
+        config.max
+    );
+    if let Some(max) = config.max_attempts {
+        println!("Você tem {max} tentativa(s).");
+    }
+
+    loop {
+        println!("Insira um número:");
+
+        // Lê a entrada do usuário (stdin, roteiro ou arquivo)
+        // Se a entrada acabou (Ctrl+D, fim do arquivo), encerra o jogo
+        let Some(linha) = entrada::ler_linha() else {
+            println!("Entrada encerrada. O número era {}.", game.secret());
+            registrar(None, Retorno::Fim);
+            break;
+        };
+        // A gravação guarda a linha como chegou (espaços, `\r`); só a
+        // conversão ignora os espaços das pontas
+        let texto = linha.trim();
+
+        // Converte a string lida para número (`i64`), explicando o erro
+        let guess: i64 = match texto.parse() {
+            Ok(num) => num,
+            Err(erro) => {
+                match erro.kind() {
+                    IntErrorKind::Empty => println!("Digite um número."),
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                        println!(
+                            "{texto} está fora da faixa ({} a {}).",
+                            config.min, config.max
+                        )
+                    }
+                    _ => println!("'{texto}' não é um número inteiro."),
+                }
+                registrar(Some(&linha), Retorno::Invalida);
+                continue;
+            }
+        };
+
+        // Mostra o valor que o usuário digitou
+        println!("Você chutou: {}", guess);
+
+        // O motor compara o palpite com o número secreto
+        let feedback = match game.guess(guess) {
+            Ok(feedback) => feedback,
+            Err(erro) => {
+                println!("Chute não contado: {erro}.");
+                registrar(Some(&linha), Retorno::Recusada);
+                continue;
+            }
+        };
+        registrar(Some(&linha), Retorno::Dica(feedback.hint));
+
+        match feedback.hint {
+            Hint::Higher => println!("O número secreto é maior."),
+            Hint::Lower => println!("O número secreto é menor."),
+            Hint::Correct => {
+                println!(
+                    "Parabéns! Você acertou em {} tentativa(s).",
+                    feedback.attempts
+                );
+                break; // Sai do loop, fim do jogo
+            }
+        }
+
+        if game.state() == State::Lost {
+            println!(
+                "Suas tentativas acabaram! O número era {}.",
+                game.secret()
+            );
+            break;
+        }
+        if let Some(restantes) = feedback.remaining {
+            println!("Restam {restantes} tentativa(s).");
+        }
+    }
+}
+
+/// Joga de novo uma partida gravada: mesma semente, mesmas linhas, na mesma
+/// ordem. Devolve `false` se o jogo reagiu diferente do que foi gravado.
+pub fn reproduzir(gravacao: &Gravacao) -> bool {
+    println!("Reproduzindo partida gravada (semente {}).", gravacao.seed);
+
+    let mut game = gravacao.game();
+    let mut nova = Gravacao::new(&game);
+    entrada::com_entrada(gravacao.roteiro(), || {
+        jogar_gravando(&mut game, Some(&mut nova))
+    });
+
+    println!("\nLinha do tempo gravada:");
+    for evento in &gravacao.eventos {
+        println!("  {:>8.2}s  {evento}", evento.ms as f64 / 1000.0);
+    }
+
+    let Some(i) = gravacao.primeira_divergencia(&nova) else {
+        println!(
+            "A reprodução bateu com a gravação ({} evento(s)).",
+            gravacao.eventos.len()
+        );
+        return true;
+    };
+
+    let descrever = |evento: Option<&Evento>| {
+        evento.map_or("nada".into(), |e| e.to_string())
+    };
+    println!(
+        "⚠️ Divergência no evento {}: gravado {}, reproduzido {}.",
+        i + 1,
+        descrever(gravacao.eventos.get(i)),
+        descrever(nova.eventos.get(i))
+    );
+    false
+}
+
+/// Forca com placar: quem acerta a palavra entra na tabela `forca`
+pub fn jogar_forca_com_placar(game: &mut HangmanGame, caminho: &Path) {
+    let inicio = Instant::now();
+    jogar_forca(game);
+    let duracao = inicio.elapsed();
+
+    if game.is_won() {
+        salvar_no_placar(caminho, "forca", game.attempts(), duracao);
+    }
+}
+
+pub fn jogar_forca(game: &mut HangmanGame) {
+    println!(
+        "Forca: a palavra tem {} letra(s). Você pode errar {} vez(es).",
+        game.word().chars().filter(|c| c.is_alphabetic()).count(),
+        game.remaining_misses()
+    );
+
+    loop {
+        println!("\n{}", game.mask());
+        if !game.tried().is_empty() {
+            let chutadas: Vec<String> =
+                game.tried().iter().map(char::to_string).collect();
+            println!("Chutadas: {}", chutadas.join(" "));
+        }
+        println!("Insira uma letra (ou a palavra inteira):");
+
+        // Mesma entrada do jogo de números: stdin, roteiro ou arquivo
+        let Some(linha) = entrada::ler_linha() else {
+            println!("Entrada encerrada. A palavra era '{}'.", game.word());
+            break;
+        };
+        let linha = linha.trim();
+        if linha.is_empty() {
+            println!("Digite uma letra.");
+            continue;
+        }
+
+        match game.guess(linha) {
+            Ok(Outcome::Hit(n)) => println!("Boa! {n} letra(s) revelada(s)."),
+            Ok(Outcome::Miss) => println!(
+                "Não tem! Você ainda pode errar {} vez(es).",
+                game.remaining_misses()
+            ),
+            Err(erro) => {
+                println!("Chute não contado: {erro}.");
+                continue;
+            }
+        }
+
+        match game.state() {
+            State::Won => {
+                println!(
+                    "Parabéns! A palavra era '{}'. Você acertou em {} \
+                     tentativa(s).",
+                    game.word(),
+                    game.attempts()
+                );
+                break;
+            }
+            State::Lost => {
+                println!("Enforcado! A palavra era '{}'.", game.word());
+                break;
+            }
+            State::Playing => {}
+        }
+    }
+
+    // Letras não são bytes: acentos ocupam mais de um byte em UTF-8
+    let palavra = game.word();
+    println!(
+        "('{palavra}' tem {} caractere(s) e {} byte(s) em UTF-8.)",
+        palavra.chars().count(),
+        palavra.len()
+    );
+}
+
+/// Modo reverso: o jogador pensa num número e responde aos chutes do
+/// computador com "maior", "menor" ou "acertou"
+pub fn jogar_reverso(config: Config) {
+    let mut game = ReverseGame::new(config);
+
+    println!(
+        "Pense em um número entre {} e {}. Eu vou advinhar!",
+        config.min, config.max
+    );
+    println!("Responda se o SEU número é maior, menor ou se eu acertei.");
+
+    loop {
+        let guess = game.next_guess();
+        println!("Meu chute #{}: {guess}", game.attempts());
+
+        let Some(linha) = entrada::ler_linha() else {
+            println!("Entrada encerrada.");
+            break;
+        };
+
+        let Some(hint) = parse_resposta(&linha) else {
+            println!("Responda maior, menor ou acertou.");
+            continue;
+        };
+
+        if let Err(contradicao) = game.answer(hint) {
+            println!("Trapaça! {contradicao}.");
+            break;
+        }
+
+        if game.is_found() {
+            println!(
+                "Acertei em {} chute(s)! O ótimo teórico para essa faixa é \
+                 no máximo {}.",
+                game.attempts(),
+                game.optimum()
+            );
+            break;
+        }
+    }
+}
+
+/// `maior`/`+`, `menor`/`-` ou `acertou`/`=` (sem diferenciar maiúsculas)
+fn parse_resposta(linha: &str) -> Option<Hint> {
+    match linha.trim().to_lowercase().as_str() {
+        "maior" | "+" | ">" => Some(Hint::Higher),
+        "menor" | "-" | "<" => Some(Hint::Lower),
+        "acertou" | "=" => Some(Hint::Correct),
+        _ => None,
+    }
+}
@@ -0,0 +1,361 @@
+// Literais inteiros em qualquer base, como o Rust os escreve:
+//
+//   98_222      decimal (o `_` só separa os dígitos, não muda o valor)
+//   0xff        hexadecimal
+//   0o77        octal
+//   0b1111_0000 binário
+//   b'A'        byte (só `u8`): o código ASCII do caractere
+//   57u8        qualquer um deles com o tipo no fim (`0xffu8`, `1_000i64`)
+//
+// Negativos são guardados em complemento de dois: `-1i8` e `255u8` são os
+// mesmos 8 bits (`1111_1111`). O tipo decide como os bits são lidos.
+
+/// Sufixos aceitos, com o número de bits e se têm sinal
+const SUFIXOS: [(&str, u32, bool); 12] = [
+    ("i8", 8, true),
+    ("i16", 16, true),
+    ("i32", 32, true),
+    ("i64", 64, true),
+    ("i128", 128, true),
+    ("isize", usize::BITS, true),
+    ("u8", 8, false),
+    ("u16", 16, false),
+    ("u32", 32, false),
+    ("u64", 64, false),
+    ("u128", 128, false),
+    ("usize", usize::BITS, false),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    Decimal,
+    Hexadecimal,
+    Octal,
+    Binario,
+    /// `b'A'`
+    Byte,
+}
+
+impl Base {
+    pub fn nome(self) -> &'static str {
+        match self {
+            Base::Decimal => "decimal",
+            Base::Hexadecimal => "hexadecimal",
+            Base::Octal => "octal",
+            Base::Binario => "binário",
+            Base::Byte => "byte",
+        }
+    }
+
+    fn radix(self) -> u32 {
+        match self {
+            Base::Decimal | Base::Byte => 10,
+            Base::Hexadecimal => 16,
+            Base::Octal => 8,
+            Base::Binario => 2,
+        }
+    }
+}
+
+/// Um literal inteiro já lido
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Literal {
+    pub base: Base,
+    pub negativo: bool,
+    /// O valor sem o sinal (cabe até `u128::MAX`)
+    pub magnitude: u128,
+    /// `u8` em `57u8`
+    pub sufixo: Option<&'static str>,
+}
+
+/// Lê um literal inteiro do Rust (`0xff`, `-0b1010`, `1_000u16`, `b'\n'`)
+pub fn ler(texto: &str) -> Result<Literal, String> {
+    let original = texto.trim();
+    let (negativo, texto) = match original.strip_prefix('-') {
+        Some(resto) => (true, resto),
+        None => (false, original),
+    };
+
+    if let Some(byte) = texto.strip_prefix("b'") {
+        let byte = byte
+            .strip_suffix('\'')
+            .ok_or_else(|| format!("{original}: falta fechar a aspa"))?;
+        if negativo {
+            return Err(format!("{original}: byte literal é u8, sem sinal"));
+        }
+        return Ok(Literal {
+            base: Base::Byte,
+            negativo: false,
+            magnitude: ler_byte(byte)
+                .ok_or_else(|| format!("{original}: byte literal inválido"))?
+                .into(),
+            sufixo: Some("u8"),
+        });
+    }
+
+    let (base, digitos) = match texto.get(..2) {
+        Some("0x") => (Base::Hexadecimal, &texto[2..]),
+        Some("0o") => (Base::Octal, &texto[2..]),
+        Some("0b") => (Base::Binario, &texto[2..]),
+        _ => (Base::Decimal, texto),
+    };
+
+    // O sufixo começa no `i` ou no `u` (nenhum dos dois é dígito hexa)
+    let (digitos, sufixo) = match digitos.find(['i', 'u']) {
+        Some(i) => {
+            let nome = digitos[i..].trim_start_matches('_');
+            let sufixo = SUFIXOS
+                .iter()
+                .find(|(s, ..)| *s == nome)
+                .map(|(s, ..)| *s)
+                .ok_or_else(|| {
+                    format!("{original}: sufixo desconhecido ({nome})")
+                })?;
+            (&digitos[..i], Some(sufixo))
+        }
+        None => (digitos, None),
+    };
+
+    let limpos: String = digitos.chars().filter(|&c| c != '_').collect();
+    if limpos.is_empty() {
+        return Err(format!("{original}: faltam os dígitos"));
+    }
+    if let Some(c) = limpos.chars().find(|c| !c.is_digit(base.radix())) {
+        return Err(format!(
+            "{original}: '{c}' não é um dígito {}",
+            base.nome()
+        ));
+    }
+    let magnitude = u128::from_str_radix(&limpos, base.radix())
+        .map_err(|_| format!("{original} não cabe nem em u128"))?;
+
+    let literal = Literal {
+        base,
+        negativo: negativo && magnitude != 0,
+        magnitude,
+        sufixo,
+    };
+    if let Some(tipo) = sufixo {
+        let (_, bits, sinal) = tipo_do_sufixo(tipo);
+        if negativo && !sinal {
+            return Err(format!(
+                "{original}: o `-` não se aplica a {tipo} (não tem sinal)"
+            ));
+        }
+        if !cabe(&literal, bits, sinal) {
+            return Err(format!(
+                "{original}: literal fora da faixa de {tipo} ({} a {})",
+                minimo(bits, sinal),
+                maximo(bits, sinal)
+            ));
+        }
+    }
+    Ok(literal)
+}
+
+/// O conteúdo entre as aspas de `b'...'`, com os escapes do Rust
+fn ler_byte(texto: &str) -> Option<u8> {
+    let byte = match texto {
+        "\\n" => b'\n',
+        "\\r" => b'\r',
+        "\\t" => b'\t',
+        "\\\\" => b'\\',
+        "\\'" => b'\'',
+        "\\\"" => b'"',
+        "\\0" => b'\0',
+        _ => match texto.strip_prefix("\\x") {
+            Some(hexa) if hexa.len() == 2 => {
+                u8::from_str_radix(hexa, 16).ok()?
+            }
+            Some(_) => return None,
+            None => {
+                let mut letras = texto.chars();
+                match (letras.next(), letras.next()) {
+                    (Some(c), None) if c.is_ascii() && c != '\\' => c as u8,
+                    _ => return None,
+                }
+            }
+        },
+    };
+    Some(byte)
+}
+
+/// O byte como o Rust o escreveria: `b'A'`, `b'\n'`, `b'\xff'`
+pub fn literal_de_byte(byte: u8) -> String {
+    let dentro = match byte {
+        b'\n' => "\\n".to_string(),
+        b'\r' => "\\r".to_string(),
+        b'\t' => "\\t".to_string(),
+        b'\\' => "\\\\".to_string(),
+        b'\'' => "\\'".to_string(),
+        b'\0' => "\\0".to_string(),
+        0x20..=0x7e => char::from(byte).to_string(),
+        _ => format!("\\x{byte:02x}"),
+    };
+    format!("b'{dentro}'")
+}
+
+fn tipo_do_sufixo(tipo: &str) -> (&'static str, u32, bool) {
+    SUFIXOS
+        .into_iter()
+        .find(|(s, ..)| *s == tipo)
+        .unwrap_or(("i32", 32, true))
+}
+
+/// Os `bits` de menor ordem ligados
+fn mascara(bits: u32) -> u128 {
+    match bits {
+        128 => u128::MAX,
+        _ => (1 << bits) - 1,
+    }
+}
+
+fn minimo(bits: u32, sinal: bool) -> String {
+    match sinal {
+        true => format!("-{}", 1_u128 << (bits - 1)),
+        false => "0".to_string(),
+    }
+}
+
+fn maximo(bits: u32, sinal: bool) -> String {
+    match sinal {
+        true => (mascara(bits) >> 1).to_string(),
+        false => mascara(bits).to_string(),
+    }
+}
+
+/// O literal cabe num inteiro de `bits` bits (com ou sem sinal)?
+fn cabe(literal: &Literal, bits: u32, sinal: bool) -> bool {
+    match (sinal, literal.negativo) {
+        (false, true) => false,
+        (false, false) => literal.magnitude <= mascara(bits),
+        (true, true) => literal.magnitude <= 1 << (bits - 1),
+        (true, false) => literal.magnitude <= mascara(bits) >> 1,
+    }
+}
+
+/// Separa `digitos` em grupos de `tamanho` com `_`, a partir da direita
+pub fn agrupar(digitos: &str, tamanho: usize) -> String {
+    let letras: Vec<char> = digitos.chars().collect();
+    let mut grupos: Vec<String> = letras
+        .rchunks(tamanho)
+        .map(|grupo| grupo.iter().collect())
+        .collect();
+    grupos.reverse();
+    grupos.join("_")
+}
+
+/// O mesmo valor em todas as bases, e os bits em complemento de dois
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Representacoes {
+    pub literal: Literal,
+    pub decimal: String,
+    pub hexadecimal: String,
+    pub octal: String,
+    pub binario: String,
+    /// `b'A'`, quando o valor está entre 0 e 255
+    pub byte: Option<String>,
+    /// Largura usada para os bits
+    pub bits: u32,
+    /// O tipo de onde veio a largura (`None` quando ela foi pedida)
+    pub tipo: Option<&'static str>,
+    /// Os `bits` bits, em grupos de 4
+    pub padrao: String,
+    /// Os mesmos bits lidos sem sinal e com sinal
+    pub sem_sinal: String,
+    pub com_sinal: String,
+}
+
+/// Lê o literal e mostra ele em todas as bases. A largura vem de `bits`, do
+/// sufixo ou, sem nenhum dos dois, do menor entre `i32`, `i64`, `i128` e
+/// `u128` em que o valor cabe (como o Rust, que começa em `i32`).
+pub fn converter(
+    texto: &str,
+    bits: Option<u32>,
+) -> Result<Representacoes, String> {
+    let literal = ler(texto)?;
+
+    let (bits, tipo) = match (bits, literal.sufixo) {
+        (Some(bits), _) => {
+            if !(1..=128).contains(&bits) {
+                return Err(format!("largura inválida: {bits} (use 1 a 128)"));
+            }
+            if !cabe(&literal, bits, true) && !cabe(&literal, bits, false) {
+                return Err(format!("{texto} não cabe em {bits} bits"));
+            }
+            (bits, None)
+        }
+        (None, Some(sufixo)) => (tipo_do_sufixo(sufixo).1, Some(sufixo)),
+        (None, None) => {
+            // u128 só serve para positivos: um negativo que não cabe em
+            // i128 não cabe em tipo nenhum
+            let (nome, bits, _) = ["i32", "i64", "i128", "u128"]
+                .into_iter()
+                .map(tipo_do_sufixo)
+                .find(|&(_, bits, sinal)| cabe(&literal, bits, sinal))
+                .ok_or_else(|| format!("{texto} não cabe em i128"))?;
+            (bits, Some(nome))
+        }
+    };
+
+    let sinal = if literal.negativo { "-" } else { "" };
+    let m = literal.magnitude;
+    let padrao = match literal.negativo {
+        true => (!m).wrapping_add(1) & mascara(bits),
+        false => m,
+    };
+    let negativo_no_padrao = padrao >> (bits - 1) & 1 == 1;
+    let com_sinal = match negativo_no_padrao {
+        true => format!("-{}", (!padrao).wrapping_add(1) & mascara(bits)),
+        false => padrao.to_string(),
+    };
+
+    Ok(Representacoes {
+        literal,
+        decimal: format!("{sinal}{}", agrupar(&m.to_string(), 3)),
+        hexadecimal: format!("{sinal}0x{}", agrupar(&format!("{m:x}"), 4)),
+        octal: format!("{sinal}0o{}", agrupar(&format!("{m:o}"), 3)),
+        binario: format!("{sinal}0b{}", agrupar(&format!("{m:b}"), 4)),
+        byte: u8::try_from(m)
+            .ok()
+            .filter(|_| !literal.negativo)
+            .map(literal_de_byte),
+        bits,
+        tipo,
+        padrao: agrupar(&format!("{padrao:0w$b}", w = bits as usize), 4),
+        sem_sinal: padrao.to_string(),
+        com_sinal,
+    })
+}
+
+pub fn imprimir(texto: &str, r: &Representacoes) {
+    let sufixo = r
+        .literal
+        .sufixo
+        .map_or(String::new(), |s| format!(", sufixo {s}"));
+    println!("{} ({}{sufixo})\n", texto.trim(), r.literal.base.nome());
+
+    println!("  decimal       {}", r.decimal);
+    println!("  hexadecimal   {}", r.hexadecimal);
+    println!("  octal         {}", r.octal);
+    println!("  binário       {}", r.binario);
+    match &r.byte {
+        Some(byte) => println!("  byte          {byte}"),
+        None => println!("  byte          (só de 0 a 255)"),
+    }
+
+    let largura = match r.tipo {
+        Some(tipo) => format!("{tipo} ({} bits)", r.bits),
+        None => format!("{} bits", r.bits),
+    };
+    println!("\n  Em {largura}:  {}", r.padrao);
+    println!("    lidos sem sinal = {}", r.sem_sinal);
+    println!("    lidos com sinal = {}", r.com_sinal);
+
+    if r.literal.negativo {
+        println!(
+            "\n  Complemento de dois: inverta os bits de {} e some 1.",
+            r.decimal.trim_start_matches('-')
+        );
+    }
+}
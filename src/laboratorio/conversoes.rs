@@ -0,0 +1,351 @@
+// Conversões entre tipos primitivos: `as`, `From` e `TryFrom`.
+//
+// A matriz com todos os pares é gerada por macro, e quem decide se cada
+// conversão existe é o compilador: para cada par `(Origem, Destino)` uma
+// sonda (veja `crate::sonda`) pergunta se `Destino: From<Origem>` (e
+// `TryFrom`, e `as`). Assim a matriz acompanha a biblioteca padrão sozinha.
+//
+// `as` não é uma trait, então as regras da linguagem (numérico ↔ numérico,
+// `bool`/`char` → inteiro, `u8` → `char`) viram impls de `ComoAs` abaixo.
+
+use std::fmt;
+
+/// Tipos da matriz, na ordem das linhas e colunas
+pub const TIPOS: [&str; 16] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64", "bool", "char",
+];
+
+/// Uma conversão que o operador `as` aceita
+pub trait ComoAs<D> {
+    fn como(self) -> D;
+}
+
+macro_rules! como_as {
+    ($($origem:ty),* => $destinos:tt) => {
+        $(como_as!(@um $origem => $destinos);)*
+    };
+    (@um $origem:ty => [$($destino:ty),*]) => {
+        $(
+            impl ComoAs<$destino> for $origem {
+                fn como(self) -> $destino {
+                    self as $destino
+                }
+            }
+        )*
+    };
+}
+
+// Números viram qualquer número; `bool` e `char` viram inteiros; e só `u8`
+// vira `char` (os outros inteiros podem não ser um código Unicode válido)
+como_as!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        => [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,
+            f32, f64]
+);
+como_as!(
+    bool, char => [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+                   usize]
+);
+como_as!(u8 => [char]);
+
+/// O que acontece ao converter um valor de um tipo para outro
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conversao {
+    pub origem: &'static str,
+    pub destino: &'static str,
+    /// O valor lido, como o Rust o imprime (`{:?}`)
+    pub valor: String,
+    /// `valor as Destino`, se `as` aceita o par
+    pub cast: Option<String>,
+    /// `Destino::from(valor)`, se existe a conversão sem perda
+    pub from: Option<String>,
+    /// `Destino::try_from(valor)`, se existe (`Err` com a mensagem)
+    pub try_from: Option<Result<String, String>>,
+}
+
+/// Um par da matriz: quais conversões existem e como fazê-las
+#[derive(Debug, Clone, Copy)]
+pub struct Par {
+    pub origem: &'static str,
+    pub destino: &'static str,
+    pub cast: bool,
+    pub from: bool,
+    pub try_from: bool,
+    converter: fn(&str) -> Result<Conversao, String>,
+}
+
+impl Par {
+    /// Lê `valor` como o tipo de origem e faz as três conversões
+    pub fn converter(&self, valor: &str) -> Result<Conversao, String> {
+        (self.converter)(valor)
+    }
+}
+
+macro_rules! par {
+    ($o:ident, $d:ident) => {{
+        fn via_as() -> Option<fn($o) -> $d> {
+            sonda!(
+                D = $d;
+                $o: ComoAs<D>;
+                Option<fn($o) -> D>;
+                Some(<$o as ComoAs<D>>::como);
+                None
+            )
+        }
+        fn via_from() -> Option<fn($o) -> $d> {
+            sonda!(D = $d; D: From<$o>; Option<fn($o) -> D>; Some(D::from); None)
+        }
+        fn via_try_from() -> Option<fn($o) -> Result<$d, String>> {
+            sonda!(
+                D = $d;
+                D: TryFrom<$o>, <D as TryFrom<$o>>::Error: fmt::Display;
+                Option<fn($o) -> Result<D, String>>;
+                Some(|valor| D::try_from(valor).map_err(|e| e.to_string()));
+                None
+            )
+        }
+
+        fn converter(texto: &str) -> Result<Conversao, String> {
+            let valor: $o = texto.trim().parse().map_err(|_| {
+                format!("{texto} não é um valor de {}", stringify!($o))
+            })?;
+
+            Ok(Conversao {
+                origem: stringify!($o),
+                destino: stringify!($d),
+                valor: format!("{valor:?}"),
+                cast: via_as().map(|f| format!("{:?}", f(valor))),
+                from: via_from().map(|f| format!("{:?}", f(valor))),
+                try_from: via_try_from()
+                    .map(|f| f(valor).map(|v| format!("{v:?}"))),
+            })
+        }
+
+        Par {
+            origem: stringify!($o),
+            destino: stringify!($d),
+            cast: via_as().is_some(),
+            from: via_from().is_some(),
+            try_from: via_try_from().is_some(),
+            converter,
+        }
+    }};
+}
+
+macro_rules! linha {
+    ($o:ident) => {
+        [
+            par!($o, i8),
+            par!($o, i16),
+            par!($o, i32),
+            par!($o, i64),
+            par!($o, i128),
+            par!($o, isize),
+            par!($o, u8),
+            par!($o, u16),
+            par!($o, u32),
+            par!($o, u64),
+            par!($o, u128),
+            par!($o, usize),
+            par!($o, f32),
+            par!($o, f64),
+            par!($o, bool),
+            par!($o, char),
+        ]
+    };
+}
+
+/// Os 256 pares de tipos primitivos, linha a linha na ordem de `TIPOS`
+pub fn matriz() -> Vec<Par> {
+    [
+        linha!(i8),
+        linha!(i16),
+        linha!(i32),
+        linha!(i64),
+        linha!(i128),
+        linha!(isize),
+        linha!(u8),
+        linha!(u16),
+        linha!(u32),
+        linha!(u64),
+        linha!(u128),
+        linha!(usize),
+        linha!(f32),
+        linha!(f64),
+        linha!(bool),
+        linha!(char),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Busca o par pelo nome dos tipos
+pub fn par(origem: &str, destino: &str) -> Result<Par, String> {
+    for tipo in [origem, destino] {
+        if !TIPOS.contains(&tipo) {
+            return Err(format!(
+                "tipo desconhecido: {tipo} (use {})",
+                TIPOS.join(", ")
+            ));
+        }
+    }
+
+    matriz()
+        .into_iter()
+        .find(|p| p.origem == origem && p.destino == destino)
+        .ok_or_else(|| format!("par {origem} → {destino} fora da matriz"))
+}
+
+/// Explica o que o `as` fez com o valor, quando ele não é exato
+pub fn observacao(conversao: &Conversao) -> Option<String> {
+    let cast = conversao.cast.as_deref()?;
+    let (origem, destino) = (conversao.origem, conversao.destino);
+    let flutuante = |tipo: &str| tipo.starts_with('f');
+    let inteiro = |tipo: &str| tipo.starts_with(['i', 'u']);
+
+    if flutuante(origem) && inteiro(destino) {
+        let valor: f64 = conversao.valor.parse().ok()?;
+        let resultado: f64 = cast.parse().ok()?;
+        return if valor.is_nan() {
+            Some("NaN vira 0 com `as`".to_string())
+        } else if resultado != valor.trunc() {
+            Some(format!("fora da faixa de {destino}: `as` satura em {cast}"))
+        } else if valor.fract() != 0.0 {
+            Some(
+                "`as` descarta a parte fracionária (arredonda para o zero)"
+                    .into(),
+            )
+        } else {
+            None
+        };
+    }
+
+    if flutuante(origem) && flutuante(destino) {
+        let valor = como_f64(&conversao.valor, origem)?;
+        let resultado = como_f64(cast, destino)?;
+        return if resultado.is_infinite() && valor.is_finite() {
+            Some(format!("fora da faixa de {destino}: vira infinito"))
+        } else if resultado != valor && !valor.is_nan() {
+            Some(format!(
+                "arredondado para o {destino} mais próximo ({resultado})"
+            ))
+        } else {
+            None
+        };
+    }
+
+    if inteiro(origem) && flutuante(destino) {
+        let resultado: f64 = cast.parse().ok()?;
+        let exato = match conversao.valor.parse::<i128>() {
+            Ok(n) => resultado.abs() < 2f64.powi(127) && resultado as i128 == n,
+            Err(_) => {
+                let n: u128 = conversao.valor.parse().ok()?;
+                resultado < 2f64.powi(128) && resultado as u128 == n
+            }
+        };
+        return (!exato).then(|| {
+            format!(
+                "{destino} não guarda {} exatamente: arredondado",
+                conversao.valor
+            )
+        });
+    }
+
+    // Inteiro (ou `char`, pelo código) para inteiro: sobram os bits menores
+    if inteiro(destino) && matches!(conversao.try_from, Some(Err(_))) {
+        let bits = bits(destino);
+        let negativo = |texto: &str| texto.starts_with('-');
+        let mut texto = format!("`as` guarda só os {bits} bits menores");
+        if origem == "char" {
+            texto += " do código Unicode";
+        } else if negativo(&conversao.valor) != negativo(cast) {
+            texto += " e o sinal muda (complemento de dois)";
+        }
+        return Some(texto);
+    }
+
+    None
+}
+
+/// O número exato guardado no texto: `"0.1"` como `f32` é 0.10000000149...
+fn como_f64(texto: &str, tipo: &str) -> Option<f64> {
+    match tipo {
+        "f32" => texto.parse::<f32>().ok().map(f64::from),
+        _ => texto.parse().ok(),
+    }
+}
+
+/// Bits de um tipo inteiro pelo nome (`isize`/`usize` dependem do alvo)
+fn bits(tipo: &str) -> u32 {
+    match &tipo[1..] {
+        "size" => usize::BITS,
+        bits => bits.parse().unwrap_or(0),
+    }
+}
+
+/// Mostra as três conversões de um valor, lado a lado
+pub fn imprimir(conversao: &Conversao) {
+    let Conversao {
+        origem,
+        destino,
+        valor,
+        ..
+    } = conversao;
+    println!("{valor}: {origem} → {destino}\n");
+
+    match &conversao.cast {
+        Some(resultado) => println!("  as        {resultado}"),
+        None => println!(
+            "  as        não compila: `as` não converte {origem} em {destino}"
+        ),
+    }
+    match &conversao.from {
+        Some(resultado) => {
+            println!("  From      {resultado} (sem perda para qualquer valor)")
+        }
+        None => println!(
+            "  From      não existe: nem todo {origem} cabe em {destino}"
+        ),
+    }
+    match &conversao.try_from {
+        Some(Ok(resultado)) => println!("  TryFrom   Ok({resultado})"),
+        Some(Err(erro)) => println!("  TryFrom   Err({erro})"),
+        None => println!("  TryFrom   não existe para {origem} → {destino}"),
+    }
+
+    if let Some(observacao) = observacao(conversao) {
+        println!("\n  ⚠️ {observacao}");
+    }
+}
+
+/// A matriz inteira: F = From (sem perda), T = só TryFrom, a = `as`
+pub fn imprimir_matriz(pares: &[Par]) {
+    let mut cabecalho = format!("{:<8}", "de\\para");
+    for tipo in TIPOS {
+        cabecalho += &format!("{tipo:>6}");
+    }
+    println!("{cabecalho}");
+
+    for linha in pares.chunks(TIPOS.len()) {
+        let mut texto = format!("{:<8}", linha[0].origem);
+        for par in linha {
+            let celula = match (par.from, par.try_from, par.cast) {
+                (true, _, true) => "F a",
+                (true, _, false) => "F",
+                (false, true, true) => "T a",
+                (false, true, false) => "T",
+                (false, false, true) => "a",
+                (false, false, false) => "·",
+            };
+            texto += &format!("{celula:>6}");
+        }
+        println!("{texto}");
+    }
+
+    println!(
+        "\nF = From (sem perda)   T = só TryFrom (pode falhar)   a = `as`"
+    );
+    println!("· = nenhuma conversão direta (ex.: f64 → bool: use `x != 0.0`)");
+}
@@ -0,0 +1,628 @@
+// ============================================================================
+// ERROS DO COMPILADOR: o que cada código E0xxx quer dizer, em português
+// ============================================================================
+//
+// `rustc --explain E0502` explica em inglês e com exemplos genéricos. Aqui
+// cada código que os capítulos provocam aponta para o capítulo, para o
+// exemplo do projeto que fala dele e para o caso em `tests/nao_compila/`
+// que prova o erro, com a versão corrigida do lado:
+//
+//   resolution explain E0502
+//
+// Também lê os diagnósticos em JSON do compilador, um por linha, e explica
+// cada erro que tiver código:
+//
+//   cargo build --message-format=json | resolution explain
+//   rustc --error-format=json main.rs 2>&1 | resolution explain
+//
+// O JSON é lido por um parser pequeno daqui de baixo (o projeto não tem
+// dependência para isso): só o suficiente para as mensagens do rustc.
+//
+// ============================================================================
+
+use std::collections::HashSet;
+
+use crate::capitulos;
+
+/// Um código de erro do compilador, explicado com os exemplos do projeto
+#[derive(Debug)]
+pub struct Explicacao {
+    pub codigo: &'static str,
+    pub titulo: &'static str,
+    pub explicacao: &'static str,
+    /// A função que fala do erro (`modulo::funcao`), registrada em `capitulos`
+    pub exemplo: &'static str,
+    /// O caso em `tests/nao_compila/` que prova o erro
+    pub caso: &'static str,
+    /// A versão corrigida mínima (compila: conferido nos testes)
+    pub correcao: &'static str,
+}
+
+impl Explicacao {
+    /// O módulo do exemplo (`ownership::slice` de `ownership::slice::f`)
+    pub fn modulo(&self) -> &'static str {
+        self.exemplo
+            .rsplit_once("::")
+            .map_or(self.exemplo, |(m, _)| m)
+    }
+
+    /// O arquivo em `docs/` do capítulo do exemplo
+    pub fn doc(&self) -> Option<&'static str> {
+        capitulos::buscar(self.modulo()).first().map(|c| c.doc)
+    }
+}
+
+pub const CATALOGO: &[Explicacao] = &[
+    Explicacao {
+        codigo: "E0106",
+        titulo: "falta o lifetime de uma referência",
+        explicacao: "\
+Uma referência (`&str`, `&String`) foi usada num lugar em que o compilador \
+não sabe de quem ela empresta: no retorno de uma função sem parâmetros \
+emprestados ou num campo de struct. Se o valor é criado dentro da função, \
+devolva o próprio valor (a posse vai junto) em vez de uma referência para \
+ele, que seria descartado no fim da função.",
+        exemplo: "ownership::referencias_e_borrowing::referencia_pendurada",
+        caso: "borrowing_referencia_pendurada.rs",
+        correcao: "\
+fn main() {
+    let s = no_dangle();
+    println!(\"{s}\");
+}
+
+fn no_dangle() -> String {
+    let s = String::from(\"hello\");
+    s // a posse sai da função junto com o valor
+}",
+    },
+    Explicacao {
+        codigo: "E0277",
+        titulo: "o tipo não implementa a trait pedida",
+        explicacao: "\
+A operação precisa de uma trait que o tipo não tem: somar `i8` com \
+`Option<i8>` (não existe `Add<Option<i8>>`), imprimir com `{:?}` sem \
+`Debug`, `u8::from(i32)` sem `From<i32>`. No caso do `Option`, tire o \
+valor de dentro antes e decida o que fazer com o `None`.",
+        exemplo: "enums::option_enum::option_precisa_tratamento",
+        caso: "option_somar_com_option.rs",
+        correcao: "\
+fn main() {
+    let x: i8 = 5;
+    let y: Option<i8> = Some(5);
+
+    let sum = x + y.unwrap_or(0); // o None vira 0
+    println!(\"{sum}\");
+}",
+    },
+    Explicacao {
+        codigo: "E0308",
+        titulo: "tipos diferentes",
+        explicacao: "\
+O valor tem um tipo e o lugar espera outro, e o Rust não converte nada \
+sozinho. `mut` deixa trocar o valor, nunca o tipo: para reaproveitar o \
+nome com outro tipo, use shadowing (`let` de novo). Entre números, \
+converta com `From`, `TryFrom` ou `as` (capítulo 13).",
+        exemplo: "conceitos_comuns::variaveis_e_mutabilidade::shadowing_muda_tipo",
+        caso: "variaveis_mut_nao_muda_tipo.rs",
+        correcao: "\
+fn main() {
+    let spaces = \"   \";
+    let spaces = spaces.len(); // shadowing: um novo `spaces`, agora usize
+    println!(\"spaces: {spaces}\");
+}",
+    },
+    Explicacao {
+        codigo: "E0381",
+        titulo: "uso de uma variável antes de ela receber um valor",
+        explicacao: "\
+A variável foi declarada sem valor (`let x: i32;`) e usada antes da \
+primeira atribuição, ou numa rota (um `if` sem `else`) em que ela nunca \
+recebe um. Dê o valor na própria declaração ou garanta que todo caminho \
+atribui antes do primeiro uso.",
+        exemplo: "conceitos_comuns::variaveis_e_mutabilidade::valor_depois",
+        caso: "variaveis_usar_sem_valor.rs",
+        correcao: "\
+fn main() {
+    let x: i32;
+    x = 5; // o valor chega antes do primeiro uso
+    println!(\"The value of x is: {x}\");
+}",
+    },
+    Explicacao {
+        codigo: "E0382",
+        titulo: "uso de um valor depois do move",
+        explicacao: "\
+O valor foi movido (para outra variável ou para uma função) e a variável \
+antiga não é mais dona de nada. Se os dois precisam do valor, clone; se \
+quem recebe só precisa ler, empreste com `&`.",
+        exemplo: "ownership::o_que_e::ownership_basico",
+        caso: "ownership_usar_depois_do_move.rs",
+        correcao: "\
+fn main() {
+    let s1 = String::from(\"olá\");
+    let s2 = s1.clone(); // uma cópia: s1 continua dono do original
+    println!(\"{}\", s1);
+    println!(\"{}\", s2);
+}",
+    },
+    Explicacao {
+        codigo: "E0384",
+        titulo: "atribuição a uma variável imutável",
+        explicacao: "\
+Toda variável é imutável até que se diga o contrário. Declare com \
+`let mut` se o valor precisa mudar, ou crie uma nova com `let` \
+(shadowing) se a antiga não é mais usada.",
+        exemplo: "conceitos_comuns::variaveis_e_mutabilidade::imutavel_e_mutavel",
+        caso: "variaveis_reatribuir_imutavel.rs",
+        correcao: "\
+fn main() {
+    let mut x = 5;
+    println!(\"The value of x is: {x}\");
+    x = 6;
+    println!(\"The value of x is: {x}\");
+}",
+    },
+    Explicacao {
+        codigo: "E0499",
+        titulo: "duas referências mutáveis ao mesmo tempo",
+        explicacao: "\
+Só pode existir uma `&mut` para um valor enquanto ela estiver em uso. \
+Termine de usar a primeira antes de criar a segunda (o empréstimo acaba \
+no último uso, não no fim do bloco) ou separe as duas em escopos.",
+        exemplo: "ownership::referencias_e_borrowing::referencias_mutaveis_simultaneas",
+        caso: "borrowing_duas_mutaveis.rs",
+        correcao: "\
+fn main() {
+    let mut s = String::from(\"olá\");
+
+    let r1 = &mut s;
+    r1.push('!');
+    println!(\"{}\", r1); // último uso de r1
+
+    let r2 = &mut s;
+    println!(\"{}\", r2);
+}",
+    },
+    Explicacao {
+        codigo: "E0502",
+        titulo: "empréstimo mutável com imutáveis em uso",
+        explicacao: "\
+Enquanto alguém lê o valor por uma `&` (ou uma slice, que também é um \
+empréstimo), ninguém pode mudá-lo por uma `&mut`. Use as referências de \
+leitura pela última vez antes de pegar a mutável.",
+        exemplo: "ownership::referencias_e_borrowing::mistura_referencias",
+        caso: "borrowing_mutavel_com_imutaveis.rs",
+        correcao: "\
+fn main() {
+    let mut s = String::from(\"olá\");
+
+    let r1 = &s;
+    let r2 = &s;
+    println!(\"{} e {}\", r1, r2); // último uso de r1 e r2
+
+    let r3 = &mut s;
+    r3.push_str(\"!!!\");
+    println!(\"{}\", r3);
+}",
+    },
+    Explicacao {
+        codigo: "E0505",
+        titulo: "move de um valor ainda emprestado",
+        explicacao: "\
+Enquanto uma referência para o valor ainda vai ser usada, o dono não pode \
+entregar a posse (para outra variável, para uma função, com `drop`): a \
+referência apontaria para um valor que mudou de lugar ou já foi \
+descartado. Use a referência pela última vez antes do move, ou clone o \
+valor se os dois precisam dele.",
+        exemplo: "ownership::referencias_e_borrowing::mover_enquanto_emprestado",
+        caso: "borrowing_mover_enquanto_emprestado.rs",
+        correcao: "\
+fn main() {
+    let s = String::from(\"olá\");
+
+    let r = &s;
+    println!(\"{}\", r); // último uso de r
+
+    let t = s; // agora ninguém empresta s
+    println!(\"{}\", t);
+}",
+    },
+    Explicacao {
+        codigo: "E0596",
+        titulo: "mutação através de um empréstimo imutável",
+        explicacao: "\
+Para mudar um valor emprestado, o empréstimo tem que ser `&mut` (e a \
+variável original, `mut`). Uma `&` só deixa ler, mesmo dentro de uma \
+função que recebeu a referência.",
+        exemplo: "ownership::referencias_e_borrowing::tentativa_modificacao_imutavel",
+        caso: "borrowing_modificar_via_referencia_imutavel.rs",
+        correcao: "\
+fn main() {
+    let mut s = String::from(\"hello\");
+    change(&mut s);
+    println!(\"{s}\");
+}
+
+fn change(s: &mut String) {
+    s.push_str(\", world\");
+}",
+    },
+];
+
+/// Aceita `E0502`, `e0502`, `0502` e `502`
+pub fn normalizar(codigo: &str) -> Result<String, String> {
+    let digitos = codigo.trim().trim_start_matches(['E', 'e']);
+    if digitos.is_empty()
+        || digitos.len() > 4
+        || !digitos.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(format!("código de erro inválido: {codigo}"));
+    }
+    Ok(format!("E{digitos:0>4}"))
+}
+
+/// Busca um código no catálogo
+pub fn buscar(codigo: &str) -> Result<&'static Explicacao, String> {
+    let codigo = normalizar(codigo)?;
+    CATALOGO.iter().find(|e| e.codigo == codigo).ok_or_else(|| {
+        format!(
+            "{codigo} ainda não está no catálogo \
+             (em inglês: rustc --explain {codigo})"
+        )
+    })
+}
+
+pub fn imprimir(explicacao: &Explicacao) {
+    println!("{}: {}\n", explicacao.codigo, explicacao.titulo);
+    println!("{}\n", quebrar(explicacao.explicacao, 76).join("\n"));
+
+    if let Some(doc) = explicacao.doc() {
+        println!("Capítulo: {doc}");
+    }
+    println!("Exemplo:  {}", explicacao.exemplo);
+    println!("Caso:     tests/nao_compila/{}\n", explicacao.caso);
+
+    println!("Corrigido:");
+    for linha in explicacao.correcao.lines() {
+        if linha.is_empty() {
+            println!();
+        } else {
+            println!("    {linha}");
+        }
+    }
+}
+
+/// Quebra o texto em linhas de até `largura` caracteres
+fn quebrar(texto: &str, largura: usize) -> Vec<String> {
+    let mut linhas: Vec<String> = Vec::new();
+    for palavra in texto.split_whitespace() {
+        match linhas.last_mut() {
+            Some(linha)
+                if linha.chars().count() + 1 + palavra.chars().count()
+                    <= largura =>
+            {
+                linha.push(' ');
+                linha.push_str(palavra);
+            }
+            _ => linhas.push(palavra.to_string()),
+        }
+    }
+    linhas
+}
+
+// ============================================================================
+// DIAGNÓSTICOS EM JSON
+// ============================================================================
+
+/// Um erro ou aviso do compilador, tirado de uma linha de JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostico {
+    /// `error`, `warning`...
+    pub nivel: String,
+    pub codigo: Option<String>,
+    pub mensagem: String,
+    /// `arquivo:linha:coluna` do trecho principal, se houver
+    pub local: Option<String>,
+}
+
+/// Lê os diagnósticos de uma saída com um JSON por linha. Aceita tanto o
+/// `cargo --message-format=json` (a mensagem vem em `"message"` das linhas
+/// `"reason": "compiler-message"`) quanto o `rustc --error-format=json`
+/// (cada linha já é a mensagem). Linhas que não são JSON são ignoradas.
+pub fn diagnosticos(texto: &str) -> Vec<Diagnostico> {
+    texto
+        .lines()
+        .filter_map(|linha| Json::ler(linha.trim()).ok())
+        .filter_map(|json| match json.campo("reason") {
+            Some(Json::Texto(razao)) if razao == "compiler-message" => {
+                json.campo("message").and_then(diagnostico)
+            }
+            Some(_) => None,
+            None => diagnostico(&json),
+        })
+        .collect()
+}
+
+fn diagnostico(mensagem: &Json) -> Option<Diagnostico> {
+    let texto = |json: &Json, campo| match json.campo(campo) {
+        Some(Json::Texto(t)) => Some(t.clone()),
+        _ => None,
+    };
+
+    let local = match mensagem.campo("spans") {
+        Some(Json::Lista(spans)) => spans
+            .iter()
+            .find(|s| matches!(s.campo("is_primary"), Some(Json::Bool(true))))
+            .and_then(|span| {
+                let numero = |campo| match span.campo(campo) {
+                    Some(Json::Numero(n)) => Some(*n as u64),
+                    _ => None,
+                };
+                Some(format!(
+                    "{}:{}:{}",
+                    texto(span, "file_name")?,
+                    numero("line_start")?,
+                    numero("column_start")?
+                ))
+            }),
+        _ => None,
+    };
+
+    Some(Diagnostico {
+        nivel: texto(mensagem, "level")?,
+        codigo: mensagem.campo("code").and_then(|c| texto(c, "code")),
+        mensagem: texto(mensagem, "message")?,
+        local,
+    })
+}
+
+/// Imprime cada erro com código e a explicação dele (uma vez por código).
+/// Devolve quantos erros foram encontrados.
+pub fn explicar_diagnosticos(diagnosticos: &[Diagnostico]) -> usize {
+    let mut explicados = HashSet::new();
+    let erros: Vec<_> = diagnosticos
+        .iter()
+        .filter(|d| d.nivel == "error" && d.codigo.is_some())
+        .collect();
+
+    for (i, erro) in erros.iter().enumerate() {
+        let codigo = erro.codigo.as_deref().unwrap_or_default();
+        if i > 0 {
+            println!("\n{}", "─".repeat(60));
+        }
+        println!("error[{codigo}]: {}", erro.mensagem);
+        if let Some(local) = &erro.local {
+            println!("  --> {local}");
+        }
+        println!();
+
+        if !explicados.insert(codigo) {
+            println!("({codigo} já foi explicado acima)");
+            continue;
+        }
+        match buscar(codigo) {
+            Ok(explicacao) => imprimir(explicacao),
+            Err(erro) => println!("{erro}"),
+        }
+    }
+
+    if erros.is_empty() {
+        println!("Nenhum erro com código nos diagnósticos.");
+    }
+    erros.len()
+}
+
+/// O mínimo de JSON para ler os diagnósticos do compilador
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Nulo,
+    Bool(bool),
+    Numero(f64),
+    Texto(String),
+    Lista(Vec<Json>),
+    Objeto(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn ler(texto: &str) -> Result<Json, String> {
+        let mut leitor = Leitor {
+            texto: texto.as_bytes(),
+            pos: 0,
+        };
+        let valor = leitor.valor()?;
+        leitor.espacos();
+        if leitor.pos != leitor.texto.len() {
+            return Err(format!("sobrou texto na posição {}", leitor.pos));
+        }
+        Ok(valor)
+    }
+
+    fn campo(&self, nome: &str) -> Option<&Json> {
+        match self {
+            Json::Objeto(campos) => {
+                campos.iter().find(|(c, _)| c == nome).map(|(_, v)| v)
+            }
+            _ => None,
+        }
+    }
+}
+
+struct Leitor<'a> {
+    texto: &'a [u8],
+    pos: usize,
+}
+
+impl Leitor<'_> {
+    fn espacos(&mut self) {
+        while self
+            .texto
+            .get(self.pos)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn esperar(&mut self, byte: u8) -> Result<(), String> {
+        self.espacos();
+        if self.texto.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!(
+                "esperava '{}' na posição {}",
+                byte as char, self.pos
+            ))
+        }
+    }
+
+    fn palavra(&mut self, palavra: &str, valor: Json) -> Result<Json, String> {
+        if self.texto[self.pos..].starts_with(palavra.as_bytes()) {
+            self.pos += palavra.len();
+            Ok(valor)
+        } else {
+            Err(format!("valor inválido na posição {}", self.pos))
+        }
+    }
+
+    fn valor(&mut self) -> Result<Json, String> {
+        self.espacos();
+        match self.texto.get(self.pos) {
+            Some(b'{') => self.objeto(),
+            Some(b'[') => self.lista(),
+            Some(b'"') => self.texto().map(Json::Texto),
+            Some(b't') => self.palavra("true", Json::Bool(true)),
+            Some(b'f') => self.palavra("false", Json::Bool(false)),
+            Some(b'n') => self.palavra("null", Json::Nulo),
+            Some(_) => self.numero(),
+            None => Err("JSON terminou no meio".to_string()),
+        }
+    }
+
+    /// Os itens entre `abre` e `fecha`, separados por vírgula
+    fn itens<T>(
+        &mut self,
+        abre: u8,
+        fecha: u8,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        self.esperar(abre)?;
+        let mut itens = Vec::new();
+        self.espacos();
+        if self.texto.get(self.pos) == Some(&fecha) {
+            self.pos += 1;
+            return Ok(itens);
+        }
+        loop {
+            itens.push(item(self)?);
+            self.espacos();
+            match self.texto.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(&b) if b == fecha => {
+                    self.pos += 1;
+                    return Ok(itens);
+                }
+                _ => {
+                    return Err(format!(
+                        "esperava ',' na posição {}",
+                        self.pos
+                    ));
+                }
+            }
+        }
+    }
+
+    fn objeto(&mut self) -> Result<Json, String> {
+        self.itens(b'{', b'}', |leitor| {
+            leitor.espacos();
+            let nome = leitor.texto()?;
+            leitor.esperar(b':')?;
+            Ok((nome, leitor.valor()?))
+        })
+        .map(Json::Objeto)
+    }
+
+    fn lista(&mut self) -> Result<Json, String> {
+        self.itens(b'[', b']', Self::valor).map(Json::Lista)
+    }
+
+    fn numero(&mut self) -> Result<Json, String> {
+        let inicio = self.pos;
+        while self
+            .texto
+            .get(self.pos)
+            .is_some_and(|b| b"+-.eE".contains(b) || b.is_ascii_digit())
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.texto[inicio..self.pos])
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .map(Json::Numero)
+            .ok_or_else(|| format!("número inválido na posição {inicio}"))
+    }
+
+    fn texto(&mut self) -> Result<String, String> {
+        self.esperar(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = *self
+                .texto
+                .get(self.pos)
+                .ok_or("texto sem as aspas do fim")?;
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = *self
+                        .texto
+                        .get(self.pos)
+                        .ok_or("escape no fim do texto")?;
+                    self.pos += 1;
+                    match escape {
+                        b'n' => bytes.push(b'\n'),
+                        b't' => bytes.push(b'\t'),
+                        b'r' => bytes.push(b'\r'),
+                        b'b' => bytes.push(0x08),
+                        b'f' => bytes.push(0x0c),
+                        b'u' => {
+                            let c = self.unicode()?;
+                            let mut buf = [0; 4];
+                            bytes.extend(c.encode_utf8(&mut buf).as_bytes());
+                        }
+                        outro => bytes.push(outro),
+                    }
+                }
+                _ => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+
+    /// `\uXXXX`, juntando os pares de surrogates (`\ud83d\ude3b` = 😻)
+    fn unicode(&mut self) -> Result<char, String> {
+        let alto = self.hex4()?;
+        let codigo = if (0xD800..0xDC00).contains(&alto) {
+            if self.texto.get(self.pos..self.pos + 2) != Some(b"\\u") {
+                return Err("surrogate sem par".to_string());
+            }
+            self.pos += 2;
+            let baixo = self.hex4()?;
+            0x10000 + ((alto - 0xD800) << 10) + baixo.wrapping_sub(0xDC00)
+        } else {
+            alto
+        };
+        char::from_u32(codigo).ok_or_else(|| format!("\\u{codigo:x} inválido"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let codigo = self
+            .texto
+            .get(self.pos..self.pos + 4)
+            .and_then(|d| std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| format!("\\u inválido na posição {}", self.pos))?;
+        self.pos += 4;
+        Ok(codigo)
+    }
+}
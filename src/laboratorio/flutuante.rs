@@ -0,0 +1,462 @@
+// Ponto flutuante por dentro: como um `f32`/`f64` guarda um número (IEEE-754).
+//
+//   f64 = 1 bit de sinal | 11 bits de expoente | 52 bits de mantissa
+//   f32 = 1 bit de sinal |  8 bits de expoente | 23 bits de mantissa
+//
+//   valor = (-1)^sinal × 1.mantissa × 2^(expoente - viés)
+//
+// O "1." da frente não é guardado (é implícito), então a precisão real é de
+// 24 bits no `f32` e 53 no `f64`: 24 × log10(2) ≈ 7,2 dígitos decimais, daí os
+// "6-7 dígitos" da tabela de `tipos_de_dados`. Expoente todo em 0 são os zeros
+// e os subnormais (sem o 1 implícito); todo em 1 são os infinitos e o NaN.
+//
+// Entre dois floats vizinhos não existe nenhum outro: a distância entre eles
+// é o ULP ("unit in the last place"), e ela cresce com o valor.
+
+use std::{fmt, num::FpCategory, str::FromStr};
+
+/// Tipos aceitos pelo inspetor
+pub const TIPOS: [&str; 2] = ["f32", "f64"];
+
+/// O que o inspetor precisa saber de `f32` e `f64`
+pub trait Flutuante: Copy + PartialEq + fmt::Debug + FromStr {
+    const NOME: &'static str;
+    const BITS_EXPOENTE: u32;
+    const BITS_MANTISSA: u32;
+    /// Precisão decimal garantida (`f32::DIGITS`, `f64::DIGITS`)
+    const DIGITOS: u32;
+
+    fn para_bits(self) -> u64;
+    fn de_bits(bits: u64) -> Self;
+    fn para_f64(self) -> f64;
+    fn proximo(self) -> Self;
+    fn anterior(self) -> Self;
+    fn categoria(self) -> FpCategory;
+}
+
+macro_rules! flutuante {
+    ($t:ident, $bits:ty, $expoente:expr, $mantissa:expr) => {
+        impl Flutuante for $t {
+            const NOME: &'static str = stringify!($t);
+            const BITS_EXPOENTE: u32 = $expoente;
+            const BITS_MANTISSA: u32 = $mantissa;
+            const DIGITOS: u32 = $t::DIGITS;
+
+            fn para_bits(self) -> u64 {
+                self.to_bits().into()
+            }
+            fn de_bits(bits: u64) -> Self {
+                $t::from_bits(bits as $bits)
+            }
+            fn para_f64(self) -> f64 {
+                self.into()
+            }
+            fn proximo(self) -> Self {
+                self.next_up()
+            }
+            fn anterior(self) -> Self {
+                self.next_down()
+            }
+            fn categoria(self) -> FpCategory {
+                self.classify()
+            }
+        }
+    };
+}
+
+flutuante!(f32, u32, 8, 23);
+flutuante!(f64, u64, 11, 52);
+
+/// Nome da classe IEEE-754
+pub fn nome_da_categoria(categoria: FpCategory) -> &'static str {
+    match categoria {
+        FpCategory::Nan => "NaN",
+        FpCategory::Infinite => "infinito",
+        FpCategory::Zero => "zero",
+        FpCategory::Subnormal => "subnormal",
+        FpCategory::Normal => "normal",
+    }
+}
+
+/// Um float desmontado, pronto para imprimir
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inspecao {
+    pub tipo: &'static str,
+    /// O valor como o Rust o imprime (`{:?}`)
+    pub valor: String,
+    pub categoria: FpCategory,
+    pub negativo: bool,
+    /// Os campos crus, como inteiros
+    pub bits_expoente: u64,
+    pub bits_mantissa: u64,
+    /// Todos os bits, com espaço entre sinal, expoente e mantissa
+    pub bits: String,
+    /// Expoente sem o viés (`None` para infinitos e NaN)
+    pub expoente: Option<i32>,
+    /// `1.mantissa` (ou `0.mantissa` nos subnormais), em decimal
+    pub significando: Option<f64>,
+    /// O número que está guardado de verdade, com todos os dígitos
+    pub exato: String,
+    /// Os floats vizinhos (`None` quando não existem, como no NaN)
+    pub anterior: Option<String>,
+    pub proximo: Option<String>,
+    /// Distância até o vizinho (em módulo), em `f64`
+    pub ulp: Option<f64>,
+    pub digitos: u32,
+    pub bits_de_precisao: u32,
+}
+
+/// Desmonta `valor` em sinal, expoente e mantissa
+pub fn inspecionar<F: Flutuante>(valor: F) -> Inspecao {
+    let (e, m) = (F::BITS_EXPOENTE, F::BITS_MANTISSA);
+    let bits = valor.para_bits();
+    let bits_expoente = (bits >> m) & ((1 << e) - 1);
+    let bits_mantissa = bits & ((1 << m) - 1);
+    let vies = (1_i32 << (e - 1)) - 1;
+    let categoria = valor.categoria();
+
+    let (expoente, significando) = match categoria {
+        FpCategory::Nan | FpCategory::Infinite => (None, None),
+        // Zeros e subnormais usam o menor expoente, sem o 1 implícito
+        FpCategory::Zero | FpCategory::Subnormal => {
+            (Some(1 - vies), Some(fracao(bits_mantissa, m)))
+        }
+        FpCategory::Normal => (
+            Some(bits_expoente as i32 - vies),
+            Some(1.0 + fracao(bits_mantissa, m)),
+        ),
+    };
+
+    let finito = !matches!(categoria, FpCategory::Nan | FpCategory::Infinite);
+    let vizinho = |outro: F| {
+        (outro != valor && categoria != FpCategory::Nan)
+            .then(|| format!("{outro:?}"))
+    };
+    // Perto do MAX o próximo é infinito: vale a distância até o anterior
+    let ulp = finito.then(|| {
+        let proximo = valor.proximo().para_f64();
+        if proximo.is_finite() {
+            (proximo - valor.para_f64()).abs()
+        } else {
+            (valor.para_f64() - valor.anterior().para_f64()).abs()
+        }
+    });
+
+    Inspecao {
+        tipo: F::NOME,
+        valor: format!("{valor:?}"),
+        categoria,
+        negativo: bits >> (e + m) == 1,
+        bits_expoente,
+        bits_mantissa,
+        bits: format!(
+            "{} {:0e$b} {:0m$b}",
+            bits >> (e + m),
+            bits_expoente,
+            bits_mantissa,
+            e = e as usize,
+            m = m as usize
+        ),
+        expoente,
+        significando,
+        exato: exato(valor.para_f64()),
+        anterior: vizinho(valor.anterior()),
+        proximo: vizinho(valor.proximo()),
+        ulp,
+        digitos: F::DIGITOS,
+        bits_de_precisao: m + 1,
+    }
+}
+
+/// `mantissa / 2^bits`, a parte depois do "1."
+fn fracao(mantissa: u64, bits: u32) -> f64 {
+    mantissa as f64 / 2f64.powi(bits as i32)
+}
+
+/// Todos os dígitos decimais de um float (todo float finito é uma fração
+/// com denominador potência de 2, então a expansão decimal termina). Se
+/// não cabem numa linha, vira notação científica a partir do primeiro
+/// dígito diferente de zero: `4.940656…e-324 (751 dígitos significativos)`.
+pub fn exato(valor: f64) -> String {
+    const LIMITE: usize = 70;
+
+    if !valor.is_finite() || valor == 0.0 {
+        return format!("{valor:?}");
+    }
+
+    // valor = m × 2^-casas, com m ímpar: são `casas` dígitos depois da vírgula
+    let bits = valor.to_bits();
+    let (expoente, mantissa) = ((bits >> 52) & 0x7ff, bits & ((1 << 52) - 1));
+    let (m, e) = match expoente {
+        0 => (mantissa, -1074),
+        _ => (mantissa | 1 << 52, expoente as i64 - 1075),
+    };
+    let casas = (-(e + i64::from(m.trailing_zeros()))).max(0) as usize;
+
+    let texto = format!("{valor:.casas$}");
+    if texto.chars().count() <= LIMITE {
+        return texto;
+    }
+
+    // Os zeros à esquerda (milhares, num subnormal) não dizem nada
+    let (sinal, corpo) = match texto.strip_prefix('-') {
+        Some(corpo) => ("-", corpo),
+        None => ("", texto.as_str()),
+    };
+    let inteiros = corpo.find('.').unwrap_or(corpo.len());
+    let digitos: String = corpo.chars().filter(char::is_ascii_digit).collect();
+    let primeiro = digitos.find(|c| c != '0').unwrap_or(0);
+    let significativos = &digitos[primeiro..];
+    let expoente = inteiros as i64 - primeiro as i64 - 1;
+
+    let mostrados = &significativos[1..significativos.len().min(LIMITE - 30)];
+    format!(
+        "{sinal}{}.{mostrados}…e{expoente} ({} dígitos significativos)",
+        &significativos[..1],
+        significativos.len()
+    )
+}
+
+/// Quantos floats existem entre `a` e `b` (0 se forem iguais; `None` com NaN).
+/// `0.0` e `-0.0` estão a 0 de distância.
+pub fn distancia_ulps<F: Flutuante>(a: F, b: F) -> Option<u64> {
+    // Os bits de um float positivo crescem junto com o valor; espelhando os
+    // negativos, vizinhos viram inteiros consecutivos
+    let ordenar = |x: F| -> Option<i128> {
+        if x.categoria() == FpCategory::Nan {
+            return None;
+        }
+        let bits = x.para_bits();
+        let sinal = 1 << (F::BITS_EXPOENTE + F::BITS_MANTISSA);
+        let modulo = i128::from(bits & (sinal - 1));
+        Some(if bits & sinal != 0 { -modulo } else { modulo })
+    };
+    Some(ordenar(a)?.abs_diff(ordenar(b)?) as u64)
+}
+
+/// Lê um número (`0.1`, `-1e-40`, `inf`, `NaN`) ou os bits em hexadecimal
+/// (`0x3fb999999999999a`) e inspeciona no tipo pedido
+pub fn inspecionar_texto(texto: &str, tipo: &str) -> Result<Inspecao, String> {
+    match tipo {
+        "f32" => ler::<f32>(texto).map(inspecionar),
+        "f64" => ler::<f64>(texto).map(inspecionar),
+        _ => Err(format!(
+            "tipo de ponto flutuante desconhecido: {tipo} (use {})",
+            TIPOS.join(" ou ")
+        )),
+    }
+}
+
+fn ler<F: Flutuante>(texto: &str) -> Result<F, String> {
+    let texto = texto.trim();
+    let Some(hexa) = texto.strip_prefix("0x") else {
+        return texto.parse().map_err(|_| {
+            format!("{texto} não é um número de ponto flutuante")
+        });
+    };
+
+    let largura = 1 + F::BITS_EXPOENTE + F::BITS_MANTISSA;
+    match u64::from_str_radix(&hexa.replace('_', ""), 16) {
+        Ok(bits) if largura == 64 || bits >> largura == 0 => {
+            Ok(F::de_bits(bits))
+        }
+        Ok(_) => Err(format!("{texto} tem mais de {largura} bits")),
+        Err(_) => Err(format!("{texto} não é um hexadecimal válido")),
+    }
+}
+
+pub fn imprimir(inspecao: &Inspecao) {
+    let Inspecao {
+        tipo,
+        valor,
+        categoria,
+        ..
+    } = inspecao;
+    let (e, m) = match *tipo {
+        "f32" => (f32::BITS_EXPOENTE, f32::BITS_MANTISSA),
+        _ => (f64::BITS_EXPOENTE, f64::BITS_MANTISSA),
+    };
+    let vies = (1_i32 << (e - 1)) - 1;
+
+    println!("{valor} como {tipo}\n");
+    println!("  classe     {}", nome_da_categoria(*categoria));
+    println!("  bits       {}", inspecao.bits);
+    println!(
+        "             ↑ sinal, {e} bits de expoente, {m} bits de mantissa"
+    );
+    println!(
+        "  sinal      {}",
+        if inspecao.negativo { "- (1)" } else { "+ (0)" }
+    );
+
+    let sinal = if inspecao.negativo { "-" } else { "+" };
+    match (categoria, inspecao.expoente, inspecao.significando) {
+        (FpCategory::Nan, ..) => println!(
+            "  expoente   todo em 1 e mantissa diferente de 0: NaN \
+             (nem igual a si mesmo)"
+        ),
+        (FpCategory::Infinite, ..) => {
+            println!("  expoente   todo em 1 e mantissa 0: infinito")
+        }
+        (FpCategory::Zero, ..) => println!(
+            "  expoente   todo em 0 e mantissa 0: zero (o sinal é o único \
+             bit que muda entre 0.0 e -0.0)"
+        ),
+        (FpCategory::Normal, Some(expoente), Some(significando)) => {
+            println!(
+                "  expoente   {} - {vies} (viés) = {expoente}",
+                inspecao.bits_expoente
+            );
+            let mantissa =
+                format!("{:0m$b}", inspecao.bits_mantissa, m = m as usize);
+            let mantissa = match mantissa.trim_end_matches('0') {
+                "" => "0",
+                bits => bits,
+            };
+            println!("  mantissa   1.{mantissa} em binário = {significando:?}");
+            println!("  valor      {sinal}{significando:?} × 2^{expoente}");
+        }
+        (_, Some(expoente), Some(significando)) => {
+            println!(
+                "  expoente   todo em 0: sem o 1 implícito, 2^{expoente} fixo"
+            );
+            println!("  valor      {sinal}{significando:?} × 2^{expoente}");
+        }
+        _ => {}
+    }
+
+    println!("  guardado   {}", inspecao.exato);
+    let ou_nada = |texto: &Option<String>| {
+        texto.clone().unwrap_or_else(|| "(nenhum)".to_string())
+    };
+    println!("  anterior   {}", ou_nada(&inspecao.anterior));
+    println!("  próximo    {}", ou_nada(&inspecao.proximo));
+    if let Some(ulp) = inspecao.ulp {
+        println!("  ULP        {ulp:e} (distância até o vizinho)");
+    }
+    println!(
+        "  precisão   {} bits ≈ {:.2} dígitos decimais ({tipo}::DIGITS = {})",
+        inspecao.bits_de_precisao,
+        f64::from(inspecao.bits_de_precisao) * 2f64.log10(),
+        inspecao.digitos
+    );
+
+    if *categoria == FpCategory::Subnormal {
+        println!(
+            "\n⚠️ Subnormal: menor que o menor normal ({tipo}::MIN_POSITIVE), \
+             perde precisão a cada bit que o expoente não alcança."
+        );
+    }
+}
+
+/// Um resultado de ponto flutuante que costuma surpreender
+#[derive(Debug, Clone, PartialEq)]
+pub struct Surpresa {
+    pub expressao: &'static str,
+    pub resultado: String,
+    pub explicacao: String,
+}
+
+/// As pegadinhas clássicas, calculadas na hora
+// O clippy avisa que `nan == nan` e `0.1_f32 + 0.2_f32 == 0.3_f32` têm
+// resultado conhecido: é justamente o que a tabela quer mostrar
+#[allow(clippy::eq_op)]
+pub fn surpresas() -> Vec<Surpresa> {
+    let soma = 0.1 + 0.2;
+    let ulps = distancia_ulps(soma, 0.3).unwrap_or(0);
+    let mut dez_vezes = 0.0;
+    for _ in 0..10 {
+        dez_vezes += 0.1;
+    }
+    let nan: f64 = "NaN".parse().unwrap_or_default();
+    let grande = 1e16_f64;
+
+    vec![
+        Surpresa {
+            expressao: "0.1 + 0.2",
+            resultado: format!("{soma:?}"),
+            explicacao: format!(
+                "nem 0.1 nem 0.2 existem em binário; a soma dos dois mais \
+                 próximos cai a {ulps} ULP do 0.3 mais próximo"
+            ),
+        },
+        Surpresa {
+            expressao: "0.1 + 0.2 == 0.3",
+            resultado: (soma == 0.3).to_string(),
+            explicacao: "compare com uma tolerância: \
+                         (a - b).abs() < 1e-9"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "0.1_f32 + 0.2_f32 == 0.3_f32",
+            resultado: (0.1_f32 + 0.2_f32 == 0.3_f32).to_string(),
+            explicacao: "em f32 o arredondamento da soma coincide: a \
+                         igualdade depende do tipo, não da matemática"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "0.1 somado 10 vezes",
+            resultado: format!("{dez_vezes:?}"),
+            explicacao: "cada soma arredonda de novo, e os erros acumulam"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "56.7 / 32.2",
+            resultado: format!("{:?}", 56.7 / 32.2),
+            explicacao: "o `_quotient` de `operacoes_basicas`: dividendo e \
+                         divisor já chegam arredondados"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "1e16 + 1.0 == 1e16",
+            resultado: (grande + 1.0 == grande).to_string(),
+            explicacao: format!(
+                "o ULP em 1e16 é {}: somar 1 não chega ao vizinho",
+                inspecionar(grande).ulp.unwrap_or_default()
+            ),
+        },
+        Surpresa {
+            expressao: "16_777_217_i32 as f32",
+            resultado: format!("{:?}", 16_777_217_i32 as f32),
+            explicacao: "o f32 tem 24 bits de precisão: 2^24 + 1 não cabe"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "NaN == NaN",
+            resultado: (nan == nan).to_string(),
+            explicacao: "NaN não é igual a nada, nem a si mesmo (use \
+                         `.is_nan()`)"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "0.0 == -0.0",
+            resultado: (0.0 == -0.0_f64).to_string(),
+            explicacao: "são iguais, mas com bits diferentes (o de sinal)"
+                .to_string(),
+        },
+        Surpresa {
+            expressao: "1.0 / 0.0",
+            resultado: format!("{:?}", 1.0 / 0.0_f64),
+            explicacao: "dividir float por zero não entra em pânico: dá \
+                         infinito (com inteiros, entra)"
+                .to_string(),
+        },
+    ]
+}
+
+pub fn imprimir_surpresas(surpresas: &[Surpresa]) {
+    let largura = surpresas
+        .iter()
+        .map(|s| s.expressao.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for surpresa in surpresas {
+        println!(
+            "{:<largura$}  =  {}",
+            surpresa.expressao, surpresa.resultado
+        );
+        println!("{:<largura$}     ↳ {}", "", surpresa.explicacao);
+    }
+    println!("\nPara ver os bits de qualquer um: cargo run -- float 0.1");
+}
@@ -0,0 +1,693 @@
+// Como os tipos do projeto ficam na memória: tamanho, alinhamento e onde cada
+// campo começa (`offset`).
+//
+// Duas coisas que o código dos capítulos não mostra:
+//
+//   - O compilador reordena os campos de uma struct para gastar menos
+//     padding: em `User`, o `active: bool` declarado primeiro vai para o fim.
+//   - Um enum precisa guardar qual variante está ativa (a "tag"), mas às
+//     vezes ela cabe num valor que o dado nunca usa (o "nicho"): a capacidade
+//     de uma `String` nunca passa de `isize::MAX`, então `Message` inteiro
+//     cabe nos 24 bytes de uma `String`, e `Option<User>` tem o tamanho de
+//     `User`.
+//
+// Os offsets das structs vêm de `offset_of!`. Nos enums ele ainda não é
+// estável, então cada variante é construída e o endereço de cada campo é
+// comparado com o do valor inteiro.
+//
+// A segunda parte classifica um ponteiro como stack, heap ou memória estática
+// comparando com endereços conhecidos de cada região.
+
+use std::{
+    any::type_name,
+    mem::{align_of, offset_of, size_of},
+};
+
+use crate::enums::definindo_enums::{IpAddr, IpAddrDiff, Message};
+use crate::enums::if_let::Coin;
+use crate::structs::definindo_structs::User;
+use crate::structs::metodos::Rectangle;
+
+/// Um campo e os bytes que ele ocupa
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Campo {
+    pub nome: String,
+    pub tipo: String,
+    pub offset: usize,
+    pub tamanho: usize,
+}
+
+impl Campo {
+    fn new<C>(nome: &str, offset: usize) -> Self {
+        Self {
+            nome: nome.to_string(),
+            tipo: nome_curto(type_name::<C>()),
+            offset,
+            tamanho: size_of::<C>(),
+        }
+    }
+
+    /// Campo de uma variante: o offset é a distância até o início do valor
+    fn medir<T, C>(nome: &str, valor: &T, campo: &C) -> Self {
+        let inicio = valor as *const T as usize;
+        let endereco = campo as *const C as usize;
+        Self::new::<C>(nome, endereco - inicio)
+    }
+
+    pub fn fim(&self) -> usize {
+        self.offset + self.tamanho
+    }
+}
+
+/// `alloc::string::String` → `String`, `core::option::Option<u32>` →
+/// `Option<u32>`
+fn nome_curto(nome: &str) -> String {
+    let mut curto = String::new();
+    let mut palavra = String::new();
+    for c in nome.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            palavra.push(c);
+        } else {
+            curto += palavra.rsplit("::").next().unwrap_or_default();
+            palavra.clear();
+            curto.push(c);
+        }
+    }
+    curto += palavra.rsplit("::").next().unwrap_or_default();
+    curto
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variante {
+    pub nome: &'static str,
+    pub campos: Vec<Campo>,
+}
+
+/// O layout de um tipo. Structs têm só `campos`; enums têm `variantes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub nome: &'static str,
+    /// Onde o tipo está definido
+    pub modulo: &'static str,
+    pub tamanho: usize,
+    pub alinhamento: usize,
+    pub campos: Vec<Campo>,
+    pub variantes: Vec<Variante>,
+    pub observacao: Option<String>,
+}
+
+impl Layout {
+    fn new<T>(nome: &'static str, modulo: &'static str) -> Self {
+        Self {
+            nome,
+            modulo,
+            tamanho: size_of::<T>(),
+            alinhamento: align_of::<T>(),
+            campos: Vec::new(),
+            variantes: Vec::new(),
+            observacao: None,
+        }
+    }
+
+    /// Bytes que nenhum campo usa (padding ou tag), em trechos
+    pub fn livres(&self, campos: &[Campo]) -> Vec<(usize, usize)> {
+        let mut ocupado = vec![false; self.tamanho];
+        for campo in campos {
+            ocupado[campo.offset..campo.fim()].fill(true);
+        }
+
+        let mut trechos = Vec::new();
+        let mut i = 0;
+        while i < self.tamanho {
+            if ocupado[i] {
+                i += 1;
+                continue;
+            }
+            let inicio = i;
+            while i < self.tamanho && !ocupado[i] {
+                i += 1;
+            }
+            trechos.push((inicio, i));
+        }
+        trechos
+    }
+}
+
+/// `User` de `structs::definindo_structs`
+fn layout_user() -> Layout {
+    let mut campos = vec![
+        Campo::new::<bool>("active", offset_of!(User, active)),
+        Campo::new::<String>("username", offset_of!(User, username)),
+        Campo::new::<String>("email", offset_of!(User, email)),
+        Campo::new::<u64>("sign_in_count", offset_of!(User, sign_in_count)),
+    ];
+    campos.sort_by_key(|c| c.offset);
+
+    Layout {
+        campos,
+        observacao: (offset_of!(User, active) != 0).then(|| {
+            format!(
+                "`active` foi declarado primeiro, mas está no byte {}: o \
+                 compilador reordena os campos para juntar o padding no fim",
+                offset_of!(User, active)
+            )
+        }),
+        ..Layout::new::<User>("User", "structs::definindo_structs")
+    }
+}
+
+fn layout_rectangle() -> Layout {
+    Layout {
+        campos: vec![
+            Campo::new::<u32>("width", offset_of!(Rectangle, width)),
+            Campo::new::<u32>("height", offset_of!(Rectangle, height)),
+        ],
+        ..Layout::new::<Rectangle>("Rectangle", "structs::metodos")
+    }
+}
+
+fn layout_message() -> Layout {
+    let variantes = vec![
+        Variante {
+            nome: "Quit",
+            campos: Vec::new(),
+        },
+        {
+            let valor = Message::Move { x: 0, y: 0 };
+            let Message::Move { x, y } = &valor else {
+                unreachable!()
+            };
+            Variante {
+                nome: "Move",
+                campos: vec![
+                    Campo::medir("x", &valor, x),
+                    Campo::medir("y", &valor, y),
+                ],
+            }
+        },
+        {
+            let valor = Message::Write(String::new());
+            let Message::Write(texto) = &valor else {
+                unreachable!()
+            };
+            Variante {
+                nome: "Write",
+                campos: vec![Campo::medir("0", &valor, texto)],
+            }
+        },
+        {
+            let valor = Message::ChangeColor(0, 0, 0);
+            let Message::ChangeColor(r, g, b) = &valor else {
+                unreachable!()
+            };
+            Variante {
+                nome: "ChangeColor",
+                campos: vec![
+                    Campo::medir("0", &valor, r),
+                    Campo::medir("1", &valor, g),
+                    Campo::medir("2", &valor, b),
+                ],
+            }
+        },
+    ];
+
+    Layout {
+        variantes,
+        observacao: nicho::<Message, String>().then(|| {
+            format!(
+                "do tamanho de uma String ({} bytes): a tag das outras \
+                 variantes mora num valor de capacidade que a String nunca \
+                 usa (nicho)",
+                size_of::<String>()
+            )
+        }),
+        ..Layout::new::<Message>("Message", "enums::definindo_enums")
+    }
+}
+
+fn layout_ip_addr() -> Layout {
+    let variante = |nome, valor: IpAddr| {
+        let (IpAddr::V4(texto) | IpAddr::V6(texto)) = &valor;
+        Variante {
+            nome,
+            campos: vec![Campo::medir("0", &valor, texto)],
+        }
+    };
+
+    Layout {
+        variantes: vec![
+            variante("V4", IpAddr::V4(String::new())),
+            variante("V6", IpAddr::V6(String::new())),
+        ],
+        observacao: (!nicho::<IpAddr, String>()).then(|| {
+            format!(
+                "as duas variantes usam a String inteira, então não sobra \
+                 nicho: a tag ganha {} bytes só para ela (com o padding)",
+                size_of::<IpAddr>() - size_of::<String>()
+            )
+        }),
+        ..Layout::new::<IpAddr>("IpAddr", "enums::definindo_enums")
+    }
+}
+
+fn layout_ip_addr_diff() -> Layout {
+    let v4 = {
+        let valor = IpAddrDiff::V4(127, 0, 0, 1);
+        let IpAddrDiff::V4(a, b, c, d) = &valor else {
+            unreachable!()
+        };
+        Variante {
+            nome: "V4",
+            campos: vec![
+                Campo::medir("0", &valor, a),
+                Campo::medir("1", &valor, b),
+                Campo::medir("2", &valor, c),
+                Campo::medir("3", &valor, d),
+            ],
+        }
+    };
+    let v6 = {
+        let valor = IpAddrDiff::V6(String::new());
+        let IpAddrDiff::V6(texto) = &valor else {
+            unreachable!()
+        };
+        Variante {
+            nome: "V6",
+            campos: vec![Campo::medir("0", &valor, texto)],
+        }
+    };
+
+    Layout {
+        variantes: vec![v4, v6],
+        observacao: nicho::<IpAddrDiff, String>().then(|| {
+            "os 4 bytes de V4 cabem ao lado do nicho da String de V6".into()
+        }),
+        ..Layout::new::<IpAddrDiff>("IpAddrDiff", "enums::definindo_enums")
+    }
+}
+
+fn layout_coin() -> Layout {
+    let quarter = {
+        let valor = Coin::Quarter(String::new());
+        let Coin::Quarter(estado) = &valor else {
+            unreachable!()
+        };
+        Variante {
+            nome: "Quarter",
+            campos: vec![Campo::medir("0", &valor, estado)],
+        }
+    };
+    let sem_dados = |nome| Variante {
+        nome,
+        campos: Vec::new(),
+    };
+
+    Layout {
+        variantes: vec![
+            sem_dados("Penny"),
+            sem_dados("Nickel"),
+            sem_dados("Dime"),
+            quarter,
+        ],
+        ..Layout::new::<Coin>("Coin", "enums::if_let")
+    }
+}
+
+fn layout_option_user() -> Layout {
+    let valor = Some(User {
+        active: true,
+        username: String::new(),
+        email: String::new(),
+        sign_in_count: 0,
+    });
+    let Some(user) = &valor else { unreachable!() };
+
+    Layout {
+        variantes: vec![
+            Variante {
+                nome: "None",
+                campos: Vec::new(),
+            },
+            Variante {
+                nome: "Some",
+                campos: vec![Campo::medir("0", &valor, user)],
+            },
+        ],
+        observacao: nicho::<Option<User>, User>().then(|| {
+            format!(
+                "Option<User> tem os mesmos {} bytes de User: o None é um \
+                 valor que nenhum User válido tem",
+                size_of::<User>()
+            )
+        }),
+        ..Layout::new::<Option<User>>(
+            "Option<User>",
+            "structs::definindo_structs",
+        )
+    }
+}
+
+/// O enum `E` cabe no tamanho do seu maior dado `D` (a tag foi para um nicho)
+fn nicho<E, D>() -> bool {
+    size_of::<E>() == size_of::<D>()
+}
+
+/// Os tipos do projeto, na ordem dos capítulos
+pub fn tipos_do_projeto() -> Vec<Layout> {
+    vec![
+        layout_user(),
+        layout_rectangle(),
+        layout_ip_addr(),
+        layout_ip_addr_diff(),
+        layout_message(),
+        layout_coin(),
+        layout_option_user(),
+    ]
+}
+
+/// Busca um tipo pelo nome (`User`, `Option<User>`)
+pub fn buscar(nome: &str) -> Result<Layout, String> {
+    let tipos = tipos_do_projeto();
+    let nomes: Vec<&str> = tipos.iter().map(|t| t.nome).collect();
+    let nomes = nomes.join(", ");
+    tipos
+        .into_iter()
+        .find(|t| t.nome.eq_ignore_ascii_case(nome))
+        .ok_or_else(|| format!("tipo desconhecido: {nome} (use {nomes})"))
+}
+
+/// Uma faixa de bytes com o nome de cada campo: `[username───]░░`
+/// (`░` é padding ou tag). Um caractere por byte, até 64 bytes.
+pub fn faixa(layout: &Layout, campos: &[Campo]) -> String {
+    let escala = layout.tamanho.div_ceil(64).max(1);
+    let mut trechos: Vec<(usize, usize, Option<&str>)> = campos
+        .iter()
+        .map(|c| (c.offset, c.fim(), Some(c.nome.as_str())))
+        .chain(
+            layout
+                .livres(campos)
+                .into_iter()
+                .map(|(inicio, fim)| (inicio, fim, None)),
+        )
+        .collect();
+    trechos.sort();
+
+    trechos
+        .into_iter()
+        .map(|(inicio, fim, nome)| {
+            let largura = (fim - inicio).div_ceil(escala);
+            match (nome, largura) {
+                (None, _) => "░".repeat(largura),
+                (Some(_), 1) => "▪".to_string(),
+                (Some(_), 2) => "[]".to_string(),
+                (Some(nome), _) => {
+                    let dentro: String = nome
+                        .chars()
+                        .chain(std::iter::repeat('─'))
+                        .take(largura - 2)
+                        .collect();
+                    format!("[{dentro}]")
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn imprimir(layout: &Layout) {
+    println!(
+        "{} ({}): {} bytes, alinhamento {}",
+        layout.nome, layout.modulo, layout.tamanho, layout.alinhamento
+    );
+
+    let linha = |campo: &Campo| {
+        println!(
+            "  {:>6}  {:>7}  {}: {}",
+            campo.offset, campo.tamanho, campo.nome, campo.tipo
+        )
+    };
+
+    if layout.variantes.is_empty() {
+        println!("  {:>6}  {:>7}  campo", "offset", "tamanho");
+        layout.campos.iter().for_each(linha);
+        for (inicio, fim) in layout.livres(&layout.campos) {
+            println!("  {inicio:>6}  {:>7}  (padding)", fim - inicio);
+        }
+        println!("  {}", faixa(layout, &layout.campos));
+    } else {
+        for variante in &layout.variantes {
+            let faixa = faixa(layout, &variante.campos);
+            println!("  {:<12} {faixa}", variante.nome);
+            variante.campos.iter().for_each(|campo| {
+                println!(
+                    "  {:<12}   {}: {} em {}..{}",
+                    "",
+                    campo.nome,
+                    campo.tipo,
+                    campo.offset,
+                    campo.fim()
+                )
+            });
+        }
+        println!(
+            "  (░ = bytes sem campo nessa variante: tag, nicho ou padding)"
+        );
+    }
+
+    if let Some(observacao) = &layout.observacao {
+        println!("  ↳ {observacao}");
+    }
+}
+
+// ============================================================================
+// STACK, HEAP OU ESTÁTICA
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regiao {
+    Stack,
+    Heap,
+    Estatica,
+    /// Um trecho de arquivo que não é o binário (biblioteca, vdso) ou um
+    /// endereço fora de qualquer trecho
+    Outro,
+}
+
+impl Regiao {
+    pub const TODAS: [Regiao; 4] =
+        [Regiao::Stack, Regiao::Heap, Regiao::Estatica, Regiao::Outro];
+
+    pub fn nome(self) -> &'static str {
+        match self {
+            Regiao::Stack => "stack",
+            Regiao::Heap => "heap",
+            Regiao::Estatica => "estática",
+            Regiao::Outro => "outro mapeamento",
+        }
+    }
+}
+
+/// Um endereço conhecido da memória estática (os literais ficam juntos)
+static MARCO_ESTATICO: &str = "marco da memória estática";
+
+/// Classifica o ponteiro (veja `localizar`)
+pub fn classificar<T: ?Sized>(ponteiro: *const T) -> Regiao {
+    localizar(ponteiro).0
+}
+
+/// A região do ponteiro e o caminho do mapeamento em que ele cai (vazio se
+/// anônimo). Os marcos são tirados na hora: uma variável local, um `Box`
+/// novo e um literal. No Linux, o `/proc/self/maps` diz o trecho de cada um
+/// (veja `pelos_mapas`); sem o `/proc`, vale o marco mais próximo e o
+/// caminho fica vazio.
+#[inline(never)]
+pub fn localizar<T: ?Sized>(ponteiro: *const T) -> (Regiao, String) {
+    let local = 0_u8;
+    let alocado = Box::new(0_u8);
+    let endereco = ponteiro as *const () as usize;
+    let pilha = &local as *const u8 as usize;
+    let heap = &*alocado as *const u8 as usize;
+    let estatico = MARCO_ESTATICO.as_ptr() as usize;
+
+    match std::fs::read_to_string("/proc/self/maps") {
+        Ok(mapas) => {
+            let (regiao, caminho) =
+                pelos_mapas(&mapas, endereco, pilha, heap, estatico);
+            (regiao, caminho.to_string())
+        }
+        Err(_) => (
+            pelo_marco_mais_proximo(endereco, pilha, heap, estatico),
+            String::new(),
+        ),
+    }
+}
+
+/// Cada linha do `/proc/self/maps`: `inicio-fim permissões ... caminho`.
+///
+/// - stack: o trecho `[stack]` ou o da variável local (outras threads têm a
+///   pilha num mapeamento anônimo)
+/// - heap: o trecho `[heap]`, o do `Box` de marco e qualquer mapeamento
+///   anônimo de leitura e escrita. O malloc não usa só o `[heap]`: cada
+///   thread tem a sua arena, e um bloco grande vem direto de um `mmap`.
+/// - estática: o trecho do binário (o mesmo arquivo do `static`)
+///
+/// O resto é `Outro`, com o caminho do trecho.
+pub fn pelos_mapas(
+    mapas: &str,
+    endereco: usize,
+    pilha: usize,
+    heap: usize,
+    estatico: usize,
+) -> (Regiao, &str) {
+    let trechos: Vec<(usize, usize, &str, &str)> = mapas
+        .lines()
+        .filter_map(|linha| {
+            let mut partes = linha.split_whitespace();
+            let (inicio, fim) = partes.next()?.split_once('-')?;
+            let inicio = usize::from_str_radix(inicio, 16).ok()?;
+            let fim = usize::from_str_radix(fim, 16).ok()?;
+            let permissoes = partes.next()?;
+            Some((inicio, fim, permissoes, partes.nth(3).unwrap_or("")))
+        })
+        .collect();
+    let trecho = |alvo: usize| {
+        trechos
+            .iter()
+            .find(|&&(inicio, fim, _, _)| (inicio..fim).contains(&alvo))
+    };
+
+    let binario = trecho(estatico).map(|&(_, _, _, caminho)| caminho);
+    let Some(&(inicio, fim, permissoes, caminho)) = trecho(endereco) else {
+        return (Regiao::Outro, "");
+    };
+    let contem = |marco: usize| (inicio..fim).contains(&marco);
+    let regiao = match caminho {
+        "[stack]" => Regiao::Stack,
+        _ if contem(pilha) => Regiao::Stack,
+        "[heap]" => Regiao::Heap,
+        _ if contem(heap) => Regiao::Heap,
+        "" if permissoes.starts_with("rw") => Regiao::Heap,
+        _ if !caminho.is_empty() && Some(caminho) == binario => {
+            Regiao::Estatica
+        }
+        _ => Regiao::Outro,
+    };
+    (regiao, caminho)
+}
+
+fn pelo_marco_mais_proximo(
+    endereco: usize,
+    pilha: usize,
+    heap: usize,
+    estatico: usize,
+) -> Regiao {
+    let marcos = [
+        (Regiao::Stack, pilha),
+        (Regiao::Heap, heap),
+        (Regiao::Estatica, estatico),
+    ];
+
+    marcos
+        .into_iter()
+        .min_by_key(|&(_, marco)| marco.abs_diff(endereco))
+        .map_or(Regiao::Stack, |(regiao, _)| regiao)
+}
+
+/// Um valor do exemplo: o nome e para onde o ponteiro aponta
+#[derive(Debug, Clone)]
+pub struct Endereco {
+    pub nome: String,
+    pub endereco: usize,
+    pub regiao: Regiao,
+    /// O caminho do mapeamento (`[heap]`, o binário, uma `.so`); vazio se
+    /// anônimo
+    pub mapeamento: String,
+}
+
+impl Endereco {
+    pub fn new<T: ?Sized>(nome: &str, ponteiro: *const T) -> Self {
+        let (regiao, mapeamento) = localizar(ponteiro);
+        Self {
+            nome: nome.to_string(),
+            endereco: ponteiro as *const () as usize,
+            regiao,
+            mapeamento,
+        }
+    }
+}
+
+/// Lê um endereço em hexadecimal, com ou sem `0x`
+pub fn ler_endereco(texto: &str) -> Result<usize, String> {
+    let digitos = texto
+        .strip_prefix("0x")
+        .or_else(|| texto.strip_prefix("0X"))
+        .unwrap_or(texto);
+    usize::from_str_radix(digitos, 16)
+        .map_err(|_| format!("endereço inválido: {texto} (use hexadecimal)"))
+}
+
+/// Os endereços pedidos (`layout --where`) junto com um marco de cada
+/// região. Um endereço só faz sentido no processo que o leu, e os deste
+/// mudam a cada execução: os marcos mostram onde cada região está agora.
+pub fn onde(enderecos: &[usize]) -> Vec<Endereco> {
+    let local = 0_u8;
+    let alocado = Box::new(0_u8);
+    let mut lista = vec![
+        Endereco::new("(marco) variável local", &local),
+        Endereco::new("(marco) Box::new(0_u8)", &*alocado),
+        Endereco::new("(marco) literal", MARCO_ESTATICO.as_ptr()),
+    ];
+    lista.extend(
+        enderecos
+            .iter()
+            .map(|&endereco| Endereco::new("pedido", endereco as *const u8)),
+    );
+    lista
+}
+
+/// O desenho das três regiões com os valores dentro de cada uma. A ordem é
+/// a de costume (stack em cima, estática embaixo), não a dos endereços. Um
+/// valor fora das três aparece numa quarta caixa, com o caminho do trecho.
+pub fn diagrama(enderecos: &[Endereco]) -> Vec<String> {
+    const LARGURA: usize = 58;
+    let borda = |esquerda: &str, direita: &str| {
+        format!("{esquerda}{}{direita}", "─".repeat(LARGURA))
+    };
+    let caixa = |texto: String| {
+        let espaco = LARGURA.saturating_sub(texto.chars().count() + 1);
+        format!("│ {texto}{}│", " ".repeat(espaco))
+    };
+
+    let mut linhas = vec![borda("┌", "┐")];
+    for (i, regiao) in Regiao::TODAS.into_iter().enumerate() {
+        let itens: Vec<_> =
+            enderecos.iter().filter(|e| e.regiao == regiao).collect();
+        if regiao == Regiao::Outro && itens.is_empty() {
+            continue;
+        }
+        if i > 0 {
+            linhas.push(borda("├", "┤"));
+        }
+        let titulo = match regiao {
+            Regiao::Stack => "STACK (cada função empilha as suas variáveis)",
+            Regiao::Heap => "HEAP (alocado em tempo de execução)",
+            Regiao::Estatica => "ESTÁTICA (embutida no binário)",
+            Regiao::Outro => "OUTRO MAPEAMENTO (bibliotecas, vdso...)",
+        };
+        linhas.push(caixa(titulo.to_string()));
+        for item in itens {
+            linhas.push(caixa(format!(
+                "  {:#018x}  {}",
+                item.endereco, item.nome
+            )));
+            if regiao == Regiao::Outro {
+                let caminho = match item.mapeamento.as_str() {
+                    "" => "fora de qualquer mapeamento",
+                    caminho => caminho,
+                };
+                linhas.push(caixa(format!("    ↳ {caminho}")));
+            }
+        }
+    }
+    linhas.push(borda("└", "┘"));
+    linhas
+}
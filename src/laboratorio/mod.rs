@@ -0,0 +1,38 @@
+// ============================================================================
+// LABORATÓRIO
+// ============================================================================
+//
+// Ferramentas para explorar, com valores de verdade, o que os capítulos
+// explicam em comentários. Cada uma é uma biblioteca pequena usada por um
+// comando do executável (veja `src/main.rs`):
+//
+//   - `tipos`: a tabela dos tipos primitivos, gerada de `MIN`/`MAX`,
+//              `size_of` e `align_of` (`resolution types`)
+//   - `bases`: literais inteiros em decimal, hexa, octal, binário e `b'A'`,
+//              e os bits em complemento de dois (`resolution base`)
+//   - `conversoes`: `as`, `From` e `TryFrom` entre todos os pares de tipos
+//              primitivos (`resolution convert`)
+//   - `erros`: os códigos de erro do compilador (E0502...) explicados em
+//              português, com o capítulo e a correção, também a partir do
+//              JSON do `cargo build` (`resolution explain`)
+//   - `flutuante`: os bits de um `f32`/`f64` (sinal, expoente e mantissa),
+//              vizinhos, ULP e as pegadinhas como `0.1 + 0.2` (`resolution float`)
+//   - `layout`: tamanho, alinhamento e offsets dos tipos do projeto, e se um
+//              ponteiro aponta para a stack, a heap ou a memória estática
+//              (`resolution layout`)
+//   - `overflow`: checked, wrapping, saturating e overflowing lado a lado,
+//              e os operadores comuns em debug e release (`resolution overflow`)
+//   - `simulador`: um mini-Rust interpretado passo a passo, com a linha do
+//              tempo de donos e empréstimos e os erros do borrow checker
+//              (`resolution borrow`)
+//
+// ============================================================================
+
+pub mod bases;
+pub mod conversoes;
+pub mod erros;
+pub mod flutuante;
+pub mod layout;
+pub mod overflow;
+pub mod simulador;
+pub mod tipos;
@@ -0,0 +1,328 @@
+// Estouro de inteiros: o que cada família de métodos faz quando o resultado
+// não cabe no tipo.
+//
+//   checked_*     → `None` no estouro
+//   wrapping_*    → dá a volta (módulo 2^bits)
+//   saturating_*  → para no `MIN`/`MAX`
+//   overflowing_* → o valor que deu a volta + `true` se estourou
+//
+// E os operadores comuns (`+ - * / %`)? Depende do perfil: em debug
+// (`cargo run`) o estouro entra em pânico ("attempt to multiply with
+// overflow"); em release (`cargo run --release`) ele dá a volta como o
+// `wrapping_*`, sem nenhum aviso. Divisão por zero e `MIN / -1` entram em
+// pânico nos dois perfis. O laboratório roda num perfil só, então as duas
+// colunas são deduzidas dos métodos: é como *seria* em cada perfil.
+
+use std::num::IntErrorKind;
+
+/// Tipos aceitos pelo laboratório
+pub const TIPOS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operacao {
+    Soma,
+    Subtracao,
+    Multiplicacao,
+    Divisao,
+    Resto,
+}
+
+impl Operacao {
+    pub const TODAS: [Operacao; 5] = [
+        Operacao::Soma,
+        Operacao::Subtracao,
+        Operacao::Multiplicacao,
+        Operacao::Divisao,
+        Operacao::Resto,
+    ];
+
+    pub fn simbolo(self) -> &'static str {
+        match self {
+            Operacao::Soma => "+",
+            Operacao::Subtracao => "-",
+            Operacao::Multiplicacao => "*",
+            Operacao::Divisao => "/",
+            Operacao::Resto => "%",
+        }
+    }
+
+    /// A mensagem do pânico do operador comum, igual à do Rust
+    fn panico(self, divisor_zero: bool) -> &'static str {
+        match (self, divisor_zero) {
+            (Operacao::Divisao, true) => "attempt to divide by zero",
+            (Operacao::Resto, true) => {
+                "attempt to calculate the remainder with a divisor of zero"
+            }
+            (Operacao::Soma, _) => "attempt to add with overflow",
+            (Operacao::Subtracao, _) => "attempt to subtract with overflow",
+            (Operacao::Multiplicacao, _) => "attempt to multiply with overflow",
+            (Operacao::Divisao, _) => "attempt to divide with overflow",
+            (Operacao::Resto, _) => {
+                "attempt to calculate the remainder with overflow"
+            }
+        }
+    }
+}
+
+/// Uma operação feita de todos os jeitos, já formatada para a tabela
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparacao {
+    pub operacao: Operacao,
+    pub checked: String,
+    pub wrapping: String,
+    pub saturating: String,
+    pub overflowing: String,
+    /// Como seria o operador comum com `overflow-checks` (padrão do perfil
+    /// debug), deduzido de `checked`
+    pub debug: String,
+    /// Como seria o operador comum sem `overflow-checks` (padrão do perfil
+    /// release), deduzido de `wrapping`
+    pub release: String,
+    /// O resultado exato não cabe no tipo (ou o divisor é zero)
+    pub estourou: bool,
+    /// A mensagem do pânico do operador comum em debug, se houver
+    pub panico: Option<&'static str>,
+}
+
+impl Comparacao {
+    /// Monta a linha a partir dos resultados já calculados. `None` em
+    /// `wrapping`/`overflowing` quer dizer divisor zero (esses métodos
+    /// também entram em pânico); `saturating_rem` não existe.
+    fn new(
+        operacao: Operacao,
+        checked: Option<String>,
+        wrapping: Option<String>,
+        saturating: Option<String>,
+        overflowing: Option<(String, bool)>,
+    ) -> Self {
+        const PANICO: &str = "pânico";
+        let divisor_zero = wrapping.is_none();
+        let ou_panico =
+            |valor: Option<String>| valor.unwrap_or_else(|| PANICO.into());
+
+        let release = match &checked {
+            Some(valor) => valor.clone(),
+            // `MIN / -1` e divisor zero são conferidos em qualquer perfil
+            None if matches!(operacao, Operacao::Divisao | Operacao::Resto) => {
+                PANICO.to_string()
+            }
+            None => wrapping.clone().unwrap_or_default(),
+        };
+
+        Self {
+            operacao,
+            estourou: checked.is_none(),
+            panico: checked.is_none().then(|| operacao.panico(divisor_zero)),
+            debug: checked.clone().unwrap_or_else(|| PANICO.into()),
+            release,
+            checked: match checked {
+                Some(valor) => format!("Some({valor})"),
+                None => "None".to_string(),
+            },
+            saturating: match (operacao, saturating) {
+                (Operacao::Resto, _) => "(não existe)".to_string(),
+                (_, valor) => ou_panico(valor),
+            },
+            overflowing: match overflowing {
+                Some((valor, estourou)) => format!("({valor}, {estourou})"),
+                None => PANICO.to_string(),
+            },
+            wrapping: ou_panico(wrapping),
+        }
+    }
+}
+
+/// `a` e `b` lidos como `$t`, com as cinco operações
+macro_rules! comparar {
+    ($t:ty, $a:expr, $b:expr) => {{
+        let a: $t = ler($a, stringify!($t))?;
+        let b: $t = ler($b, stringify!($t))?;
+        let texto = |v: $t| v.to_string();
+        let par = |(v, estourou): ($t, bool)| (v.to_string(), estourou);
+
+        Operacao::TODAS
+            .into_iter()
+            .map(|operacao| match operacao {
+                Operacao::Soma => Comparacao::new(
+                    operacao,
+                    a.checked_add(b).map(texto),
+                    Some(texto(a.wrapping_add(b))),
+                    Some(texto(a.saturating_add(b))),
+                    Some(par(a.overflowing_add(b))),
+                ),
+                Operacao::Subtracao => Comparacao::new(
+                    operacao,
+                    a.checked_sub(b).map(texto),
+                    Some(texto(a.wrapping_sub(b))),
+                    Some(texto(a.saturating_sub(b))),
+                    Some(par(a.overflowing_sub(b))),
+                ),
+                Operacao::Multiplicacao => Comparacao::new(
+                    operacao,
+                    a.checked_mul(b).map(texto),
+                    Some(texto(a.wrapping_mul(b))),
+                    Some(texto(a.saturating_mul(b))),
+                    Some(par(a.overflowing_mul(b))),
+                ),
+                // Com divisor zero, até `wrapping_div` entra em pânico
+                Operacao::Divisao | Operacao::Resto if b == 0 => {
+                    Comparacao::new(operacao, None, None, None, None)
+                }
+                Operacao::Divisao => Comparacao::new(
+                    operacao,
+                    a.checked_div(b).map(texto),
+                    Some(texto(a.wrapping_div(b))),
+                    Some(texto(a.saturating_div(b))),
+                    Some(par(a.overflowing_div(b))),
+                ),
+                Operacao::Resto => Comparacao::new(
+                    operacao,
+                    a.checked_rem(b).map(texto),
+                    Some(texto(a.wrapping_rem(b))),
+                    None,
+                    Some(par(a.overflowing_rem(b))),
+                ),
+            })
+            .collect()
+    }};
+}
+
+/// Faz as cinco operações com `a` e `b` no tipo `tipo` (`u8`, `i64`...)
+pub fn comparar(
+    tipo: &str,
+    a: &str,
+    b: &str,
+) -> Result<Vec<Comparacao>, String> {
+    Ok(match tipo {
+        "i8" => comparar!(i8, a, b),
+        "i16" => comparar!(i16, a, b),
+        "i32" => comparar!(i32, a, b),
+        "i64" => comparar!(i64, a, b),
+        "i128" => comparar!(i128, a, b),
+        "isize" => comparar!(isize, a, b),
+        "u8" => comparar!(u8, a, b),
+        "u16" => comparar!(u16, a, b),
+        "u32" => comparar!(u32, a, b),
+        "u64" => comparar!(u64, a, b),
+        "u128" => comparar!(u128, a, b),
+        "usize" => comparar!(usize, a, b),
+        _ => {
+            return Err(format!(
+                "tipo inteiro desconhecido: {tipo} (use {})",
+                TIPOS.join(", ")
+            ));
+        }
+    })
+}
+
+/// Lê um operando, explicando quando ele não cabe no tipo
+fn ler<T>(texto: &str, tipo: &str) -> Result<T, String>
+where
+    T: std::str::FromStr<Err = std::num::ParseIntError>,
+{
+    texto
+        .trim()
+        .parse()
+        .map_err(|erro: std::num::ParseIntError| match erro.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                format!("{texto} não cabe em {tipo}")
+            }
+            _ => format!("{texto} não é um número inteiro"),
+        })
+}
+
+pub fn imprimir(tipo: &str, a: &str, b: &str, linhas: &[Comparacao]) {
+    let cabecalho = [
+        "Operação",
+        "checked",
+        "wrapping",
+        "saturating",
+        "overflowing",
+        "seria em debug",
+        "seria em release",
+    ];
+    let celulas: Vec<[String; 7]> = linhas
+        .iter()
+        .map(|linha| {
+            [
+                format!("{a} {} {b}", linha.operacao.simbolo()),
+                linha.checked.clone(),
+                linha.wrapping.clone(),
+                linha.saturating.clone(),
+                linha.overflowing.clone(),
+                linha.debug.clone(),
+                linha.release.clone(),
+            ]
+        })
+        .collect();
+
+    // Cada coluna com a largura do maior texto dela
+    let larguras: Vec<usize> = (0..cabecalho.len())
+        .map(|i| {
+            celulas
+                .iter()
+                .map(|linha| linha[i].chars().count())
+                .chain([cabecalho[i].chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let formatar = |textos: &[&str]| {
+        textos
+            .iter()
+            .zip(&larguras)
+            .map(|(texto, &largura)| format!("{texto:<largura$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{a} e {b} como {tipo}:\n");
+    println!("{}", formatar(&cabecalho));
+    println!("{}", "-".repeat(larguras.iter().sum::<usize>() + 12));
+    for linha in &celulas {
+        let textos: Vec<&str> = linha.iter().map(String::as_str).collect();
+        println!("{}", formatar(&textos));
+    }
+
+    for (celula, linha) in celulas.iter().zip(linhas) {
+        if let Some(mensagem) = linha.panico {
+            println!("  ↳ {}: pânico com \"{mensagem}\"", celula[0]);
+        }
+    }
+
+    println!(
+        "\nOs métodos valem em qualquer perfil. As duas últimas colunas são \
+         como seriam os operadores comuns em cada perfil (deduzidas dos \
+         métodos, não executadas):"
+    );
+    println!(
+        "  debug   (cargo run):           estouro entra em pânico \
+         (overflow-checks ligado)"
+    );
+    println!(
+        "  release (cargo run --release): estouro dá a volta, igual ao \
+         wrapping_*, sem aviso"
+    );
+    let perfil = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    println!("Este executável foi compilado no perfil {perfil}.");
+
+    if linhas
+        .iter()
+        .any(|l| l.operacao == Operacao::Multiplicacao && l.estourou)
+    {
+        println!(
+            "\nÉ o que acontece em `Rectangle::area` (`width * height`) com \
+             lados grandes: use `checked_mul` para tratar o estouro ou um \
+             tipo maior no resultado."
+        );
+    }
+}
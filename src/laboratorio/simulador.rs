@@ -0,0 +1,1498 @@
+// ============================================================================
+// SIMULADOR DE OWNERSHIP: um mini-Rust interpretado passo a passo
+// ============================================================================
+//
+// Os capítulos 05 e 06 explicam move e borrow com exemplos que compilam (ou
+// que ficam comentados). Aqui dá para escrever o próprio exemplo numa
+// linguagem pequena, parecida com Rust, e ver linha por linha quem é dono
+// de quê e quem empresta o quê:
+//
+//   let s1 = String::from("olá");
+//   let s2 = s1;
+//   println!("{}", s1);
+//
+//   Linha  Código                         Propriedade e empréstimos
+//       1  let s1 = String::from("olá");  s1 é dono de "olá"
+//       2  let s2 = s1;                   s1 → s2 (move)
+//       3  println!("{}", s1);            ❌ E0382
+//       4  (fim do programa)              drop s2 ("olá")
+//
+// O que a linguagem tem: `let`, `let mut`, atribuição, `String::from`,
+// números e literais `"..."` (Copy), `&x`, `&mut x`, blocos `{ }`, funções
+// (`fn nome(x: String) -> String { ... }`), `println!`/`print`, `drop(x)` e
+// os métodos `clone`, `len`, `push_str`, `push` e `clear`. Os tipos
+// escritos no código são só enfeite: o simulador olha para os valores.
+//
+// Os empréstimos seguem as regras de hoje (NLL): um empréstimo vale até o
+// último uso da referência, não até o fim do bloco. Por isso os conflitos
+// são conferidos no fim, quando já se sabe qual foi o último uso de cada
+// referência. Simplificação: `&mut` copiada para outra variável conta como
+// a mesma referência (o Rust faria um reempréstimo).
+//
+// Os códigos de erro são os do rustc; os que estão no catálogo de
+// `laboratorio::erros` têm explicação em `resolution explain`.
+//
+// ============================================================================
+
+use crate::laboratorio::erros;
+
+// ============================================================================
+// LÉXICO
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Nome(String),
+    Texto(String),
+    Numero(i64),
+    Simbolo(&'static str),
+}
+
+/// Os mais longos primeiro, para `::` não virar dois `:`
+const SIMBOLOS: &[&str] = &[
+    "::", "->", "(", ")", "{", "}", ";", ",", "=", "&", ":", ".", "!", "<", ">",
+];
+
+fn tokens(fonte: &str) -> Result<Vec<(Token, usize)>, String> {
+    let mut tokens = Vec::new();
+
+    for (i, linha) in fonte.lines().enumerate() {
+        let numero = i + 1;
+        let mut resto = linha;
+
+        loop {
+            resto = resto.trim_start();
+            if resto.is_empty() || resto.starts_with("//") {
+                break;
+            }
+
+            if let Some(depois) = resto.strip_prefix('"') {
+                let mut texto = String::new();
+                let mut chars = depois.char_indices();
+                let fim = loop {
+                    match chars.next() {
+                        Some((i, '"')) => break i,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, 'n')) => texto.push('\n'),
+                            Some((_, c)) => texto.push(c),
+                            None => break depois.len(),
+                        },
+                        Some((_, c)) => texto.push(c),
+                        None => {
+                            return Err(format!(
+                                "linha {numero}: texto sem as aspas do fim"
+                            ));
+                        }
+                    }
+                };
+                tokens.push((Token::Texto(texto), numero));
+                resto = &depois[fim + 1..];
+            } else if let Some(simbolo) =
+                SIMBOLOS.iter().find(|s| resto.starts_with(**s))
+            {
+                tokens.push((Token::Simbolo(simbolo), numero));
+                resto = &resto[simbolo.len()..];
+            } else {
+                let fim = resto
+                    .find(|c: char| !c.is_alphanumeric() && c != '_')
+                    .unwrap_or(resto.len());
+                if fim == 0 {
+                    let c = resto.chars().next().unwrap_or(' ');
+                    return Err(format!("linha {numero}: não entendi '{c}'"));
+                }
+
+                let palavra = &resto[..fim];
+                let token = if palavra.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    let sem_sufixo =
+                        palavra.trim_end_matches(char::is_alphabetic);
+                    Token::Numero(sem_sufixo.parse().map_err(|_| {
+                        format!("linha {numero}: número inválido {palavra}")
+                    })?)
+                } else {
+                    Token::Nome(palavra.to_string())
+                };
+                tokens.push((token, numero));
+                resto = &resto[fim..];
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// ============================================================================
+// SINTAXE
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Numero(i64),
+    /// `"texto"`: um `&'static str`, Copy como um número
+    Literal(String),
+    /// `String::from("texto")`: um valor novo na heap
+    StringFrom(String),
+    Var(String),
+    Ref {
+        mutavel: bool,
+        nome: String,
+    },
+    Chamada {
+        nome: String,
+        args: Vec<Expr>,
+    },
+    Metodo {
+        alvo: String,
+        metodo: String,
+        args: Vec<Expr>,
+    },
+}
+
+#[derive(Debug, Clone)]
+enum Instrucao {
+    Let {
+        nome: String,
+        mutavel: bool,
+        valor: Option<Expr>,
+        linha: usize,
+    },
+    Atribuir {
+        nome: String,
+        valor: Expr,
+        linha: usize,
+    },
+    Expr {
+        expr: Expr,
+        linha: usize,
+    },
+    Bloco(Bloco),
+}
+
+#[derive(Debug, Clone)]
+struct Bloco {
+    instrucoes: Vec<Instrucao>,
+    /// A expressão final sem `;` (o valor do bloco) e a linha dela
+    fim: Option<(Expr, usize)>,
+    /// A linha do `}`
+    linha_fim: usize,
+}
+
+/// O que `Leitor::instrucao` leu: uma instrução completa ou uma expressão
+/// sem `;` e a linha dela
+enum Lido {
+    Instrucao(Instrucao),
+    SemPontoEVirgula(Expr, usize),
+}
+
+#[derive(Debug, Clone)]
+struct Funcao {
+    nome: String,
+    parametros: Vec<(String, bool)>,
+    /// Quantos parâmetros são referências (decide E0106 ou E0515)
+    referencias: usize,
+    corpo: Bloco,
+}
+
+/// Um programa lido: as funções e as instruções soltas (o `main` implícito)
+#[derive(Debug, Clone)]
+pub struct Programa {
+    funcoes: Vec<Funcao>,
+    topo: Vec<Instrucao>,
+    linhas: Vec<String>,
+}
+
+struct Leitor {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Leitor {
+    fn ver(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn ver_depois(&self) -> Option<&Token> {
+        self.tokens.get(self.pos + 1).map(|(t, _)| t)
+    }
+
+    fn linha(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |(_, l)| *l)
+    }
+
+    fn e_simbolo(&self, simbolo: &str) -> bool {
+        self.ver() == Some(&Token::Simbolo(simbolo_estatico(simbolo)))
+    }
+
+    fn e_nome(&self, nome: &str) -> bool {
+        matches!(self.ver(), Some(Token::Nome(n)) if n == nome)
+    }
+
+    /// Consome o símbolo se for ele
+    fn simbolo(&mut self, simbolo: &str) -> bool {
+        let sim = self.e_simbolo(simbolo);
+        if sim {
+            self.pos += 1;
+        }
+        sim
+    }
+
+    fn exigir(&mut self, simbolo: &str) -> Result<(), String> {
+        if self.simbolo(simbolo) {
+            Ok(())
+        } else {
+            Err(self.erro(&format!("esperava `{simbolo}`")))
+        }
+    }
+
+    fn palavra(&mut self, nome: &str) -> bool {
+        let sim = self.e_nome(nome);
+        if sim {
+            self.pos += 1;
+        }
+        sim
+    }
+
+    fn nome(&mut self) -> Result<String, String> {
+        match self.ver() {
+            Some(Token::Nome(nome)) => {
+                let nome = nome.clone();
+                self.pos += 1;
+                Ok(nome)
+            }
+            _ => Err(self.erro("esperava um nome")),
+        }
+    }
+
+    fn erro(&self, mensagem: &str) -> String {
+        let achou = match self.ver() {
+            Some(Token::Nome(n)) => format!("`{n}`"),
+            Some(Token::Texto(t)) => format!("{t:?}"),
+            Some(Token::Numero(n)) => n.to_string(),
+            Some(Token::Simbolo(s)) => format!("`{s}`"),
+            None => "o fim do programa".to_string(),
+        };
+        format!("linha {}: {mensagem}, achei {achou}", self.linha())
+    }
+
+    fn programa(&mut self) -> Result<(Vec<Funcao>, Vec<Instrucao>), String> {
+        let mut funcoes = Vec::new();
+        let mut topo = Vec::new();
+
+        while self.ver().is_some() {
+            if self.palavra("fn") {
+                funcoes.push(self.funcao()?);
+            } else {
+                match self.instrucao()? {
+                    Lido::Instrucao(instrucao) => topo.push(instrucao),
+                    Lido::SemPontoEVirgula(_, linha) => {
+                        return Err(format!(
+                            "linha {linha}: falta `;` no fim da instrução"
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok((funcoes, topo))
+    }
+
+    fn funcao(&mut self) -> Result<Funcao, String> {
+        let nome = self.nome()?;
+        self.exigir("(")?;
+        let mut parametros = Vec::new();
+        let mut referencias = 0;
+        while !self.simbolo(")") {
+            let mutavel = self.palavra("mut");
+            let parametro = self.nome()?;
+            self.exigir(":")?;
+            if self.tipo()? {
+                referencias += 1;
+            }
+            parametros.push((parametro, mutavel));
+            if !self.e_simbolo(")") {
+                self.exigir(",")?;
+            }
+        }
+        if self.simbolo("->") {
+            self.tipo()?;
+        }
+        let corpo = self.bloco()?;
+        Ok(Funcao {
+            nome,
+            parametros,
+            referencias,
+            corpo,
+        })
+    }
+
+    /// `String`, `&str`, `&mut String`, `i32`, `Vec<i32>`: lido e ignorado,
+    /// menos se é uma referência (`true`)
+    fn tipo(&mut self) -> Result<bool, String> {
+        let referencia = self.simbolo("&");
+        if referencia {
+            self.palavra("mut");
+        }
+        self.nome()?;
+        if self.simbolo("<") {
+            self.tipo()?;
+            self.exigir(">")?;
+        }
+        Ok(referencia)
+    }
+
+    fn bloco(&mut self) -> Result<Bloco, String> {
+        self.exigir("{")?;
+        let mut instrucoes = Vec::new();
+        let mut fim = None;
+
+        while !self.e_simbolo("}") {
+            if self.ver().is_none() {
+                return Err(self.erro("esperava `}`"));
+            }
+            match self.instrucao()? {
+                Lido::Instrucao(instrucao) => instrucoes.push(instrucao),
+                Lido::SemPontoEVirgula(expr, linha) if self.e_simbolo("}") => {
+                    fim = Some((expr, linha))
+                }
+                Lido::SemPontoEVirgula(..) => {
+                    return Err(self.erro("esperava `;`"));
+                }
+            }
+        }
+
+        let linha_fim = self.linha();
+        self.exigir("}")?;
+        Ok(Bloco {
+            instrucoes,
+            fim,
+            linha_fim,
+        })
+    }
+
+    /// Uma instrução, ou uma expressão sem `;` (só vale como a última coisa
+    /// de um bloco)
+    fn instrucao(&mut self) -> Result<Lido, String> {
+        let linha = self.linha();
+
+        if self.e_simbolo("{") {
+            return Ok(Lido::Instrucao(Instrucao::Bloco(self.bloco()?)));
+        }
+
+        if self.palavra("let") {
+            let mutavel = self.palavra("mut");
+            let nome = self.nome()?;
+            if self.simbolo(":") {
+                self.tipo()?;
+            }
+            let valor = if self.simbolo("=") {
+                Some(self.expr()?)
+            } else {
+                None
+            };
+            self.exigir(";")?;
+            return Ok(Lido::Instrucao(Instrucao::Let {
+                nome,
+                mutavel,
+                valor,
+                linha,
+            }));
+        }
+
+        if matches!(self.ver(), Some(Token::Nome(_)))
+            && self.ver_depois() == Some(&Token::Simbolo("="))
+        {
+            let nome = self.nome()?;
+            self.exigir("=")?;
+            let valor = self.expr()?;
+            self.exigir(";")?;
+            return Ok(Lido::Instrucao(Instrucao::Atribuir {
+                nome,
+                valor,
+                linha,
+            }));
+        }
+
+        let expr = self.expr()?;
+        if self.simbolo(";") {
+            Ok(Lido::Instrucao(Instrucao::Expr { expr, linha }))
+        } else {
+            Ok(Lido::SemPontoEVirgula(expr, linha))
+        }
+    }
+
+    fn argumentos(&mut self) -> Result<Vec<Expr>, String> {
+        self.exigir("(")?;
+        let mut args = Vec::new();
+        while !self.simbolo(")") {
+            args.push(self.expr()?);
+            if !self.e_simbolo(")") {
+                self.exigir(",")?;
+            }
+        }
+        Ok(args)
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        match self.ver().cloned() {
+            Some(Token::Numero(n)) => {
+                self.pos += 1;
+                Ok(Expr::Numero(n))
+            }
+            Some(Token::Texto(texto)) => {
+                self.pos += 1;
+                Ok(Expr::Literal(texto))
+            }
+            Some(Token::Simbolo("&")) => {
+                self.pos += 1;
+                let mutavel = self.palavra("mut");
+                Ok(Expr::Ref {
+                    mutavel,
+                    nome: self.nome()?,
+                })
+            }
+            Some(Token::Nome(nome)) => {
+                self.pos += 1;
+                if nome == "String" && self.simbolo("::") {
+                    let construtor = self.nome()?;
+                    let args = self.argumentos()?;
+                    return match (construtor.as_str(), args.as_slice()) {
+                        ("from", [Expr::Literal(texto)]) => {
+                            Ok(Expr::StringFrom(texto.clone()))
+                        }
+                        ("new", []) => Ok(Expr::StringFrom(String::new())),
+                        _ => Err(format!(
+                            "linha {}: use String::from(\"...\") ou \
+                             String::new()",
+                            self.linha()
+                        )),
+                    };
+                }
+                // `println!(...)`: a macro vira uma chamada comum
+                self.simbolo("!");
+                if self.e_simbolo("(") {
+                    let args = self.argumentos()?;
+                    return Ok(Expr::Chamada { nome, args });
+                }
+                if self.simbolo(".") {
+                    let metodo = self.nome()?;
+                    let args = self.argumentos()?;
+                    return Ok(Expr::Metodo {
+                        alvo: nome,
+                        metodo,
+                        args,
+                    });
+                }
+                Ok(Expr::Var(nome))
+            }
+            _ => Err(self.erro("esperava uma expressão")),
+        }
+    }
+}
+
+/// `Token::Simbolo` guarda `&'static str`: acha o da tabela
+fn simbolo_estatico(simbolo: &str) -> &'static str {
+    SIMBOLOS
+        .iter()
+        .find(|s| **s == simbolo)
+        .copied()
+        .unwrap_or("")
+}
+
+/// Lê o programa. O erro já vem com a linha (`linha 3: esperava ...`).
+pub fn ler(fonte: &str) -> Result<Programa, String> {
+    let mut leitor = Leitor {
+        tokens: tokens(fonte)?,
+        pos: 0,
+    };
+    let (funcoes, topo) = leitor.programa()?;
+
+    if let Some(repetida) = funcoes
+        .iter()
+        .enumerate()
+        .find(|(i, f)| funcoes[..*i].iter().any(|g| g.nome == f.nome))
+    {
+        return Err(format!("a função {} aparece duas vezes", repetida.1.nome));
+    }
+
+    Ok(Programa {
+        funcoes,
+        topo,
+        linhas: fonte.lines().map(|l| l.trim().to_string()).collect(),
+    })
+}
+
+// ============================================================================
+// SIMULAÇÃO
+// ============================================================================
+
+/// Um passo da linha do tempo: a linha de código e o que aconteceu nela
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passo {
+    pub linha: usize,
+    /// Quantas chamadas de função por dentro (para o recuo)
+    pub profundidade: usize,
+    pub codigo: String,
+    pub eventos: Vec<String>,
+}
+
+/// Um erro que o borrow checker apontaria
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erro {
+    pub linha: usize,
+    /// O código do rustc para o mesmo erro (`E0382`)
+    pub codigo: &'static str,
+    pub mensagem: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulacao {
+    pub passos: Vec<Passo>,
+    pub erros: Vec<Erro>,
+    /// Por que a simulação parou antes do fim (não é um erro do rustc:
+    /// recursão funda demais para o simulador)
+    pub interrompida: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Valor {
+    Inteiro(i64),
+    Literal(String),
+    /// Uma `String`: o conteúdo mora na heap e tem um dono só
+    Texto(String),
+    /// Uma referência: o índice do empréstimo
+    Ref(usize),
+    Nada,
+}
+
+#[derive(Debug)]
+struct Variavel {
+    nome: String,
+    mutavel: bool,
+    valor: Option<Valor>,
+    /// Para quem e em que linha o valor foi movido
+    movido: Option<(String, usize)>,
+}
+
+#[derive(Debug)]
+struct Emprestimo {
+    /// A variável emprestada
+    dono: usize,
+    mutavel: bool,
+    /// Quem recebeu a referência (`r1`, `s em calcula`)
+    quem: String,
+    linha: usize,
+    criado: usize,
+    /// O último momento e a última linha em que a referência foi usada
+    ultimo_uso: usize,
+    linha_uso: usize,
+    /// Já virou erro de referência pendurada (não repete como E0597)
+    pendurado: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Acesso {
+    Leitura,
+    Escrita,
+    Move,
+    /// Um método que muda o valor (`push_str`): um `&mut` temporário
+    Mutacao,
+    Emprestimo {
+        mutavel: bool,
+    },
+    Drop,
+}
+
+#[derive(Debug)]
+struct Registro {
+    variavel: usize,
+    momento: usize,
+    linha: usize,
+    acesso: Acesso,
+}
+
+/// Métodos que mudam o valor (pedem `mut` ou `&mut`)
+const MUTADORES: &[&str] = &["push_str", "push", "clear", "truncate", "insert"];
+
+/// Quantas chamadas dentro de chamadas antes de desistir (recursão)
+const MAX_PROFUNDIDADE: usize = 32;
+
+struct Simulador<'a> {
+    programa: &'a Programa,
+    variaveis: Vec<Variavel>,
+    /// Chamadas de função, cada uma com os seus blocos, cada bloco com as
+    /// suas variáveis
+    quadros: Vec<Vec<Vec<usize>>>,
+    emprestimos: Vec<Emprestimo>,
+    registros: Vec<Registro>,
+    passos: Vec<Passo>,
+    /// O momento de cada passo (para achar o passo do último uso)
+    momentos: Vec<usize>,
+    erros: Vec<Erro>,
+    interrompida: Option<String>,
+    /// Conta cada instrução e cada fim de escopo, em ordem
+    momento: usize,
+    linha: usize,
+}
+
+impl Simulador<'_> {
+    fn erro(&mut self, codigo: &'static str, mensagem: String) {
+        self.erros.push(Erro {
+            linha: self.linha,
+            codigo,
+            mensagem,
+        });
+    }
+
+    fn evento(&mut self, evento: String) {
+        if let Some(passo) = self.passos.last_mut() {
+            passo.eventos.push(evento);
+        }
+    }
+
+    fn passo(&mut self, linha: usize) {
+        self.momento += 1;
+        self.linha = linha;
+        let codigo = self
+            .programa
+            .linhas
+            .get(linha.wrapping_sub(1))
+            .cloned()
+            .unwrap_or_default();
+        self.passos.push(Passo {
+            linha,
+            profundidade: self
+                .quadros
+                .iter()
+                .map(Vec::len)
+                .sum::<usize>()
+                .saturating_sub(1),
+            codigo,
+            eventos: Vec::new(),
+        });
+        self.momentos.push(self.momento);
+    }
+
+    fn registrar(&mut self, variavel: usize, acesso: Acesso) {
+        self.registros.push(Registro {
+            variavel,
+            momento: self.momento,
+            linha: self.linha,
+            acesso,
+        });
+    }
+
+    fn buscar(&mut self, nome: &str) -> Option<usize> {
+        let achou = self.quadros.last().and_then(|blocos| {
+            blocos
+                .iter()
+                .rev()
+                .flat_map(|bloco| bloco.iter().rev())
+                .find(|&&i| self.variaveis[i].nome == nome)
+                .copied()
+        });
+        if achou.is_none() {
+            self.erro("E0425", format!("{nome} não existe neste escopo"));
+        }
+        achou
+    }
+
+    fn declarar(&mut self, nome: &str, mutavel: bool, valor: Option<Valor>) {
+        self.variaveis.push(Variavel {
+            nome: nome.to_string(),
+            mutavel,
+            valor,
+            movido: None,
+        });
+        let indice = self.variaveis.len() - 1;
+        if let Some(bloco) = self.quadros.last_mut().and_then(|q| q.last_mut())
+        {
+            bloco.push(indice);
+        }
+    }
+
+    fn usar_emprestimo(&mut self, emprestimo: usize) {
+        let (momento, linha) = (self.momento, self.linha);
+        let emprestimo = &mut self.emprestimos[emprestimo];
+        emprestimo.ultimo_uso = momento;
+        emprestimo.linha_uso = linha;
+    }
+
+    fn descrever(&self, valor: &Valor) -> String {
+        match valor {
+            Valor::Inteiro(n) => n.to_string(),
+            Valor::Literal(texto) | Valor::Texto(texto) => format!("{texto:?}"),
+            Valor::Ref(e) => {
+                let emprestimo = &self.emprestimos[*e];
+                let dono = &self.variaveis[emprestimo.dono].nome;
+                if emprestimo.mutavel {
+                    format!("&mut {dono}")
+                } else {
+                    format!("&{dono}")
+                }
+            }
+            Valor::Nada => "()".to_string(),
+        }
+    }
+
+    /// A variável existe, tem valor e não foi movida? Se não, registra o
+    /// erro e devolve `None`.
+    fn valida(&mut self, i: usize, acao: &str) -> Option<Valor> {
+        let variavel = &self.variaveis[i];
+        let nome = variavel.nome.clone();
+        match (&variavel.valor, &variavel.movido) {
+            (None, _) => {
+                self.erro(
+                    "E0381",
+                    format!("{acao} {nome} antes de ele receber um valor"),
+                );
+                None
+            }
+            (Some(_), Some((para, linha))) => {
+                let mensagem = format!(
+                    "{acao} {nome} depois do move (foi movido para {para} \
+                     na linha {linha})"
+                );
+                self.erro("E0382", mensagem);
+                None
+            }
+            (Some(valor), None) => Some(valor.clone()),
+        }
+    }
+
+    /// Usa o valor da variável num lugar que toma posse (`let`, argumento,
+    /// retorno): `String` é movida, número e referência são copiados
+    fn tomar(&mut self, nome: &str, destino: &str) -> Valor {
+        let Some(i) = self.buscar(nome) else {
+            return Valor::Nada;
+        };
+        let Some(valor) = self.valida(i, "usar") else {
+            return Valor::Nada;
+        };
+
+        match &valor {
+            Valor::Texto(_) => {
+                self.registrar(i, Acesso::Move);
+                self.variaveis[i].movido =
+                    Some((destino.to_string(), self.linha));
+                self.evento(format!("{nome} → {destino} (move)"));
+            }
+            Valor::Ref(e) => {
+                self.usar_emprestimo(*e);
+                let referencia = self.descrever(&valor);
+                self.evento(format!(
+                    "{destino} recebe a mesma referência ({referencia})"
+                ));
+            }
+            Valor::Inteiro(_) | Valor::Literal(_) => {
+                self.registrar(i, Acesso::Leitura);
+                self.evento(format!(
+                    "{destino} recebe uma cópia de {nome} (Copy)"
+                ));
+            }
+            Valor::Nada => {}
+        }
+        valor
+    }
+
+    /// Lê a variável sem tomar posse (`println!`, `len`)
+    fn ler(&mut self, nome: &str) {
+        let Some(i) = self.buscar(nome) else { return };
+        match self.valida(i, "usar") {
+            Some(Valor::Ref(e)) => self.usar_emprestimo(e),
+            Some(_) => self.registrar(i, Acesso::Leitura),
+            None => {}
+        }
+    }
+
+    fn emprestar(&mut self, nome: &str, mutavel: bool, destino: &str) -> Valor {
+        let Some(i) = self.buscar(nome) else {
+            return Valor::Nada;
+        };
+        if self.valida(i, "emprestar").is_none() {
+            return Valor::Nada;
+        }
+        if mutavel && !self.variaveis[i].mutavel {
+            self.erro(
+                "E0596",
+                format!(
+                    "{nome} não foi declarado com `mut`: não dá para \
+                     emprestar como &mut"
+                ),
+            );
+        }
+
+        self.registrar(i, Acesso::Emprestimo { mutavel });
+        self.emprestimos.push(Emprestimo {
+            dono: i,
+            mutavel,
+            quem: destino.to_string(),
+            linha: self.linha,
+            criado: self.momento,
+            ultimo_uso: self.momento,
+            linha_uso: self.linha,
+            pendurado: false,
+        });
+
+        let tipo = if mutavel { "&mut" } else { "&" };
+        self.evento(format!("{destino} empresta {nome} ({tipo})"));
+        Valor::Ref(self.emprestimos.len() - 1)
+    }
+
+    fn avaliar(&mut self, expr: &Expr, destino: &str) -> Valor {
+        match expr {
+            Expr::Numero(n) => Valor::Inteiro(*n),
+            Expr::Literal(texto) => Valor::Literal(texto.clone()),
+            Expr::StringFrom(texto) => {
+                self.evento(format!("{destino} é dono de {texto:?}"));
+                Valor::Texto(texto.clone())
+            }
+            Expr::Var(nome) => self.tomar(nome, destino),
+            Expr::Ref { mutavel, nome } => {
+                self.emprestar(nome, *mutavel, destino)
+            }
+            Expr::Metodo { alvo, metodo, args } => {
+                self.metodo(alvo, metodo, args, destino)
+            }
+            Expr::Chamada { nome, args } => match nome.as_str() {
+                "println" | "print" => {
+                    for arg in args {
+                        match arg {
+                            Expr::Var(nome) => self.ler(nome),
+                            // `println!("{}", &s)`: lê pelo empréstimo
+                            Expr::Ref { nome, .. } => self.ler(nome),
+                            outro => {
+                                self.avaliar(outro, "println!");
+                            }
+                        }
+                    }
+                    Valor::Nada
+                }
+                "drop" => {
+                    for arg in args {
+                        if let Valor::Texto(texto) = self.avaliar(arg, "drop") {
+                            self.evento(format!("drop {texto:?}"));
+                        }
+                    }
+                    Valor::Nada
+                }
+                _ => self.chamar(nome, args, destino),
+            },
+        }
+    }
+
+    fn metodo(
+        &mut self,
+        alvo: &str,
+        metodo: &str,
+        args: &[Expr],
+        destino: &str,
+    ) -> Valor {
+        for arg in args {
+            self.avaliar(arg, metodo);
+        }
+        let texto_do_arg = match args {
+            [Expr::Literal(texto)] => texto.clone(),
+            _ => String::new(),
+        };
+
+        let Some(i) = self.buscar(alvo) else {
+            return Valor::Nada;
+        };
+        let Some(valor) = self.valida(i, "usar") else {
+            return Valor::Nada;
+        };
+        let muda = MUTADORES.contains(&metodo);
+
+        // Pela referência, o valor é o da variável emprestada
+        let dono = match valor {
+            Valor::Ref(e) => {
+                self.usar_emprestimo(e);
+                let emprestimo = &self.emprestimos[e];
+                if muda && !emprestimo.mutavel {
+                    let dono = &self.variaveis[emprestimo.dono].nome;
+                    let mensagem = format!(
+                        "{alvo} é uma referência & (só leitura): não dá \
+                         para mudar {dono} por ela com {metodo}"
+                    );
+                    self.erro("E0596", mensagem);
+                }
+                self.emprestimos[e].dono
+            }
+            _ => {
+                if muda && !self.variaveis[i].mutavel {
+                    self.erro(
+                        "E0596",
+                        format!(
+                            "{alvo} não foi declarado com `mut`: {metodo} \
+                             precisa mudar o valor"
+                        ),
+                    );
+                }
+                let acesso = if muda {
+                    Acesso::Mutacao
+                } else {
+                    Acesso::Leitura
+                };
+                self.registrar(i, acesso);
+                i
+            }
+        };
+
+        let conteudo = match &self.variaveis[dono].valor {
+            Some(Valor::Texto(texto) | Valor::Literal(texto)) => texto.clone(),
+            _ => String::new(),
+        };
+        match metodo {
+            "clone" => {
+                self.evento(format!(
+                    "{destino} é dono de uma cópia de {alvo} ({conteudo:?})"
+                ));
+                Valor::Texto(conteudo)
+            }
+            "len" => Valor::Inteiro(conteudo.len() as i64),
+            _ if muda => {
+                let novo = match metodo {
+                    "clear" => String::new(),
+                    _ => conteudo + &texto_do_arg,
+                };
+                self.evento(format!(
+                    "{} agora vale {novo:?}",
+                    self.variaveis[dono].nome
+                ));
+                if let Some(Valor::Texto(texto)) =
+                    &mut self.variaveis[dono].valor
+                {
+                    *texto = novo;
+                }
+                Valor::Nada
+            }
+            _ => Valor::Nada,
+        }
+    }
+
+    fn chamar(&mut self, nome: &str, args: &[Expr], destino: &str) -> Valor {
+        let programa = self.programa;
+        let linha = self.linha;
+        let Some(funcao) = programa.funcoes.iter().find(|f| f.nome == nome)
+        else {
+            self.erro("E0425", format!("a função {nome} não existe"));
+            return Valor::Nada;
+        };
+        if funcao.parametros.len() != args.len() {
+            self.erro(
+                "E0061",
+                format!(
+                    "{nome} recebe {} argumento(s), veio {}",
+                    funcao.parametros.len(),
+                    args.len()
+                ),
+            );
+            return Valor::Nada;
+        }
+        if self.quadros.len() > MAX_PROFUNDIDADE {
+            self.interrompida.get_or_insert_with(|| {
+                format!(
+                    "linha {linha}: mais de {MAX_PROFUNDIDADE} chamadas uma \
+                     dentro da outra em {nome}"
+                )
+            });
+            return Valor::Nada;
+        }
+
+        let valores: Vec<_> = funcao
+            .parametros
+            .iter()
+            .zip(args)
+            .map(|((parametro, _), arg)| {
+                self.avaliar(arg, &format!("{parametro} em {nome}"))
+            })
+            .collect();
+
+        self.quadros.push(vec![Vec::new()]);
+        for ((parametro, mutavel), valor) in
+            funcao.parametros.iter().zip(valores)
+        {
+            self.declarar(parametro, *mutavel, Some(valor));
+        }
+
+        let retorno =
+            self.corpo(&funcao.corpo, &format!("o retorno de {nome}"));
+
+        // Devolver referência para uma variável que morre aqui
+        if let Some(Valor::Ref(e)) = &retorno {
+            let dono = self.emprestimos[*e].dono;
+            let local = self.quadros.last().is_some_and(|blocos| {
+                blocos.iter().flatten().any(|&i| i == dono)
+            });
+            if local {
+                self.emprestimos[*e].pendurado = true;
+                let dono = self.variaveis[dono].nome.clone();
+                // Com um só parâmetro referência, o retorno empresta dele e
+                // o rustc chega a olhar o corpo; sem nenhum (ou com vários),
+                // já para na assinatura, sem saber de quem emprestar
+                if funcao.referencias == 1 {
+                    self.erro(
+                        "E0515",
+                        format!(
+                            "{nome} devolve uma referência para {dono}, \
+                             variável local descartada no fim de {nome}"
+                        ),
+                    );
+                } else {
+                    self.erro(
+                        "E0106",
+                        format!(
+                            "{nome} devolve uma referência para {dono}, que \
+                             é descartado no fim de {nome} (referência \
+                             pendurada)"
+                        ),
+                    );
+                }
+            }
+        }
+
+        self.fim_do_escopo(funcao.corpo.linha_fim);
+        self.quadros.pop();
+
+        // De volta à linha da chamada, com o valor devolvido
+        let retorno = retorno.unwrap_or(Valor::Nada);
+        let descricao = self.descrever(&retorno);
+        let evento = match retorno {
+            Valor::Texto(_) => {
+                format!("{destino} é dono de {descricao} (veio de {nome})")
+            }
+            Valor::Ref(_) => format!("{destino} recebe {descricao} de {nome}"),
+            Valor::Inteiro(_) | Valor::Literal(_) => {
+                format!("{destino} = {descricao} (veio de {nome})")
+            }
+            Valor::Nada => return retorno,
+        };
+        self.passo(linha);
+        self.evento(evento);
+        retorno
+    }
+
+    fn instrucao(&mut self, instrucao: &Instrucao) {
+        match instrucao {
+            Instrucao::Let {
+                nome,
+                mutavel,
+                valor,
+                linha,
+            } => {
+                self.passo(*linha);
+                let valor = valor.as_ref().map(|expr| {
+                    let valor = self.avaliar(expr, nome);
+                    if let Valor::Inteiro(_) | Valor::Literal(_) = valor
+                        && !matches!(expr, Expr::Var(_) | Expr::Chamada { .. })
+                    {
+                        let descricao = self.descrever(&valor);
+                        self.evento(format!("{nome} = {descricao}"));
+                    }
+                    valor
+                });
+                self.declarar(nome, *mutavel, valor);
+            }
+            Instrucao::Atribuir { nome, valor, linha } => {
+                self.passo(*linha);
+                let novo = self.avaliar(valor, nome);
+                let Some(i) = self.buscar(nome) else { return };
+
+                let variavel = &self.variaveis[i];
+                if variavel.valor.is_some() && !variavel.mutavel {
+                    self.erro(
+                        "E0384",
+                        format!(
+                            "{nome} não foi declarado com `mut`: não dá para \
+                             atribuir de novo"
+                        ),
+                    );
+                }
+                self.registrar(i, Acesso::Escrita);
+
+                let variavel = &mut self.variaveis[i];
+                let antigo = variavel.valor.replace(novo);
+                let estava_vivo = variavel.movido.take().is_none();
+                if let (Some(Valor::Texto(texto)), true) = (antigo, estava_vivo)
+                {
+                    self.evento(format!("drop do valor antigo ({texto:?})"));
+                }
+            }
+            Instrucao::Expr { expr, linha } => {
+                self.passo(*linha);
+                if let Valor::Texto(texto) = self.avaliar(expr, "o resultado") {
+                    self.evento(format!("drop {texto:?} (ninguém guardou)"));
+                }
+            }
+            Instrucao::Bloco(bloco) => {
+                if let Some(blocos) = self.quadros.last_mut() {
+                    blocos.push(Vec::new());
+                }
+                self.corpo(bloco, "o resultado do bloco");
+                self.fim_do_escopo(bloco.linha_fim);
+            }
+        }
+    }
+
+    /// Roda as instruções do bloco no escopo atual e devolve o valor da
+    /// expressão final (se houver)
+    fn corpo(&mut self, bloco: &Bloco, destino: &str) -> Option<Valor> {
+        for instrucao in &bloco.instrucoes {
+            self.instrucao(instrucao);
+        }
+        bloco.fim.as_ref().map(|(expr, linha)| {
+            self.passo(*linha);
+            self.avaliar(expr, destino)
+        })
+    }
+
+    /// Fecha o bloco mais de dentro: cada `String` que ainda tem dono é
+    /// descartada, na ordem contrária à da declaração
+    fn fim_do_escopo(&mut self, linha: usize) {
+        let Some(variaveis) = self.quadros.last_mut().and_then(|q| q.pop())
+        else {
+            return;
+        };
+        self.passo(linha);
+
+        for i in variaveis.into_iter().rev() {
+            let variavel = &self.variaveis[i];
+            if let (Some(Valor::Texto(texto)), None) =
+                (&variavel.valor, &variavel.movido)
+            {
+                let evento = format!("drop {} ({texto:?})", variavel.nome);
+                self.registrar(i, Acesso::Drop);
+                self.evento(evento);
+            }
+        }
+
+        if self.passos.last().is_some_and(|p| p.eventos.is_empty()) {
+            self.passos.pop();
+            self.momentos.pop();
+        }
+    }
+
+    /// Com o último uso de cada referência conhecido, confere quem mexeu no
+    /// valor emprestado enquanto o empréstimo ainda valia
+    fn conferir_emprestimos(&mut self) {
+        let mut erros = Vec::new();
+
+        for emprestimo in &self.emprestimos {
+            let nome = &self.variaveis[emprestimo.dono].nome;
+            let quem = &emprestimo.quem;
+            let tipo = if emprestimo.mutavel { "&mut" } else { "&" };
+            let ainda = format!(
+                "{nome} está emprestado ({tipo}) para {quem} desde a linha \
+                 {}, e {quem} ainda é usado na linha {}",
+                emprestimo.linha, emprestimo.linha_uso
+            );
+
+            for registro in &self.registros {
+                if registro.variavel != emprestimo.dono
+                    || registro.momento <= emprestimo.criado
+                    || registro.momento > emprestimo.ultimo_uso
+                {
+                    continue;
+                }
+
+                let (codigo, acao) = match (emprestimo.mutavel, registro.acesso)
+                {
+                    (true, Acesso::Emprestimo { mutavel: true })
+                    | (true, Acesso::Mutacao) => {
+                        ("E0499", format!("emprestar {nome} como &mut de novo"))
+                    }
+                    (true, Acesso::Emprestimo { mutavel: false })
+                    | (true, Acesso::Leitura) => {
+                        ("E0502", format!("ler {nome}"))
+                    }
+                    (false, Acesso::Emprestimo { mutavel: true })
+                    | (false, Acesso::Mutacao) => {
+                        ("E0502", format!("mudar {nome}"))
+                    }
+                    (_, Acesso::Escrita) => {
+                        ("E0506", format!("atribuir a {nome}"))
+                    }
+                    (_, Acesso::Move) => ("E0505", format!("mover {nome}")),
+                    (_, Acesso::Drop) if !emprestimo.pendurado => (
+                        "E0597",
+                        format!(
+                            "{nome} é descartado aqui e não vive o bastante:"
+                        ),
+                    ),
+                    _ => continue,
+                };
+
+                let mensagem = if codigo == "E0597" {
+                    format!(
+                        "{acao} {quem} (empréstimo da linha {}) ainda é usado \
+                         na linha {}",
+                        emprestimo.linha, emprestimo.linha_uso
+                    )
+                } else {
+                    format!("não dá para {acao}: {ainda}")
+                };
+                erros.push(Erro {
+                    linha: registro.linha,
+                    codigo,
+                    mensagem,
+                });
+            }
+        }
+
+        self.erros.extend(erros);
+    }
+}
+
+/// Roda o programa e devolve a linha do tempo e os erros (ordenados pela
+/// linha). Sem `fn main`, as instruções soltas são o `main`.
+pub fn simular(programa: &Programa) -> Simulacao {
+    let mut simulador = Simulador {
+        programa,
+        variaveis: Vec::new(),
+        quadros: Vec::new(),
+        emprestimos: Vec::new(),
+        registros: Vec::new(),
+        passos: Vec::new(),
+        momentos: Vec::new(),
+        erros: Vec::new(),
+        interrompida: None,
+        momento: 0,
+        linha: 0,
+    };
+
+    let tem_main = programa.funcoes.iter().any(|f| f.nome == "main");
+    if tem_main && programa.topo.is_empty() {
+        simulador.chamar("main", &[], "o resultado");
+    } else {
+        simulador.quadros.push(vec![Vec::new()]);
+        for instrucao in &programa.topo {
+            simulador.instrucao(instrucao);
+        }
+        let ultima = programa.linhas.len() + 1;
+        simulador.fim_do_escopo(ultima);
+        if let Some(passo) = simulador.passos.last_mut()
+            && passo.linha == ultima
+        {
+            passo.codigo = "(fim do programa)".to_string();
+        }
+    }
+
+    simulador.conferir_emprestimos();
+
+    // Onde cada empréstimo termina: no último uso, não no fim do bloco
+    for emprestimo in &simulador.emprestimos {
+        let Some(i) = simulador
+            .momentos
+            .iter()
+            .position(|&m| m == emprestimo.ultimo_uso)
+        else {
+            continue;
+        };
+        let evento = format!("último uso de {}", emprestimo.quem);
+        let eventos = &mut simulador.passos[i].eventos;
+        if emprestimo.ultimo_uso > emprestimo.criado
+            && !emprestimo.pendurado
+            && !eventos.contains(&evento)
+        {
+            eventos.push(evento);
+        }
+    }
+    let mut erros = simulador.erros;
+    // Como o rustc: um erro por código em cada linha, mesmo com dois
+    // empréstimos no caminho
+    erros.sort_by_key(|erro| erro.linha);
+    erros.dedup_by(|a, b| a.linha == b.linha && a.codigo == b.codigo);
+
+    Simulacao {
+        passos: simulador.passos,
+        erros,
+        interrompida: simulador.interrompida,
+    }
+}
+
+pub fn imprimir(simulacao: &Simulacao) {
+    let largura = simulacao
+        .passos
+        .iter()
+        .map(|p| p.codigo.chars().count() + 2 * p.profundidade)
+        .max()
+        .unwrap_or(0)
+        .max("Código".chars().count());
+
+    println!("Linha  {:<largura$}  Propriedade e empréstimos", "Código");
+    let mut marcadas = Vec::new();
+    for passo in &simulacao.passos {
+        let mut eventos = passo.eventos.clone();
+        // O ❌ vai no primeiro passo de cada linha com erro
+        if !marcadas.contains(&passo.linha) {
+            for erro in
+                simulacao.erros.iter().filter(|e| e.linha == passo.linha)
+            {
+                let marca = format!("❌ {}", erro.codigo);
+                if !eventos.contains(&marca) {
+                    eventos.push(marca);
+                }
+            }
+            marcadas.push(passo.linha);
+        }
+
+        let codigo =
+            format!("{}{}", "  ".repeat(passo.profundidade), passo.codigo);
+        let linha = format!(
+            "{:>5}  {codigo:<largura$}  {}",
+            passo.linha,
+            eventos.join("; ")
+        );
+        println!("{}", linha.trim_end());
+    }
+    println!();
+
+    if let Some(motivo) = &simulacao.interrompida {
+        println!("⚠️ Simulação interrompida: {motivo}.");
+    }
+    match simulacao.erros.as_slice() {
+        [] if simulacao.interrompida.is_some() => {}
+        [] => println!(
+            "✅ Nenhum erro: o borrow checker aceitaria este programa."
+        ),
+        erros => {
+            let plural = if erros.len() == 1 { "erro" } else { "erros" };
+            println!("❌ {} {plural}:", erros.len());
+            for erro in erros {
+                println!(
+                    "  linha {} [{}]: {}",
+                    erro.linha, erro.codigo, erro.mensagem
+                );
+                if erros::buscar(erro.codigo).is_ok() {
+                    println!("    → resolution explain {}", erro.codigo);
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// EXEMPLOS
+// ============================================================================
+
+/// Os exemplos dos capítulos, prontos para `resolution borrow --example`
+pub const EXEMPLOS: &[(&str, &str)] = &[
+    (
+        "move",
+        "\
+// ownership::o_que_e::ownership_basico
+let s1 = String::from(\"olá\");
+let s2 = s1;
+println!(\"{}\", s1);
+",
+    ),
+    (
+        "funcao",
+        "\
+// ownership::o_que_e::ownership_com_funcao
+fn imprime_nome(s: String) {
+    println!(\"{}\", s);
+}
+
+let nome = String::from(\"Rustacean\");
+imprime_nome(nome);
+println!(\"{}\", nome);
+",
+    ),
+    (
+        "clone",
+        "\
+// ownership::o_que_e::clone_vs_move
+let a = String::from(\"clone-me\");
+let b = a.clone();
+let c = b;
+println!(\"{} {}\", a, c);
+",
+    ),
+    (
+        "mutaveis",
+        "\
+// ownership::referencias_e_borrowing::referencias_mutaveis_simultaneas
+let mut s = String::from(\"olá\");
+let r1 = &mut s;
+let r2 = &mut s;
+println!(\"{}, {}\", r1, r2);
+",
+    ),
+    (
+        "mistura",
+        "\
+// ownership::referencias_e_borrowing::mistura_referencias
+let mut s = String::from(\"olá\");
+let r1 = &s;
+let r2 = &s;
+let r3 = &mut s;
+println!(\"{}, {}, {}\", r1, r2, r3);
+",
+    ),
+    (
+        "emprestado",
+        "\
+// ownership::referencias_e_borrowing::mover_enquanto_emprestado
+let s = String::from(\"olá\");
+let r = &s;
+let t = s;
+println!(\"{}, {}\", r, t);
+",
+    ),
+    (
+        "nll",
+        "\
+// ownership::referencias_e_borrowing::escopo_encerra_referencia
+let mut s = String::from(\"olá\");
+let r1 = &s;
+let r2 = &s;
+println!(\"{} e {}\", r1, r2);
+let r3 = &mut s;
+r3.push_str(\"!!!\");
+println!(\"{}\", r3);
+",
+    ),
+    (
+        "pendurada",
+        "\
+// ownership::referencias_e_borrowing::referencia_pendurada
+fn dangle() -> &String {
+    let s = String::from(\"hello\");
+    &s
+}
+
+let r = dangle();
+println!(\"{}\", r);
+",
+    ),
+    (
+        "escopo",
+        "\
+// uma referência que vive mais que o dono
+let r;
+{
+    let x = String::from(\"curta\");
+    r = &x;
+}
+println!(\"{}\", r);
+",
+    ),
+];
+
+pub fn exemplo(nome: &str) -> Result<&'static str, String> {
+    EXEMPLOS
+        .iter()
+        .find(|(n, _)| *n == nome)
+        .map(|(_, fonte)| *fonte)
+        .ok_or_else(|| {
+            let nomes: Vec<_> = EXEMPLOS.iter().map(|(n, _)| *n).collect();
+            format!(
+                "exemplo desconhecido: {nome} (existem: {})",
+                nomes.join(", ")
+            )
+        })
+}
@@ -0,0 +1,236 @@
+// A tabela de `conceitos_comuns::tipos_de_dados`, mas gerada pelo próprio
+// compilador: os limites vêm das constantes `MIN`/`MAX` de cada tipo e os
+// tamanhos de `size_of`/`align_of`, então `isize`/`usize` mostram o tamanho
+// real no alvo em que o programa foi compilado.
+
+use std::mem::{align_of, size_of};
+
+/// Família de um tipo primitivo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Categoria {
+    InteiroComSinal,
+    InteiroSemSinal,
+    Flutuante,
+    Booleano,
+    Caractere,
+}
+
+impl Categoria {
+    pub fn nome(self) -> &'static str {
+        match self {
+            Categoria::InteiroComSinal => "inteiro com sinal",
+            Categoria::InteiroSemSinal => "inteiro sem sinal",
+            Categoria::Flutuante => "ponto flutuante",
+            Categoria::Booleano => "booleano",
+            Categoria::Caractere => "caractere",
+        }
+    }
+}
+
+/// Uma linha da tabela
+#[derive(Debug, Clone, PartialEq)]
+pub struct TipoPrimitivo {
+    pub nome: &'static str,
+    pub categoria: Categoria,
+    /// `size_of`, em bytes
+    pub tamanho: usize,
+    /// `align_of`, em bytes
+    pub alinhamento: usize,
+    /// Menor e maior valor, como o Rust os imprime
+    pub min: String,
+    pub max: String,
+    pub detalhe: Option<String>,
+}
+
+impl TipoPrimitivo {
+    pub fn bits(&self) -> usize {
+        self.tamanho * 8
+    }
+}
+
+macro_rules! inteiro {
+    ($t:ty) => {
+        TipoPrimitivo {
+            nome: stringify!($t),
+            categoria: if <$t>::MIN == 0 {
+                Categoria::InteiroSemSinal
+            } else {
+                Categoria::InteiroComSinal
+            },
+            tamanho: size_of::<$t>(),
+            alinhamento: align_of::<$t>(),
+            min: <$t>::MIN.to_string(),
+            max: <$t>::MAX.to_string(),
+            detalhe: None,
+        }
+    };
+}
+
+macro_rules! flutuante {
+    ($t:ty) => {
+        TipoPrimitivo {
+            nome: stringify!($t),
+            categoria: Categoria::Flutuante,
+            tamanho: size_of::<$t>(),
+            alinhamento: align_of::<$t>(),
+            min: format!("{:e}", <$t>::MIN),
+            max: format!("{:e}", <$t>::MAX),
+            detalhe: Some(format!(
+                "{} dígitos de precisão, EPSILON = {:e}",
+                <$t>::DIGITS,
+                <$t>::EPSILON
+            )),
+        }
+    };
+}
+
+/// Todos os tipos primitivos escalares, na ordem do livro
+pub fn tabela() -> Vec<TipoPrimitivo> {
+    let ponteiro = Some(format!(
+        "tamanho de um ponteiro: {} bits neste alvo",
+        usize::BITS
+    ));
+
+    let mut tipos = vec![
+        inteiro!(i8),
+        inteiro!(i16),
+        inteiro!(i32),
+        inteiro!(i64),
+        inteiro!(i128),
+        TipoPrimitivo {
+            detalhe: ponteiro.clone(),
+            ..inteiro!(isize)
+        },
+        inteiro!(u8),
+        inteiro!(u16),
+        inteiro!(u32),
+        inteiro!(u64),
+        inteiro!(u128),
+        TipoPrimitivo {
+            detalhe: ponteiro,
+            ..inteiro!(usize)
+        },
+        flutuante!(f32),
+        flutuante!(f64),
+    ];
+
+    tipos.push(TipoPrimitivo {
+        nome: "bool",
+        categoria: Categoria::Booleano,
+        tamanho: size_of::<bool>(),
+        alinhamento: align_of::<bool>(),
+        min: false.to_string(),
+        max: true.to_string(),
+        detalhe: Some("1 bit de informação, mas ocupa 1 byte".to_string()),
+    });
+    tipos.push(TipoPrimitivo {
+        nome: "char",
+        categoria: Categoria::Caractere,
+        tamanho: size_of::<char>(),
+        alinhamento: align_of::<char>(),
+        min: format!("U+{:04X}", u32::from(char::MIN)),
+        max: format!("U+{:04X}", u32::from(char::MAX)),
+        detalhe: Some(
+            "valor escalar Unicode (sempre 4 bytes; em UTF-8 são 1 a 4)"
+                .to_string(),
+        ),
+    });
+
+    tipos
+}
+
+/// `-2147483648` → `-2.147.483.648` (os outros textos passam sem mudança)
+pub fn separar_milhares(numero: &str) -> String {
+    let (sinal, digitos) = match numero.strip_prefix('-') {
+        Some(resto) => ("-", resto),
+        None => ("", numero),
+    };
+    if digitos.is_empty() || !digitos.bytes().all(|b| b.is_ascii_digit()) {
+        return numero.to_string();
+    }
+
+    let mut grupos: Vec<&str> = Vec::new();
+    let mut fim = digitos.len();
+    while fim > 3 {
+        grupos.push(&digitos[fim - 3..fim]);
+        fim -= 3;
+    }
+    grupos.push(&digitos[..fim]);
+    grupos.reverse();
+
+    format!("{sinal}{}", grupos.join("."))
+}
+
+pub fn imprimir(tipos: &[TipoPrimitivo]) {
+    println!(
+        "{:<6} {:<18} {:>4} {:>5} {:>6}  Intervalo",
+        "Tipo", "Categoria", "Bits", "Bytes", "Alinh."
+    );
+    println!("{}", "-".repeat(110));
+
+    for tipo in tipos {
+        println!(
+            "{:<6} {:<18} {:>4} {:>5} {:>6}  {} a {}",
+            tipo.nome,
+            tipo.categoria.nome(),
+            tipo.bits(),
+            tipo.tamanho,
+            tipo.alinhamento,
+            separar_milhares(&tipo.min),
+            separar_milhares(&tipo.max)
+        );
+        if let Some(detalhe) = &tipo.detalhe {
+            println!("{:<6} ↳ {detalhe}", "");
+        }
+    }
+}
+
+/// A tabela em JSON. Os limites vão como texto: `u128::MAX` não cabe num
+/// número de JavaScript (`f64`) sem perder dígitos.
+pub fn json(tipos: &[TipoPrimitivo]) -> String {
+    let linhas: Vec<String> = tipos
+        .iter()
+        .map(|tipo| {
+            let detalhe = tipo
+                .detalhe
+                .as_deref()
+                .map_or("null".to_string(), texto_json);
+            format!(
+                "  {{\"tipo\": {}, \"categoria\": {}, \"bits\": {}, \
+                 \"bytes\": {}, \"alinhamento\": {}, \"min\": {}, \
+                 \"max\": {}, \"detalhe\": {}}}",
+                texto_json(tipo.nome),
+                texto_json(tipo.categoria.nome()),
+                tipo.bits(),
+                tipo.tamanho,
+                tipo.alinhamento,
+                texto_json(&tipo.min),
+                texto_json(&tipo.max),
+                detalhe
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", linhas.join(",\n"))
+}
+
+/// Uma string JSON, com aspas e escapes
+pub fn texto_json(texto: &str) -> String {
+    let mut json = String::with_capacity(texto.len() + 2);
+    json.push('"');
+    for c in texto.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                json.push_str(&format!("\\u{:04x}", u32::from(c)))
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
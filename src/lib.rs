@@ -0,0 +1,58 @@
+// ============================================================================
+// RESOLUTION: Estudos do livro "The Rust Programming Language"
+// ============================================================================
+//
+// Cada módulo de capítulo tem uma função `main` que roda os seus exemplos.
+// O módulo `capitulos` registra todos eles para o executável `resolution`
+// (veja `src/main.rs`), que permite listar e rodar um capítulo por vez.
+//
+// O `println!` usado nos capítulos é o do módulo `saida` (mesma sintaxe),
+// para que a saída possa ser capturada e comparada nos testes.
+//
+// ============================================================================
+
+// Vêm antes dos outros módulos para que as macros deles (`println!` e
+// `sonda!`) valham no crate todo
+#[macro_use]
+pub mod saida;
+#[macro_use]
+pub mod sonda;
+
+pub mod alocacoes;
+pub mod capitulos;
+pub mod entrada;
+pub mod executor;
+pub mod jogo_de_advinhacao;
+pub mod laboratorio;
+
+// Os capítulos reproduzem de propósito código "do livro" que o compilador e o
+// clippy reclamam: structs que nunca são lidas, campos redundantes (`email:
+// email`), `&String` em vez de `&str`, `match` verboso, `unwrap` em `Some`...
+// Corrigir isso apagaria justamente o que os exemplos querem mostrar, então
+// cada capítulo libera só os lints que os seus exemplos disparam. O resto do
+// crate (jogo, laboratório, executor...) segue os lints normais.
+#[allow(clippy::needless_late_init)]
+pub mod conceitos_comuns;
+#[allow(
+    dead_code,
+    clippy::manual_range_contains,
+    clippy::single_match,
+    clippy::unnecessary_literal_unwrap
+)]
+pub mod enums;
+#[allow(
+    dead_code,
+    clippy::manual_range_contains,
+    clippy::ptr_arg,
+    clippy::single_match
+)]
+pub mod ownership;
+#[allow(
+    dead_code,
+    clippy::empty_line_after_doc_comments,
+    clippy::manual_range_contains,
+    clippy::ptr_arg,
+    clippy::redundant_field_names,
+    clippy::single_match
+)]
+pub mod structs;
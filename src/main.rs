@@ -1,51 +1,742 @@
-mod conceitos_comuns;
-mod enums;
-mod jogo_de_advinhacao;
-mod ownership;
-mod structs;
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Read},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-fn main() {
-    println!("\nComeçando o programa!\n");
+use resolution::alocacoes;
+use resolution::capitulos::{self, CAPITULOS, Capitulo};
+use resolution::entrada::{self, Roteiro};
+use resolution::executor;
+use resolution::jogo_de_advinhacao::forca::{self, HangmanGame};
+use resolution::jogo_de_advinhacao::gravacao::Gravacao;
+use resolution::jogo_de_advinhacao::placar::{self, Placar};
+use resolution::jogo_de_advinhacao::{Config, Difficulty, GuessingGame};
+use resolution::jogo_de_advinhacao::{
+    estrategias, multijogador, simulacao, terminal,
+};
+use resolution::laboratorio::{
+    bases, conversoes, erros, flutuante, layout, overflow, simulador, tipos,
+};
 
-    // println!("\nJogo de advinhação!\n");
-    // jogo_de_advinhacao::main();
+const USO: &str = "\
+Uso:
+  resolution list                          lista os capítulos
+  resolution list <capítulo>               lista as seções de um capítulo
+  resolution run <capítulo>                roda um capítulo (ex.: 07, enums::if_let)
+  resolution run <capítulo> --section <n>  roda só a seção <n> do capítulo
+  resolution run --all                     roda todos os capítulos em ordem
+      --allocs                             alocações na heap de cada seção
+                                           (com `--features alocacoes`)
+  resolution game                          jogo de advinhação
+      --difficulty <facil|normal|dificil>  faixa e tentativas prontas
+      --range <min>..=<max>                faixa personalizada
+      --attempts <n>                       limite de tentativas (0 = sem limite)
+      --seed <n>                           mesmo número secreto toda vez
+      --reverse                            o computador advinha o seu número
+      --record <arquivo>                   grava a partida no arquivo
+      --replay <arquivo>                   joga de novo uma partida gravada
+  resolution hangman                       forca: advinhe a palavra letra por letra
+      --words <arquivo>                    lista de palavras (uma por linha)
+      --errors <n>                         erros permitidos (6)
+      --seed <n>                           mesma palavra toda vez
+  resolution serve                         servidor multijogador (localhost)
+      --port <n>                           porta TCP (7878)
+      --rounds <n>                         encerra depois de <n> rodadas
+      --difficulty <nível>, --range <min>..=<max>, --seed <n>
+  resolution join [--port <n>]             entra num servidor multijogador
+  resolution types [--json]                tabela dos tipos primitivos
+  resolution convert <valor> <de> <para>   as, From e TryFrom (ex.: 300 i32 u8)
+  resolution convert --matrix              quais conversões existem entre os tipos
+  resolution base <literal>                0xff, 0o17, 0b1010, b'A', 57u8, -1i8...
+      --bits <n>                           largura do complemento de dois
+  resolution float <valor>                 sinal, expoente e mantissa (ex.: 0.1)
+      --type <f32|f64>                     tipo do valor (f64)
+  resolution float --surprises             0.1 + 0.2 e outras pegadinhas
+  resolution explain <código>              erro do compilador em português (ex.: E0502)
+  resolution explain                       explica o JSON de `cargo build --message-format=json`
+  resolution explain --list                os códigos do catálogo
+  resolution borrow <arquivo>              simula ownership e borrow num mini-Rust
+  resolution borrow --example <nome>       um exemplo dos capítulos (ex.: mistura)
+  resolution borrow --list                 os exemplos prontos
+  resolution layout [<tipo>]               tamanho, alinhamento e offsets (User...)
+  resolution layout --where <0x…>...       stack, heap ou estática, em diagrama
+  resolution overflow <a> <b>              estouro de inteiros em cada método
+      --type <tipo>                        tipo dos operandos (i32)
+  resolution scores                        melhores partidas por dificuldade
+      --difficulty <nível>                 só uma dificuldade (ex.: facil)
+      --top <n>                            quantas partidas mostrar (10)
+  resolution simulate                      compara estratégias de chute
+      --games <n>                          partidas por estratégia (10000)
+      --seed <n>                           semente da primeira partida (0)
+      --difficulty <nível>, --range <min>..=<max>
 
-    println!("\nVariáveis e mutabilidade\n");
-    conceitos_comuns::variaveis_e_mutabilidade::main();
+Opções (valem para qualquer comando):
+  --input <arquivo>    lê as respostas do arquivo, uma por linha
+  --non-interactive    não lê nada: toda pergunta usa a resposta padrão
 
-    println!("\nTipos de dados\n");
-    conceitos_comuns::tipos_de_dados::main();
+O placar fica em placar.tsv (ou no arquivo de $RESOLUTION_PLACAR).";
 
-    println!("\nFunções\n");
-    conceitos_comuns::funcoes::main();
+/// Conta as alocações de cada seção, mas só mostra com `run --allocs`
+#[cfg(feature = "alocacoes")]
+#[global_allocator]
+static ALOCADOR: alocacoes::ContadorDeAlocacoes =
+    alocacoes::ContadorDeAlocacoes;
 
-    println!("\nControle de fluxo\n");
-    conceitos_comuns::controle_de_fluxo::main();
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    println!("\nOwnership\n");
-    ownership::memoria::main();
+    match executar(&args) {
+        Ok(codigo) => codigo,
+        Err(Falha::Uso(erro)) => {
+            eprintln!("erro: {erro}\n\n{USO}");
+            ExitCode::from(2)
+        }
+        Err(Falha::Erro(erro)) => {
+            eprintln!("erro: {erro}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Por que o comando falhou. Só a linha de comando malformada mostra o
+/// `USO`: num valor ruim (um literal, um código de erro) ele esconderia a
+/// mensagem que importa.
+enum Falha {
+    /// Comando desconhecido, argumento sobrando ou faltando
+    Uso(String),
+    /// O pedido foi entendido, mas não deu para atender
+    Erro(String),
+}
+
+impl From<String> for Falha {
+    fn from(erro: String) -> Self {
+        Falha::Erro(erro)
+    }
+}
+
+fn uso(erro: impl Into<String>) -> Falha {
+    Falha::Uso(erro.into())
+}
+
+/// Interpreta a linha de comando e despacha para o comando certo
+fn executar(args: &[String]) -> Result<ExitCode, Falha> {
+    let args = configurar_entrada(args)?;
+
+    let Some((comando, resto)) = args.split_first() else {
+        println!("{USO}");
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    match (comando.as_str(), resto) {
+        ("list", []) => {
+            listar();
+            Ok(ExitCode::SUCCESS)
+        }
+        ("list", [chave]) => {
+            buscar(chave)?.into_iter().for_each(listar_secoes);
+            Ok(ExitCode::SUCCESS)
+        }
+        ("run", resto) => comando_run(resto),
+        ("game", resto) => comando_game(resto),
+        ("hangman", resto) => comando_hangman(resto),
+        ("serve", resto) => comando_serve(resto),
+        ("join", resto) => comando_join(resto),
+        ("types", []) => {
+            tipos::imprimir(&tipos::tabela());
+            Ok(ExitCode::SUCCESS)
+        }
+        ("types", [opcao]) if opcao == "--json" => {
+            println!("{}", tipos::json(&tipos::tabela()));
+            Ok(ExitCode::SUCCESS)
+        }
+        ("convert", [opcao]) if opcao == "--matrix" => {
+            conversoes::imprimir_matriz(&conversoes::matriz());
+            Ok(ExitCode::SUCCESS)
+        }
+        ("convert", [valor, origem, destino]) => {
+            let conversao =
+                conversoes::par(origem, destino)?.converter(valor)?;
+            conversoes::imprimir(&conversao);
+            Ok(ExitCode::SUCCESS)
+        }
+        ("base", resto) => comando_base(resto),
+        ("float", [opcao]) if opcao == "--surprises" => {
+            flutuante::imprimir_surpresas(&flutuante::surpresas());
+            Ok(ExitCode::SUCCESS)
+        }
+        ("float", resto) => comando_float(resto),
+        ("explain", [opcao]) if opcao == "--list" => {
+            for explicacao in erros::CATALOGO {
+                println!("{}  {}", explicacao.codigo, explicacao.titulo);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        ("explain", [codigo]) => {
+            erros::imprimir(erros::buscar(codigo)?);
+            Ok(ExitCode::SUCCESS)
+        }
+        ("explain", []) => comando_explain(),
+        ("borrow", resto) => comando_borrow(resto),
+        ("layout", []) => {
+            for (i, tipo) in layout::tipos_do_projeto().iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                layout::imprimir(tipo);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        ("layout", [opcao, enderecos @ ..]) if opcao == "--where" => {
+            if enderecos.is_empty() {
+                return Err(uso("--where precisa de ao menos um endereço"));
+            }
+            let enderecos = enderecos
+                .iter()
+                .map(|texto| layout::ler_endereco(texto))
+                .collect::<Result<Vec<_>, _>>()?;
+            for linha in layout::diagrama(&layout::onde(&enderecos)) {
+                println!("{linha}");
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        ("layout", [tipo]) => {
+            layout::imprimir(&layout::buscar(tipo)?);
+            Ok(ExitCode::SUCCESS)
+        }
+        ("overflow", resto) => comando_overflow(resto),
+        ("scores", resto) => comando_scores(resto),
+        ("simulate", resto) => comando_simulate(resto),
+        ("help" | "--help" | "-h", _) => {
+            println!("{USO}");
+            Ok(ExitCode::SUCCESS)
+        }
+        _ => Err(uso(format!("argumentos inválidos: {}", args.join(" ")))),
+    }
+}
+
+/// Tira `--input` e `--non-interactive` dos argumentos e troca a entrada
+/// usada pelos exemplos interativos
+fn configurar_entrada(args: &[String]) -> Result<Vec<String>, Falha> {
+    let mut resto = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let caminho = args
+                    .next()
+                    .ok_or_else(|| uso("--input precisa de um arquivo"))?;
+                let roteiro = Roteiro::de_arquivo(caminho)
+                    .map_err(|e| format!("não consegui ler {caminho}: {e}"))?;
+                entrada::usar(roteiro);
+            }
+            "--non-interactive" => entrada::usar(Roteiro::default()),
+            _ => resto.push(arg.clone()),
+        }
+    }
+
+    Ok(resto)
+}
+
+/// `run <capítulo> [--section <n>]` ou `run --all`
+///
+/// Sai com código 1 se algum capítulo entrou em pânico.
+fn comando_run(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut todos = false;
+    let mut chave = None;
+    let mut secao = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => todos = true,
+            "--allocs" if alocacoes::instalado() => alocacoes::mostrar(true),
+            "--allocs" => eprintln!(
+                "🔎 heap: contador de alocações não instalado (compile com \
+                 `--features alocacoes`)"
+            ),
+            "--section" => {
+                let valor = args
+                    .next()
+                    .ok_or_else(|| uso("--section precisa de um número"))?;
+                let numero = valor
+                    .parse::<usize>()
+                    .map_err(|_| format!("seção inválida: {valor}"))?;
+                secao = Some(numero);
+            }
+            _ if chave.is_none() && !arg.starts_with("--") => chave = Some(arg),
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let capitulos = match (todos, chave, secao) {
+        (true, None, None) => CAPITULOS.iter().collect(),
+        (false, Some(chave), None) => buscar(chave)?,
+        (false, Some(chave), Some(numero)) => {
+            let capitulo = match buscar(chave)?.as_slice() {
+                [capitulo] => *capitulo,
+                _ => {
+                    return Err(uso(format!(
+                        "o capítulo {chave} tem mais de um módulo; \
+                         use o caminho do módulo com --section"
+                    )));
+                }
+            };
+            let secao = capitulo.secao(numero).ok_or_else(|| {
+                format!("{} não tem a seção {numero}", capitulo.modulo)
+            })?;
+            capitulos::executar_secao(numero, secao);
+            return Ok(ExitCode::SUCCESS);
+        }
+        _ => return Err(uso("use `run <capítulo>` ou `run --all`")),
+    };
+
+    let execucoes = executor::rodar_todos(capitulos);
+    executor::imprimir_resumo(&execucoes);
+
+    if execucoes.iter().all(|e| e.passou()) {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// `game [--difficulty <nível>] [--range <min>..=<max>] [--attempts <n>]
+/// [--seed <n>] [--reverse] [--record <arquivo>]` ou `game --replay <arquivo>`
+fn comando_game(args: &[String]) -> Result<ExitCode, Falha> {
+    // A gravação já traz semente e configuração: nada mais se aplica
+    if let [opcao, arquivo] = args
+        && opcao == "--replay"
+    {
+        let gravacao = Gravacao::carregar(Path::new(arquivo))?;
+        return Ok(if terminal::reproduzir(&gravacao) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    let mut config = Config::default();
+    let mut faixa = None;
+    let mut tentativas = None;
+    let mut seed = None;
+    let mut reverso = false;
+    let mut gravar = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut valor = || {
+            args.next()
+                .ok_or_else(|| uso(format!("{arg} precisa de um valor")))
+        };
+
+        match arg.as_str() {
+            "--difficulty" => config = valor()?.parse::<Difficulty>()?.config(),
+            "--reverse" => reverso = true,
+            "--record" => gravar = Some(PathBuf::from(valor()?)),
+            "--range" => faixa = Some(parse_faixa(valor()?)?),
+            "--attempts" => tentativas = Some(parse_tentativas(valor()?)?),
+            "--seed" => {
+                let valor = valor()?;
+                let n = valor
+                    .parse()
+                    .map_err(|_| format!("semente inválida: {valor}"))?;
+                seed = Some(n);
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    // --range e --attempts ajustam a dificuldade escolhida
+    if faixa.is_some() || tentativas.is_some() {
+        let (min, max) = faixa.unwrap_or((config.min, config.max));
+        config =
+            Config::new(min, max, tentativas.unwrap_or(config.max_attempts))?;
+    }
+
+    if reverso {
+        terminal::jogar_reverso(config);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut game = match seed {
+        Some(seed) => GuessingGame::with_seed(config, seed),
+        None => GuessingGame::new(config),
+    };
+
+    let mut gravacao = gravar.as_ref().map(|_| Gravacao::new(&game));
+    let caminho = placar::caminho_padrao();
+    terminal::jogar_com_placar(&mut game, &caminho, gravacao.as_mut());
+
+    if let (Some(arquivo), Some(gravacao)) = (gravar, gravacao) {
+        gravacao.salvar(&arquivo).map_err(|e| {
+            format!("não consegui gravar {}: {e}", arquivo.display())
+        })?;
+        println!("Partida gravada em {}.", arquivo.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `hangman [--words <arquivo>] [--errors <n>] [--seed <n>]`
+fn comando_hangman(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut palavras = None;
+    let mut erros = forca::MAX_ERROS_PADRAO;
+    let mut seed = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let valor = args
+            .next()
+            .ok_or_else(|| uso(format!("{arg} precisa de um valor")))?;
+        let invalido = || format!("{arg} inválido: {valor}");
+
+        match arg.as_str() {
+            "--words" => {
+                palavras = Some(forca::carregar_palavras(Path::new(valor))?)
+            }
+            "--errors" => erros = valor.parse().map_err(|_| invalido())?,
+            "--seed" => seed = Some(valor.parse().map_err(|_| invalido())?),
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let palavras =
+        palavras.unwrap_or_else(|| forca::ler_palavras(forca::PALAVRAS_PADRAO));
+    let mut game = match seed {
+        Some(seed) => HangmanGame::with_seed(&palavras, erros, seed)?,
+        None => HangmanGame::new(&palavras, erros)?,
+    };
+
+    terminal::jogar_forca_com_placar(&mut game, &placar::caminho_padrao());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `serve [--port <n>] [--rounds <n>] [--difficulty <nível>]
+/// [--range <min>..=<max>] [--seed <n>]`
+fn comando_serve(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut config = Config::default();
+    let mut porta = multijogador::PORTA_PADRAO;
+    let mut rodadas = None;
+    let mut seed = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let valor = args
+            .next()
+            .ok_or_else(|| uso(format!("{arg} precisa de um valor")))?;
+        let invalido = || format!("{arg} inválido: {valor}");
+
+        match arg.as_str() {
+            "--port" => porta = valor.parse().map_err(|_| invalido())?,
+            "--rounds" => {
+                rodadas = Some(valor.parse().map_err(|_| invalido())?)
+            }
+            "--seed" => seed = Some(valor.parse().map_err(|_| invalido())?),
+            "--difficulty" => config = valor.parse::<Difficulty>()?.config(),
+            "--range" => {
+                let (min, max) = parse_faixa(valor)?;
+                config = Config::new(min, max, None)?;
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    // Só na máquina local: o jogo não tem nenhuma proteção para a rede
+    let listener = TcpListener::bind(("127.0.0.1", porta))
+        .map_err(|e| format!("não consegui abrir a porta {porta}: {e}"))?;
+    println!(
+        "Servidor em 127.0.0.1:{porta}, números de {} a {}. \
+         Conecte com `resolution join --port {porta}`.",
+        config.min, config.max
+    );
 
-    println!("\nOwnership\n");
-    ownership::o_que_e::main();
+    multijogador::servir(listener, config, seed, rodadas)
+        .map_err(|e| format!("erro no servidor: {e}"))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `join [--port <n>]`
+fn comando_join(args: &[String]) -> Result<ExitCode, Falha> {
+    let porta = match args {
+        [] => multijogador::PORTA_PADRAO,
+        [opcao, valor] if opcao == "--port" => valor
+            .parse()
+            .map_err(|_| format!("--port inválido: {valor}"))?,
+        _ => {
+            return Err(uso(format!(
+                "argumentos inválidos: {}",
+                args.join(" ")
+            )));
+        }
+    };
+
+    let endereco = format!("127.0.0.1:{porta}");
+    multijogador::conectar(&endereco)
+        .map_err(|e| format!("não consegui conectar em {endereco}: {e}"))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `simulate [--games <n>] [--seed <n>] [--difficulty <nível>]
+/// [--range <min>..=<max>]`
+fn comando_simulate(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut config = Config::default();
+    let mut jogos = 10_000;
+    let mut seed = 0;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let valor = args
+            .next()
+            .ok_or_else(|| uso(format!("{arg} precisa de um valor")))?;
+        let invalido = || format!("{arg} inválido: {valor}");
 
-    ownership::referencias_e_borrowing::main();
-    ownership::slice::main();
+        match arg.as_str() {
+            "--games" => jogos = valor.parse().map_err(|_| invalido())?,
+            "--seed" => seed = valor.parse().map_err(|_| invalido())?,
+            "--difficulty" => config = valor.parse::<Difficulty>()?.config(),
+            "--range" => {
+                let (min, max) = parse_faixa(valor)?;
+                config = Config::new(min, max, None)?;
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
 
-    println!("\nStructs\n");
-    structs::definindo_structs::main();
+    println!(
+        "{jogos} partidas por estratégia, números de {} a {}, semente {seed}\n",
+        config.min, config.max
+    );
 
-    println!("\nStructs - Exemplo Retângulo\n");
-    structs::exemplo_retangulo::main();
+    let resultados: Vec<_> = estrategias::todas()
+        .iter_mut()
+        .map(|estrategia| {
+            simulacao::simulate(estrategia.as_mut(), config, jogos, seed)
+        })
+        .collect();
 
-    println!("\nMétodos\n");
-    structs::metodos::main();
+    simulacao::imprimir(&resultados);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `base <literal> [--bits <n>]`
+fn comando_base(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut bits = None;
+    let mut literal = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bits" => {
+                let n = args
+                    .next()
+                    .ok_or_else(|| uso("--bits precisa de um número"))?;
+                let n =
+                    n.parse().map_err(|_| format!("largura inválida: {n}"))?;
+                bits = Some(n);
+            }
+            // `-1i8` é um literal, não uma opção
+            _ if !arg.starts_with("--") && literal.is_none() => {
+                literal = Some(arg.as_str())
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let literal = literal.ok_or_else(|| uso("base precisa de um literal"))?;
+    bases::imprimir(literal, &bases::converter(literal, bits)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `explain` sem código: lê os diagnósticos em JSON da entrada padrão
+fn comando_explain() -> Result<ExitCode, Falha> {
+    let mut entrada = io::stdin();
+    if entrada.is_terminal() {
+        return Err(uso(
+            "use `explain <código>` ou mande o JSON pela entrada \
+             (cargo build --message-format=json | resolution explain)",
+        ));
+    }
+
+    let mut texto = String::new();
+    entrada
+        .read_to_string(&mut texto)
+        .map_err(|e| format!("não consegui ler a entrada: {e}"))?;
+    erros::explicar_diagnosticos(&erros::diagnosticos(&texto));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `borrow <arquivo>`, `borrow --example <nome>` ou `borrow --list`
+fn comando_borrow(args: &[String]) -> Result<ExitCode, Falha> {
+    let fonte = match args {
+        [opcao] if opcao == "--list" => {
+            for (nome, fonte) in simulador::EXEMPLOS {
+                let origem = fonte.lines().next().unwrap_or_default();
+                println!("{nome:<10} {}", origem.trim_start_matches("// "));
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+        [opcao, nome] if opcao == "--example" => {
+            simulador::exemplo(nome)?.to_string()
+        }
+        [arquivo] if !arquivo.starts_with("--") => fs::read_to_string(arquivo)
+            .map_err(|e| format!("não consegui ler {arquivo}: {e}"))?,
+        _ => {
+            return Err(uso(
+                "use `borrow <arquivo>` ou `borrow --example <nome>`",
+            ));
+        }
+    };
+
+    let simulacao = simulador::simular(&simulador::ler(&fonte)?);
+    simulador::imprimir(&simulacao);
+    Ok(
+        if simulacao.erros.is_empty() && simulacao.interrompida.is_none() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        },
+    )
+}
+
+/// `float <valor> [--type <f32|f64>]`
+fn comando_float(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut tipo = "f64";
+    let mut valor = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--type" => {
+                tipo = args
+                    .next()
+                    .ok_or_else(|| uso("--type precisa de um tipo"))?
+            }
+            // `-0.5` e `-inf` são valores, não opções
+            _ if !arg.starts_with("--") && valor.is_none() => {
+                valor = Some(arg.as_str())
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let valor = valor.ok_or_else(|| uso("float precisa de um valor"))?;
+    flutuante::imprimir(&flutuante::inspecionar_texto(valor, tipo)?);
+    Ok(ExitCode::SUCCESS)
+}
 
-    println!("\nEnums\n");
-    enums::definindo_enums::main();
+/// `overflow <a> <b> [--type <tipo>]`
+fn comando_overflow(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut tipo = "i32";
+    let mut operandos = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--type" => {
+                tipo = args
+                    .next()
+                    .ok_or_else(|| uso("--type precisa de um tipo"))?
+            }
+            _ if !arg.starts_with("--") => operandos.push(arg.as_str()),
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let [a, b] = operandos[..] else {
+        return Err(uso("overflow precisa de dois números"));
+    };
+    let linhas = overflow::comparar(tipo, a, b)?;
+    overflow::imprimir(tipo, a, b, &linhas);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `scores [--difficulty <nível>] [--top <n>]`
+fn comando_scores(args: &[String]) -> Result<ExitCode, Falha> {
+    let mut dificuldade = None;
+    let mut top = 10;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let valor = args
+            .next()
+            .ok_or_else(|| uso(format!("{arg} precisa de um valor")))?;
+
+        match arg.as_str() {
+            "--difficulty" => dificuldade = Some(valor.as_str()),
+            "--top" => {
+                top = valor
+                    .parse()
+                    .map_err(|_| format!("--top inválido: {valor}"))?;
+            }
+            _ => return Err(uso(format!("argumento inesperado: {arg}"))),
+        }
+    }
+
+    let caminho = placar::caminho_padrao();
+    let placar = Placar::carregar(&caminho)
+        .map_err(|e| format!("não consegui ler {}: {e}", caminho.display()))?;
+
+    println!("Placar ({})", caminho.display());
+    placar::imprimir(&placar, dificuldade, top);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `1..=500` (ou `1..500`, também inclusivo no fim)
+fn parse_faixa(texto: &str) -> Result<(i64, i64), String> {
+    let invalida = || format!("faixa inválida: {texto} (use min..=max)");
+
+    let (min, max) = texto.split_once("..").ok_or_else(invalida)?;
+    let max = max.strip_prefix('=').unwrap_or(max);
+
+    let min = min.trim().parse().map_err(|_| invalida())?;
+    let max = max.trim().parse().map_err(|_| invalida())?;
+    Ok((min, max))
+}
+
+/// Um número de tentativas, ou `0` para ilimitadas
+fn parse_tentativas(texto: &str) -> Result<Option<u32>, String> {
+    match texto.parse::<u32>() {
+        Ok(0) => Ok(None),
+        Ok(n) => Ok(Some(n)),
+        Err(_) => Err(format!("número de tentativas inválido: {texto}")),
+    }
+}
+
+fn buscar(chave: &str) -> Result<Vec<&'static Capitulo>, String> {
+    let encontrados = capitulos::buscar(chave);
+    if encontrados.is_empty() {
+        return Err(format!("capítulo desconhecido: {chave}"));
+    }
+    Ok(encontrados)
+}
+
+fn listar() {
+    println!("{:<3} {:<45} {:<44} Doc", "#", "Módulo", "Título");
+    println!("{}", "-".repeat(124));
+
+    for capitulo in CAPITULOS {
+        println!(
+            "{:<3} {:<45} {:<44} {}",
+            capitulo.id(),
+            capitulo.modulo,
+            capitulo.titulo,
+            capitulo.doc
+        );
+    }
+}
 
-    println!("\nOption<T>\n");
-    enums::option_enum::main();
+fn listar_secoes(capitulo: &Capitulo) {
+    println!(
+        "{}. {} ({})",
+        capitulo.id(),
+        capitulo.titulo,
+        capitulo.modulo
+    );
 
-    println!("\nif let e let...else\n");
-    enums::if_let::main();
+    for (i, secao) in capitulo.secoes.iter().enumerate() {
+        println!("  {:>2}. {}", i + 1, secao.titulo);
+    }
 }
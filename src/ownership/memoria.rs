@@ -1,9 +1,20 @@
+use crate::capitulos::{self, Secao};
+use crate::laboratorio::layout::{self, Endereco};
+
+// `.aloca(n)`: quantas alocações na heap a seção faz, conferido com
+// `cargo run -- run ownership::memoria --allocs` (e pelos testes)
+pub const SECOES: &[Secao] = &[
+    Secao::new("Stack: tipos primitivos", stack_com_tipo_primario).aloca(0),
+    Secao::new("Heap: String", heap_com_string).aloca(1),
+    Secao::new("Estático: literal &str", dado_estatico_com_str_literal)
+        .aloca(0),
+    Secao::new("Stack: array de tamanho fixo", array_na_stack).aloca(0),
+    Secao::new("Estático: slice de bytes", slice_estatica).aloca(0),
+    Secao::new("Conferindo os endereços", conferindo_os_enderecos),
+];
+
 pub fn main() {
-    stack_com_tipo_primario();
-    heap_com_string();
-    dado_estatico_com_str_literal();
-    array_na_stack();
-    slice_estatica();
+    capitulos::executar_secoes(SECOES);
 }
 
 /// STACK: valores de tipo simples e tamanho fixo são armazenados diretamente na stack.
@@ -53,3 +64,28 @@ fn slice_estatica() {
     // slice aponta para dados em área estática
     println!("Slice estática: {:?}", slice);
 }
+
+/// CONFERINDO: os mesmos valores das seções acima, com o endereço de cada um
+/// e a região em que ele cai (veja `laboratorio::layout::classificar`).
+fn conferindo_os_enderecos() {
+    let x: i32 = 10;
+    let texto = String::from("alocado na heap");
+    let s: &str = "sou um dado estático";
+    let arr: [u8; 5] = [1, 2, 3, 4, 5];
+    let slice: &[u8] = b"static slice";
+
+    // Variável e conteúdo nem sempre moram no mesmo lugar
+    let enderecos = [
+        Endereco::new("x: i32", &x),
+        Endereco::new("texto: String (ptr, len, cap)", &texto),
+        Endereco::new("s (a referência)", &s),
+        Endereco::new("arr: [u8; 5] (o conteúdo junto)", &arr),
+        Endereco::new("\"alocado na heap\" (de texto)", texto.as_ptr()),
+        Endereco::new("\"sou um dado estático\"", s.as_ptr()),
+        Endereco::new("b\"static slice\"", slice.as_ptr()),
+    ];
+
+    for linha in layout::diagrama(&enderecos) {
+        println!("{linha}");
+    }
+}
@@ -1,4 +1,6 @@
 pub mod memoria;
 pub mod o_que_e;
+pub mod rastreio;
 pub mod referencias_e_borrowing;
 pub mod slice;
+pub mod tracos;
@@ -1,10 +1,20 @@
+use crate::capitulos::{self, Secao};
+use crate::ownership::rastreio::{self, Tracked};
+use crate::ownership::tracos;
+
+// `.aloca(n)`: quantas alocações na heap a seção faz. Um move não aloca, um
+// clone sim (confira com `run ownership::o_que_e --allocs`, veja `alocacoes`).
+pub const SECOES: &[Secao] = &[
+    Secao::new("Ownership básico", ownership_basico).aloca(1),
+    Secao::new("Ownership com funções", ownership_com_funcao).aloca(1),
+    Secao::new("clone vs move", clone_vs_move).aloca(2),
+    Secao::new("Referência imutável", referencia_imutavel).aloca(1),
+    Secao::new("Referência mutável", referencia_mutavel).aloca(2),
+    Secao::new("Tabela Copy vs não-Copy", tipos_copy_vs_clone),
+];
+
 pub fn main() {
-    ownership_basico();
-    ownership_com_funcao();
-    clone_vs_move();
-    referencia_imutavel();
-    referencia_mutavel();
-    tipos_copy_vs_clone();
+    capitulos::executar_secoes(SECOES);
 }
 
 /// Ownership básico: todo valor em Rust tem um "dono" (owner).
@@ -14,56 +24,76 @@ pub fn main() {
 ///
 /// Tipos como `String`, `Vec`, `Box` não implementam `Copy`, então são movidos.
 /// Tipos primitivos como `i32`, `bool`, `char` **implementam `Copy`**, então são duplicados.
+///
+/// A `String` vem dentro de um `Tracked` (veja `ownership::rastreio`), que
+/// imprime quando o valor é criado, movido e descartado.
 fn ownership_basico() {
-    // Exemplo 1: tipo não-Copy (String)
-    let s1 = String::from("olá");
+    rastreio::escopo("ownership_basico", || {
+        // Exemplo 1: tipo não-Copy (String)
+        let s1 = Tracked::new("s1", String::from("olá"));
+
+        // `String` é movido para s2. s1 perde a validade.
+        let s2 = s1.mover("s2");
 
-    // `String` é movido para s2. s1 perde a validade.
-    let s2 = s1;
+        // println!("{}", s1); // ERRO! s1 foi movido
+        // error[E0382]: tests/nao_compila/ownership_usar_depois_do_move.rs
+        // Passo a passo: resolution borrow --example move
+        println!("s2 ainda tem a string: {}", s2);
 
-    // println!("{}", s1); // ERRO! s1 foi movido
-    println!("s2 ainda tem a string: {}", s2);
+        // Exemplo 2: tipo Copy (i32)
+        let x = 42;
 
-    // Exemplo 2: tipo Copy (i32)
-    let x = 42;
+        // `i32` é `Copy`, então x é duplicado para y.
+        let y = x;
 
-    // `i32` é `Copy`, então x é duplicado para y.
-    let y = x;
+        // Ambas as variáveis são válidas
+        println!("x ainda é válido: {}", x);
+        println!("y também: {}", y);
 
-    // Ambas as variáveis são válidas
-    println!("x ainda é válido: {}", x);
-    println!("y também: {}", y);
+        // Fim do escopo: só s2 é descartado (s1 já não é dono de nada)
+    })
 }
 
 /// Funções também podem tomar posse de um valor.
 /// Quando passamos um valor diretamente para uma função (sem &), o dono muda.
 /// Isso também acontece com valores não-Copy como `String`.
 fn ownership_com_funcao() {
-    let nome = String::from("Rustacean");
+    rastreio::escopo("ownership_com_funcao", || {
+        let nome = Tracked::new("nome", String::from("Rustacean"));
 
-    // nome é movido para a função e não pode mais ser usado aqui
-    imprime_nome(nome);
+        // nome é movido para a função e não pode mais ser usado aqui
+        imprime_nome(nome.mover("s"));
 
-    // println!("{}", nome); // ERRO! nome foi movido
+        // println!("{}", nome); // ERRO! nome foi movido
+        // error[E0382]: tests/nao_compila/ownership_move_para_funcao.rs
+        // Passo a passo: resolution borrow --example funcao
+        // E no fim deste escopo não há drop: quem descartou foi imprime_nome
+    })
 }
 
 /// A função recebe um `String` por valor, ou seja, ela se torna a nova dona.
 /// Ao fim da função, o valor será descartado.
-fn imprime_nome(s: String) {
-    println!("Nome recebido: {}", s);
+fn imprime_nome(s: Tracked<String>) {
+    rastreio::escopo("imprime_nome", move || {
+        println!("Nome recebido: {}", s);
+    })
 }
 
 /// O método `.clone()` permite criar uma **cópia profunda** dos dados.
 /// Com `String`, isso significa criar uma nova alocação na heap,
 /// copiando todos os caracteres do original.
 fn clone_vs_move() {
-    let a = String::from("clone-me");
+    rastreio::escopo("clone_vs_move", || {
+        let a = Tracked::new("a", String::from("clone-me"));
 
-    // Aqui, b recebe uma cópia completa dos dados de a.
-    let b = a.clone();
+        // Aqui, b recebe uma cópia completa dos dados de a.
+        let b = a.clone();
 
-    // Como usamos `.clone()`, `a` ainda é válido.
-    println!("a: {}, b: {}", a, b);
+        // Como usamos `.clone()`, `a` ainda é válido.
+        println!("a: {}, b: {}", a, b);
+
+        // Dois valores, dois drops (na ordem contrária à da criação)
+    })
 }
 
 /// Em vez de mover o valor, podemos apenas **emprestá-lo** com `&`.
@@ -102,11 +132,14 @@ fn referencia_mutavel() {
 }
 
 /// A função recebe uma referência mutável e modifica o conteúdo da string.
+/// A `String` nasceu com a capacidade exata do texto, então o `push` precisa
+/// de um bloco maior na heap (uma realocação).
 fn adiciona_exclamacao(s: &mut String) {
     s.push('!');
 }
 
-/// Exibe uma tabela resumida de quais tipos possuem `Copy` e quais não.
+/// Exibe uma tabela de quais tipos possuem `Copy` (e `Clone`, `Send`,
+/// `Sync`, `Sized`, e se têm algo a fazer no drop) e quais não.
 ///
 /// Tipos `Copy`:
 /// - São simples, leves, e com tamanho fixo conhecido em tempo de compilação.
@@ -117,24 +150,17 @@ fn adiciona_exclamacao(s: &mut String) {
 /// - Envolvem dados alocados na heap ou recursos exclusivos.
 /// - Exigem `.clone()` para duplicação ou `&` para uso temporário.
 /// - Ex: String, Vec, Box, HashMap, structs que contêm esses tipos.
+///
+/// A tabela não é escrita à mão: cada "sim" ou "não" vem do compilador
+/// (veja `ownership::tracos`). Um `#[derive(Clone, Copy)]` em `Rectangle`
+/// aparece aqui sem mudar mais nada.
 fn tipos_copy_vs_clone() {
-    println!("\n=== Tabela de Tipos: Copy vs Não-Copy ===");
-    println!("{:<25} | {:<10}", "Tipo", "É Copy?");
-    println!("{}", "-".repeat(40));
-
-    // Tipos simples: Copy
-    println!("{:<25} | {:<10}", "i32", "Sim");
-    println!("{:<25} | {:<10}", "f64", "Sim");
-    println!("{:<25} | {:<10}", "bool", "Sim");
-    println!("{:<25} | {:<10}", "char", "Sim");
-    println!("{:<25} | {:<10}", "[i32; 3]", "Sim");
-    println!("{:<25} | {:<10}", "&str", "Sim");
-    println!("{:<25} | {:<10}", "&String", "Sim");
-
-    // Tipos compostos: não são Copy
-    println!("{:<25} | {:<10}", "String", "Não");
-    println!("{:<25} | {:<10}", "Vec<T>", "Não");
-    println!("{:<25} | {:<10}", "Box<T>", "Não");
-    println!("{:<25} | {:<10}", "HashMap<K, V>", "Não");
-    println!("{:<25} | {:<10}", "struct com String", "Não");
+    println!("\n=== Tabela de Tipos: Copy, Clone, Send, Sync, Sized, Drop ===");
+    tracos::imprimir();
+
+    // Repare: nenhum tipo com "Drop: sim" é Copy. Quem tem algo a liberar
+    // no fim do escopo não pode ser duplicado byte a byte.
+    println!();
+    println!("Drop = tem algo a liberar no fim do escopo (mem::needs_drop)");
+    println!("Sized = tamanho conhecido na compilação (str e [i32] não têm)");
 }
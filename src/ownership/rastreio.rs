@@ -0,0 +1,173 @@
+// ============================================================================
+// RASTREIO: um valor que avisa quando é criado, movido, clonado e descartado
+// ============================================================================
+//
+// Os exemplos de ownership dizem nos comentários "aqui s1 foi movido" e
+// "aqui o valor é descartado", mas nada disso aparece na saída. `Tracked<T>`
+// embrulha um valor e imprime cada evento com um id único no processo (`#1`,
+// `#2`...) e o escopo em que ele aconteceu:
+//
+//   ┌ ownership_com_funcao
+//   │ #1 nome = "Rustacean"
+//   │ #1 nome → s (move)
+//   │ ┌ imprime_nome
+//   Nome recebido: Rustacean
+//   │ │ #1 s drop
+//   │ └ imprime_nome
+//   └ ownership_com_funcao
+//
+// `Clone` e `Drop` são implementações de verdade, então o trace mostra o que
+// o compilador faz. Já o move não passa por código nenhum (é só uma cópia
+// de bytes), por isso ele aparece quando o exemplo chama `.mover("novo")`.
+//
+// Nada aqui aloca: o trace não entra na conta do `--allocs`.
+//
+// ============================================================================
+
+use std::{
+    cell::Cell,
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+thread_local! {
+    /// Quantos escopos estão abertos (a profundidade do recuo)
+    static PROFUNDIDADE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// O último id dado. Nunca volta a zero: dois valores com o mesmo id são
+/// sempre o mesmo valor, em qualquer escopo ou thread.
+static ULTIMO_ID: AtomicU64 = AtomicU64::new(0);
+
+/// O recuo do trace: um "│ " por escopo aberto
+struct Recuo(usize);
+
+impl fmt::Display for Recuo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (0..self.0).try_for_each(|_| f.write_str("│ "))
+    }
+}
+
+fn recuo() -> Recuo {
+    Recuo(PROFUNDIDADE.with(Cell::get))
+}
+
+/// Fecha o escopo mesmo se o exemplo entrar em pânico
+struct Fechar(&'static str);
+
+impl Drop for Fechar {
+    fn drop(&mut self) {
+        let profundidade = PROFUNDIDADE.with(|p| p.get().saturating_sub(1));
+        PROFUNDIDADE.with(|p| p.set(profundidade));
+        println!("{}└ {}", Recuo(profundidade), self.0);
+    }
+}
+
+/// Roda `f` como um escopo com nome: os eventos de dentro ficam recuados e
+/// o que for descartado no fim de `f` aparece antes do `└`.
+pub fn escopo<R>(nome: &'static str, f: impl FnOnce() -> R) -> R {
+    let profundidade = PROFUNDIDADE.with(Cell::get);
+    println!("{}┌ {nome}", Recuo(profundidade));
+    PROFUNDIDADE.with(|p| p.set(profundidade + 1));
+
+    let _fechar = Fechar(nome);
+    f()
+}
+
+/// Um valor com id e nome, que imprime o que acontece com ele
+pub struct Tracked<T> {
+    id: u64,
+    nome: &'static str,
+    /// Quantas vezes o nome vem com ′ (`a′` é um clone de `a`)
+    linhagem: u8,
+    valor: T,
+}
+
+impl<T> Tracked<T> {
+    fn proximo_id() -> u64 {
+        ULTIMO_ID.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Como o nome aparece no trace: `#1 s1`, `#2 a′`...
+    fn rotulo(&self) -> impl fmt::Display + '_ {
+        struct Rotulo<'a>(u64, &'a str, u8);
+
+        impl fmt::Display for Rotulo<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "#{} {}", self.0, self.1)?;
+                (0..self.2).try_for_each(|_| f.write_str("′"))
+            }
+        }
+
+        Rotulo(self.id, self.nome, self.linhagem)
+    }
+
+    /// O move em si não roda código: este método só troca o nome e deixa o
+    /// move aparecer no trace. O id continua o mesmo, porque o valor é o
+    /// mesmo.
+    pub fn mover(mut self, para: &'static str) -> Self {
+        println!("{}{} → {para} (move)", recuo(), self.rotulo());
+        self.nome = para;
+        self.linhagem = 0;
+        self
+    }
+}
+
+impl<T: fmt::Debug> Tracked<T> {
+    pub fn new(nome: &'static str, valor: T) -> Self {
+        let rastreado = Self {
+            id: Self::proximo_id(),
+            nome,
+            linhagem: 0,
+            valor,
+        };
+        println!("{}{} = {:?}", recuo(), rastreado.rotulo(), rastreado.valor);
+        rastreado
+    }
+}
+
+impl<T: Clone> Clone for Tracked<T> {
+    fn clone(&self) -> Self {
+        let clone = Self {
+            id: Self::proximo_id(),
+            nome: self.nome,
+            linhagem: self.linhagem.saturating_add(1),
+            valor: self.valor.clone(),
+        };
+        println!("{}{} = clone de {}", recuo(), clone.rotulo(), self.rotulo());
+        clone
+    }
+}
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        println!("{}{} drop", recuo(), self.rotulo());
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.valor
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.valor
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.valor.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.valor.fmt(f)
+    }
+}
@@ -1,12 +1,28 @@
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Referência básica", referencia_basica),
+    Secao::new("Referência com função", referencia_com_funcao),
+    Secao::new(
+        "Modificar via referência imutável",
+        tentativa_modificacao_imutavel,
+    ),
+    Secao::new("Referência mutável válida", referencia_mutavel_valida),
+    Secao::new(
+        "Duas referências mutáveis simultâneas",
+        referencias_mutaveis_simultaneas,
+    ),
+    Secao::new(
+        "Misturando referências mutáveis e imutáveis",
+        mistura_referencias,
+    ),
+    Secao::new("Mover enquanto emprestado", mover_enquanto_emprestado),
+    Secao::new("O escopo termina no último uso", escopo_encerra_referencia),
+    Secao::new("Referência pendurada", referencia_pendurada),
+];
+
 pub fn main() {
-    referencia_basica();
-    referencia_com_funcao();
-    tentativa_modificacao_imutavel();
-    referencia_mutavel_valida();
-    referencias_mutaveis_simultaneas();
-    mistura_referencias();
-    escopo_encerra_referencia();
-    referencia_pendurada();
+    capitulos::executar_secoes(SECOES);
 }
 
 /// Demonstra uma referência imutável básica, sem mover a posse.
@@ -39,6 +55,7 @@ fn exibe(s: &String) {
 fn tentativa_modificacao_imutavel() {
     let _s: String = String::from("hello");
     // change(&_s); // ERRO: não é permitido modificar via referência imutável
+    // error[E0596]: tests/nao_compila/borrowing_modificar_via_referencia_imutavel.rs
 }
 
 // fn change(s: &String) {
@@ -64,6 +81,8 @@ fn referencias_mutaveis_simultaneas() {
     // let r2 = &mut _s; // ERRO: não pode haver duas referências mutáveis ao mesmo tempo
 
     // println!("{}, {}", r1, r2);
+    // error[E0499]: tests/nao_compila/borrowing_duas_mutaveis.rs
+    // Passo a passo: resolution borrow --example mutaveis
 }
 
 /// Demonstra erro ao misturar referência mutável com imutáveis simultâneas
@@ -75,6 +94,19 @@ fn mistura_referencias() {
     // let r3 = &mut _s; // ERRO: referência mutável com imutáveis ativas
 
     // println!("{}, {}, {}", r1, r2, r3);
+    // error[E0502]: tests/nao_compila/borrowing_mutavel_com_imutaveis.rs
+    // Passo a passo: resolution borrow --example mistura
+}
+
+/// Demonstra erro ao mover um valor que ainda está emprestado
+fn mover_enquanto_emprestado() {
+    let _s: String = String::from("olá");
+
+    // let r = &_s;
+    // let t = _s; // ERRO: move de `_s` enquanto `r` ainda empresta
+    // println!("{}, {}", r, t);
+    // error[E0505]: tests/nao_compila/borrowing_mover_enquanto_emprestado.rs
+    // Passo a passo: resolution borrow --example emprestado
 }
 
 /// Mostra que o escopo termina na última vez que a referência é usada
@@ -84,6 +116,7 @@ fn escopo_encerra_referencia() {
     let r1: &String = &s;
     let r2: &String = &s;
     println!("{} e {}", r1, r2); // última vez que r1 e r2 são usados
+    // Passo a passo: resolution borrow --example nll
 
     // Agora é seguro criar uma referência mutável
     let r3: &mut String = &mut s;
@@ -100,6 +133,8 @@ fn referencia_pendurada() {
 //     let s = String::from("hello");
 //     &s
 // } // ERRO: s será dropado aqui e r apontaria para memória inválida
+// error[E0106]: tests/nao_compila/borrowing_referencia_pendurada.rs
+// Passo a passo: resolution borrow --example pendurada
 
 // Correto: retorna a String, transferindo a posse
 // fn no_dangle() -> String {
@@ -1,24 +1,24 @@
-pub fn main() {
-    println!("--- 1. Diferença entre String e &str ---");
-    exemplo_string_vs_str();
-
-    println!("\n--- 2. Por que aceitar &str e não &String ---");
-    exemplo_aceitar_str();
-
-    println!("\n--- 3. Problema de referência imutável travando mutável ---");
-    exemplo_bloqueio();
-
-    println!("\n--- 4. Como liberar referência imutável ---");
-    exemplo_liberar_referencia();
+use crate::capitulos::{self, Secao};
+use crate::ownership::rastreio::{self, Tracked};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Diferença entre String e &str", exemplo_string_vs_str),
+    Secao::new("Por que aceitar &str e não &String", exemplo_aceitar_str),
+    Secao::new(
+        "Problema de referência imutável travando mutável",
+        exemplo_bloqueio,
+    ),
+    Secao::new(
+        "Como liberar referência imutável",
+        exemplo_liberar_referencia,
+    ),
+    Secao::new("Como contornar usando .clone()", exemplo_clone),
+    Secao::new("&mut str: slice mutável", exemplo_mut_str),
+    Secao::new("Onde os dados de &str vivem", exemplo_armazenamento_str),
+];
 
-    println!("\n--- 5. Como contornar usando .clone() ---");
-    exemplo_clone();
-
-    println!("\n--- 6. &mut str: slice mutável ---");
-    exemplo_mut_str();
-
-    println!("\n--- 7. Onde os dados de &str vivem ---");
-    exemplo_armazenamento_str();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 /// 1. Mostra diferença de onde estão os dados e os tipos
@@ -49,7 +49,8 @@ fn exemplo_bloqueio() {
     let slice: &str = &s[..];
     println!("Primeira palavra (slice): {}", slice);
 
-    // s.push_str(" world"); // ❌ erro se descomentar
+    // s.push_str(" world"); // ❌ erro se descomentar: a slice ainda está em uso
+    // error[E0502]: tests/nao_compila/slice_mutar_com_slice_ativa.rs
     println!("Se eu tentar alterar aqui, Rust não deixa!");
 }
 
@@ -68,13 +69,15 @@ fn exemplo_liberar_referencia() {
 
 /// 5. Mostra como .clone() cria uma cópia e evita o problema
 fn exemplo_clone() {
-    let mut s: String = String::from("hello");
+    rastreio::escopo("exemplo_clone", || {
+        let mut s = Tracked::new("s", String::from("hello"));
 
-    let copia: String = s.clone(); // nova String, heap separada
-    s.push_str(" world"); // posso mutar livremente
+        let copia: Tracked<String> = s.clone(); // nova String, heap separada
+        s.push_str(" world"); // posso mutar livremente
 
-    println!("Original alterado: {}", s);
-    println!("Cópia intocada: {}", copia);
+        println!("Original alterado: {}", s);
+        println!("Cópia intocada: {}", copia);
+    })
 }
 
 /// 6. &mut str — slice mutável da String
@@ -0,0 +1,203 @@
+// ============================================================================
+// TRAÇOS: quem é Copy, Clone, Send, Sync e Sized, perguntado ao compilador
+// ============================================================================
+//
+// A tabela "É Copy?" do capítulo era escrita à mão, e uma tabela escrita à
+// mão fica errada no dia em que alguém põe `#[derive(Clone, Copy)]` num
+// tipo. Aqui cada célula é um `sonda!` (veja `crate::sonda`): quem responde
+// é o compilador.
+//
+// A coluna Drop não pergunta `T: Drop`. `String` não implementa `Drop` (quem
+// implementa é o `Vec` dentro dela), mas descartar uma `String` libera a
+// heap. O que importa para ownership é `mem::needs_drop`: o tipo tem algo a
+// fazer no fim do escopo? Um tipo assim nunca pode ser Copy.
+//
+// Os tipos do projeto que entram são os públicos (`pub` e `pub(crate)`) de
+// `src/`, com o arquivo na frente (`motor::Config`): um tipo privado só é
+// visível dentro do próprio módulo, e a tabela é montada aqui. O teste
+// `tests/ownership.rs` lê os fontes e falha se algum público ficar de fora.
+//
+// ============================================================================
+
+use std::{cell::Cell, collections::HashMap, rc::Rc};
+
+use crate::enums::{definindo_enums, if_let, option_enum};
+use crate::jogo_de_advinhacao::{
+    estrategias, forca, gravacao, motor, multijogador, placar, reverso,
+    simulacao,
+};
+use crate::laboratorio::{
+    bases, conversoes, erros, flutuante, layout, overflow, simulador, tipos,
+};
+use crate::ownership::{rastreio, tracos};
+use crate::structs::{definindo_structs, exemplo_retangulo, metodos};
+use crate::{alocacoes, capitulos, entrada, executor, sonda};
+
+/// O que um tipo implementa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tracos {
+    pub nome: &'static str,
+    pub copy: bool,
+    pub clone: bool,
+    pub send: bool,
+    pub sync: bool,
+    pub sized: bool,
+    /// `mem::needs_drop`: tem algo para liberar no fim do escopo
+    pub drop: bool,
+}
+
+macro_rules! tracos {
+    ($($tipo:ty),* $(,)?) => {
+        vec![$(Tracos {
+            nome: stringify!($tipo),
+            copy: sonda!($tipo: Copy),
+            clone: sonda!($tipo: Clone),
+            send: sonda!($tipo: Send),
+            sync: sonda!($tipo: Sync),
+            sized: sonda!($tipo: Sized),
+            drop: ::std::mem::needs_drop::<$tipo>(),
+        }),*]
+    };
+}
+
+/// Tipos da biblioteca padrão, um de cada jeito
+pub fn da_std() -> Vec<Tracos> {
+    tracos![
+        i32,
+        f64,
+        bool,
+        char,
+        [i32; 3],
+        (i32, bool),
+        &str,
+        &String,
+        &mut String,
+        str,
+        [i32],
+        String,
+        Vec<i32>,
+        Box<i32>,
+        HashMap<String, i32>,
+        Option<i32>,
+        Option<String>,
+        (i32, String),
+        Rc<i32>,
+        Cell<i32>,
+    ]
+}
+
+/// Os tipos públicos (`pub` e `pub(crate)`) do projeto: um `#[derive]` novo
+/// muda a tabela sozinho, e um tipo público novo que não entrar aqui quebra
+/// o teste
+pub fn do_projeto() -> Vec<Tracos> {
+    tracos![
+        // Capítulos
+        definindo_structs::User,
+        definindo_structs::Color,
+        definindo_structs::Point,
+        definindo_structs::AlwaysEqual,
+        exemplo_retangulo::RectangleSemDebug,
+        exemplo_retangulo::Rectangle,
+        metodos::Rectangle,
+        definindo_enums::IpAddrKind,
+        definindo_enums::IpAddrKindV1,
+        definindo_enums::IpAddrStruct,
+        definindo_enums::IpAddr,
+        definindo_enums::IpAddrDiff,
+        definindo_enums::Message,
+        if_let::Coin,
+        option_enum::User,
+        rastreio::Tracked<String>,
+        tracos::Tracos,
+        // Jogo
+        motor::Difficulty,
+        motor::Config,
+        motor::Hint,
+        motor::Feedback,
+        motor::State,
+        motor::GuessError,
+        motor::GuessingGame,
+        reverso::Contradiction,
+        reverso::ReverseGame,
+        forca::Outcome,
+        forca::HangmanError,
+        forca::HangmanGame,
+        estrategias::BinarySearch,
+        estrategias::Random,
+        estrategias::Linear,
+        estrategias::HumanLike,
+        gravacao::Retorno,
+        gravacao::Evento,
+        gravacao::Gravacao,
+        placar::Registro,
+        placar::Placar,
+        simulacao::Stats,
+        multijogador::Comando,
+        // Laboratório
+        tipos::Categoria,
+        tipos::TipoPrimitivo,
+        bases::Base,
+        bases::Literal,
+        bases::Representacoes,
+        conversoes::Conversao,
+        conversoes::Par,
+        flutuante::Inspecao,
+        flutuante::Surpresa,
+        overflow::Operacao,
+        overflow::Comparacao,
+        erros::Explicacao,
+        erros::Diagnostico,
+        layout::Campo,
+        layout::Variante,
+        layout::Layout,
+        layout::Regiao,
+        layout::Endereco,
+        simulador::Programa,
+        simulador::Passo,
+        simulador::Erro,
+        simulador::Simulacao,
+        // O resto do crate
+        capitulos::Capitulo,
+        capitulos::Secao,
+        executor::Resultado,
+        executor::Execucao,
+        entrada::Terminal,
+        entrada::Roteiro,
+        alocacoes::Alocacoes,
+        alocacoes::ContadorDeAlocacoes,
+        sonda::Sonda<String>,
+    ]
+}
+
+pub fn imprimir() {
+    let grupos = [("Biblioteca padrão", da_std()), ("Projeto (tipos públicos)", do_projeto())];
+    let largura = grupos
+        .iter()
+        .flat_map(|(_, linhas)| linhas.iter().map(|t| t.nome.len()))
+        .max()
+        .unwrap_or(0);
+
+    let cabecalho = format!(
+        "{:<largura$} | Copy | Clone | Send | Sync | Sized | Drop",
+        "Tipo"
+    );
+    let sim = |valor: bool| if valor { "sim" } else { "não" };
+
+    println!("{cabecalho}");
+    for (titulo, linhas) in &grupos {
+        println!("{}", "-".repeat(cabecalho.chars().count()));
+        println!("{titulo}");
+        for t in linhas {
+            println!(
+                "{:<largura$} | {:<4} | {:<5} | {:<4} | {:<4} | {:<5} | {}",
+                t.nome,
+                sim(t.copy),
+                sim(t.clone),
+                sim(t.send),
+                sim(t.sync),
+                sim(t.sized),
+                sim(t.drop)
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// ============================================================================
+// SAÍDA: para onde vai o que os capítulos imprimem
+// ============================================================================
+//
+// Dentro deste crate, `println!` é a macro definida aqui embaixo, e não a da
+// biblioteca padrão. Ela tem a mesma sintaxe, mas escreve na saída atual da
+// thread, que pode ser:
+//
+//   - o stdout (padrão)
+//   - um buffer em memória (`capturar`), usado pelos testes de snapshot
+//   - qualquer `io::Write` (`usar`, `com_saida`)
+//
+// Assim os capítulos continuam escritos como no livro (`println!("{x}")`),
+// mas a saída deles pode ser conferida.
+//
+// ============================================================================
+
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, Write},
+};
+
+/// Mesmo uso do `println!` da biblioteca padrão, mas escreve na saída atual
+/// da thread (veja `crate::saida`)
+macro_rules! println {
+    () => {
+        $crate::saida::escrever(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::saida::escrever(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+enum Destino {
+    Stdout,
+    Buffer(Vec<u8>),
+    Writer(Box<dyn Write>),
+}
+
+thread_local! {
+    static ATUAL: RefCell<Destino> = const { RefCell::new(Destino::Stdout) };
+}
+
+/// Escreve na saída atual. É o que o `println!` do crate chama.
+///
+/// O buffer de quem recebe a saída não é do exemplo, então fica fora da
+/// conta de `alocacoes::medir`.
+pub fn escrever(args: fmt::Arguments) {
+    crate::alocacoes::sem_contar(|| {
+        ATUAL.with(|atual| match &mut *atual.borrow_mut() {
+            Destino::Stdout => io::stdout().write_fmt(args),
+            Destino::Buffer(buffer) => buffer.write_fmt(args),
+            Destino::Writer(writer) => writer.write_fmt(args),
+        })
+    })
+    .expect("falha ao escrever na saída");
+}
+
+/// Troca a saída da thread atual
+pub fn usar(writer: impl Write + 'static) {
+    ATUAL.with(|atual| *atual.borrow_mut() = Destino::Writer(Box::new(writer)));
+}
+
+/// Volta para a saída guardada quando sai de escopo, mesmo se `f` entrar
+/// em pânico (o `executor` segue rodando os próximos capítulos)
+struct Restaurar(Option<Destino>);
+
+impl Drop for Restaurar {
+    fn drop(&mut self) {
+        if let Some(anterior) = self.0.take() {
+            trocar(anterior);
+        }
+    }
+}
+
+/// Roda `f` escrevendo em `writer` e depois volta para a saída anterior
+pub fn com_saida<R>(writer: impl Write + 'static, f: impl FnOnce() -> R) -> R {
+    let destino = Destino::Writer(Box::new(writer));
+    let _restaurar = Restaurar(Some(trocar(destino)));
+    f()
+}
+
+/// Roda `f` e devolve tudo o que foi impresso com `println!`
+pub fn capturar(f: impl FnOnce()) -> String {
+    let mut restaurar = Restaurar(Some(trocar(Destino::Buffer(Vec::new()))));
+    f();
+
+    let anterior = restaurar.0.take().expect("a saída anterior sumiu");
+    match trocar(anterior) {
+        Destino::Buffer(buffer) => {
+            String::from_utf8_lossy(&buffer).into_owned()
+        }
+        _ => unreachable!("a saída capturada foi trocada durante a captura"),
+    }
+}
+
+fn trocar(destino: Destino) -> Destino {
+    ATUAL.with(|atual| atual.replace(destino))
+}
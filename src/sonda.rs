@@ -0,0 +1,82 @@
+// ============================================================================
+// SONDA: perguntar ao compilador se um tipo implementa uma trait
+// ============================================================================
+//
+// Rust não tem "if T: Copy" em tempo de execução, mas a busca de métodos dá
+// um jeito. É o truque de "autoref specialization":
+//
+//   impl<T: Copy> Sim for Sonda<T> {}      // método em `&Sonda<T>`
+//   impl<T> Nao for &Sonda<T> {}           // método em `&&Sonda<T>`
+//   (&Sonda::<String>::new()).responder()
+//
+// A busca tenta primeiro o receptor como ele é (`&Sonda<T>`, onde só `Sim`
+// serve, e só se o bound vale) e só depois com um `&` a mais (onde `Nao`
+// sempre serve). Quem escolhe é o compilador, então a resposta acompanha a
+// biblioteca padrão e os `#[derive]` do projeto sem tabela escrita à mão.
+//
+// A escolha acontece onde o tipo está escrito, e não dentro de uma função
+// genérica (lá o bound ainda não é conhecido). Por isso a pergunta é uma
+// macro, `sonda!`, usada pela matriz de `laboratorio::conversoes` e pela
+// tabela de `ownership::tracos`.
+//
+// ============================================================================
+
+use std::marker::PhantomData;
+
+/// O receptor das perguntas: não guarda nada, só o tipo
+pub struct Sonda<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Sonda<T> {
+    pub const fn new() -> Self {
+        Sonda(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for Sonda<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `sonda!(String: Copy)` responde `false`. Com resposta própria, o valor
+/// de "sim" só precisa compilar quando os bounds valem:
+///
+/// ```text
+/// sonda!(D = u16; D: From<u8>; Option<fn(u8) -> D>; Some(D::from); None)
+/// ```
+///
+/// `D: ?Sized = str` aceita tipos sem tamanho (a forma curta já aceita).
+macro_rules! sonda {
+    (
+        $p:ident $(: ?$relaxado:path)? = $tipo:ty;
+        $($bound:ty: $traco:path),+;
+        $resposta:ty; $sim:expr; $nao:expr $(;)?
+    ) => {{
+        // Uma das duas nunca é escolhida, e é esse o ponto
+        #[allow(dead_code)]
+        trait Sim<$p $(: ?$relaxado)?> {
+            fn responder(&self) -> $resposta;
+        }
+        impl<$p $(: ?$relaxado)?> Sim<$p> for $crate::sonda::Sonda<$p>
+        where
+            $($bound: $traco),+
+        {
+            fn responder(&self) -> $resposta {
+                $sim
+            }
+        }
+
+        #[allow(dead_code)]
+        trait Nao<$p $(: ?$relaxado)?> {
+            fn responder(&self) -> $resposta {
+                $nao
+            }
+        }
+        impl<$p $(: ?$relaxado)?> Nao<$p> for &$crate::sonda::Sonda<$p> {}
+
+        (&$crate::sonda::Sonda::<$tipo>::new()).responder()
+    }};
+    ($tipo:ty: $traco:path) => {
+        sonda!(T: ?Sized = $tipo; T: $traco; bool; true; false)
+    };
+}
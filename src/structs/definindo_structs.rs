@@ -14,30 +14,21 @@
 //   let user = User { active: true, username: String::from("foo") };
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. Struct básica ---");
-    struct_basica();
-
-    println!("\n--- 2. Struct mutável ---");
-    struct_mutavel();
-
-    println!("\n--- 3. Função que retorna struct ---");
-    funcao_retorna_struct();
-
-    println!("\n--- 4. Field init shorthand ---");
-    field_init_shorthand();
+use crate::capitulos::{self, Secao};
+
+pub const SECOES: &[Secao] = &[
+    Secao::new("Struct básica", struct_basica),
+    Secao::new("Struct mutável", struct_mutavel),
+    Secao::new("Função que retorna struct", funcao_retorna_struct),
+    Secao::new("Field init shorthand", field_init_shorthand),
+    Secao::new("Struct update syntax", struct_update_syntax),
+    Secao::new("Tuple structs", tuple_structs),
+    Secao::new("Unit-like structs", unit_structs),
+    Secao::new("Ownership em structs", ownership_em_structs),
+];
 
-    println!("\n--- 5. Struct update syntax ---");
-    struct_update_syntax();
-
-    println!("\n--- 6. Tuple structs ---");
-    tuple_structs();
-
-    println!("\n--- 7. Unit-like structs ---");
-    unit_structs();
-
-    println!("\n--- 8. Ownership em structs ---");
-    ownership_em_structs();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -46,11 +37,12 @@ pub fn main() {
 
 /// Define a struct User com 4 campos tipados
 /// Isso é como um `interface` ou `type` no TypeScript
-struct User {
-    active: bool,
-    username: String, // String, não &str (a struct é dona dos dados)
-    email: String,
-    sign_in_count: u64,
+/// (`pub(crate)` só para o `laboratorio::layout` medir os campos)
+pub(crate) struct User {
+    pub(crate) active: bool,
+    pub(crate) username: String, // String, não &str (a struct é dona dos dados)
+    pub(crate) email: String,
+    pub(crate) sign_in_count: u64,
 }
 
 /// Criando e acessando uma instância
@@ -174,6 +166,7 @@ fn struct_update_syntax() {
 
     // ⚠️ CUIDADO: user1.username foi MOVIDO para user2!
     // println!("{}", user1.username);  // ❌ ERRO! username foi movido
+    // error[E0382]: tests/nao_compila/structs_campo_movido_no_update.rs
 
     // Mas campos Copy (bool, u64) ainda são válidos em user1:
     println!("user1.active ainda é válido: {}", user1.active);
@@ -209,8 +202,8 @@ fn struct_update_syntax() {
 /// Útil quando você quer tipos distintos sem nomear cada campo
 
 // Ambas têm 3 valores i32, mas são tipos DIFERENTES
-struct Color(i32, i32, i32);
-struct Point(i32, i32, i32);
+pub(crate) struct Color(i32, i32, i32);
+pub(crate) struct Point(i32, i32, i32);
 
 fn tuple_structs() {
     let black = Color(0, 0, 0);
@@ -230,6 +223,7 @@ fn tuple_structs() {
     // ❌ Não pode passar Color onde espera Point:
     // fn move_point(p: Point) { ... }
     // move_point(black);  // ERRO! Color não é Point
+    // error[E0308]: tests/nao_compila/structs_tuple_structs_diferentes.rs
 }
 
 // ============================================================================
@@ -238,7 +232,7 @@ fn tuple_structs() {
 
 /// Structs sem nenhum campo, usadas para implementar traits
 /// Comportam-se como () (unit type)
-struct AlwaysEqual;
+pub(crate) struct AlwaysEqual;
 
 fn unit_structs() {
     let _subject = AlwaysEqual;
@@ -273,6 +267,7 @@ fn ownership_em_structs() {
     //     username: &str,  // ERRO: precisa de lifetime
     //     email: &str,     // ERRO: precisa de lifetime
     // }
+    // error[E0106]: tests/nao_compila/structs_referencia_sem_lifetime.rs
 
     // Para usar referências, precisamos de lifetimes (capítulo 10):
     // struct UserRef<'a> {
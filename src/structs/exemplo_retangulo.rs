@@ -14,21 +14,18 @@
 //
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. Com variáveis soltas (ruim) ---");
-    com_variaveis_soltas();
-
-    println!("\n--- 2. Com tuplas (melhor, mas ainda confuso) ---");
-    com_tuplas();
-
-    println!("\n--- 3. Com structs (ideal) ---");
-    com_structs();
+use crate::capitulos::{self, Secao};
 
-    println!("\n--- 4. Debug trait e formatação ---");
-    debug_trait();
+pub const SECOES: &[Secao] = &[
+    Secao::new("Com variáveis soltas (ruim)", com_variaveis_soltas),
+    Secao::new("Com tuplas (melhor, mas ainda confuso)", com_tuplas),
+    Secao::new("Com structs (ideal)", com_structs),
+    Secao::new("Debug trait e formatação", debug_trait),
+    Secao::new("Macro dbg!()", macro_dbg),
+];
 
-    println!("\n--- 5. Macro dbg!() ---");
-    macro_dbg();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -61,10 +58,7 @@ fn area_v1(width: u32, height: u32) -> u32 {
 fn com_tuplas() {
     let rect1 = (30, 50); // (largura, altura)
 
-    println!(
-        "Área do retângulo: {} pixels quadrados",
-        area_v2(rect1)
-    );
+    println!("Área do retângulo: {} pixels quadrados", area_v2(rect1));
 
     // Problema: dimensions.0 é largura ou altura?
     // Fácil de confundir e introduzir bugs
@@ -79,14 +73,14 @@ fn area_v2(dimensions: (u32, u32)) -> u32 {
 // ============================================================================
 
 /// Struct sem Debug (ainda não podemos imprimir com {:?})
-struct RectangleSemDebug {
+pub(crate) struct RectangleSemDebug {
     width: u32,
     height: u32,
 }
 
 /// Struct COM Debug - permite imprimir com {:?}
 #[derive(Debug)]
-struct Rectangle {
+pub(crate) struct Rectangle {
     width: u32,
     height: u32,
 }
@@ -98,10 +92,7 @@ fn com_structs() {
     };
 
     // Passa por REFERÊNCIA (&rect1) para não mover ownership
-    println!(
-        "Área do retângulo: {} pixels quadrados",
-        area_v3(&rect1)
-    );
+    println!("Área do retângulo: {} pixels quadrados", area_v3(&rect1));
 
     // rect1 ainda é válido porque só emprestamos
     println!("Largura: {}", rect1.width);
@@ -127,6 +118,7 @@ fn debug_trait() {
     // ❌ Isso NÃO funciona sem #[derive(Debug)]:
     // let rect_sem = RectangleSemDebug { width: 10, height: 20 };
     // println!("{:?}", rect_sem);  // ERRO: não implementa Debug
+    // error[E0277]: tests/nao_compila/structs_sem_debug.rs
 
     // ✅ Com #[derive(Debug)], podemos usar {:?}
     println!("Debug simples:  {:?}", rect);
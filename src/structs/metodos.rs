@@ -16,24 +16,19 @@
 //
 // ============================================================================
 
-pub fn main() {
-    println!("--- 1. Método básico com &self ---");
-    metodo_basico();
-
-    println!("\n--- 2. Tipos de self ---");
-    tipos_de_self();
-
-    println!("\n--- 3. Método com mesmo nome de campo ---");
-    metodo_mesmo_nome_campo();
+use crate::capitulos::{self, Secao};
 
-    println!("\n--- 4. Métodos com mais parâmetros ---");
-    metodos_com_parametros();
+pub const SECOES: &[Secao] = &[
+    Secao::new("Método básico com &self", metodo_basico),
+    Secao::new("Tipos de self", tipos_de_self),
+    Secao::new("Método com mesmo nome de campo", metodo_mesmo_nome_campo),
+    Secao::new("Métodos com mais parâmetros", metodos_com_parametros),
+    Secao::new("Associated functions (construtores)", associated_functions),
+    Secao::new("Múltiplos blocos impl", multiplos_impl),
+];
 
-    println!("\n--- 5. Associated functions (construtores) ---");
-    associated_functions();
-
-    println!("\n--- 6. Múltiplos blocos impl ---");
-    multiplos_impl();
+pub fn main() {
+    capitulos::executar_secoes(SECOES);
 }
 
 // ============================================================================
@@ -41,9 +36,9 @@ pub fn main() {
 // ============================================================================
 
 #[derive(Debug)]
-struct Rectangle {
-    width: u32,
-    height: u32,
+pub(crate) struct Rectangle {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
 }
 
 // ============================================================================
@@ -134,6 +129,7 @@ fn tipos_de_self() {
     let square = rect3.into_square();
     println!("Transformado em quadrado: {:?}", square);
     // println!("{:?}", rect3);  // ❌ ERRO! rect3 foi consumido
+    // error[E0382]: tests/nao_compila/metodos_self_consumido.rs
 }
 
 fn metodo_mesmo_nome_campo() {
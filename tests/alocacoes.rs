@@ -0,0 +1,117 @@
+// ============================================================================
+// ALOCAÇÕES: confere o que cada seção afirma sobre a heap
+// ============================================================================
+//
+// Este binário de teste usa o `ContadorDeAlocacoes` como alocador global, e
+// cada seção marcada com `.aloca(n)` roda medida. Se uma seção que diz "não
+// aloca" passar a alocar (ou o contrário), o teste falha.
+//
+// ============================================================================
+
+use resolution::alocacoes::{self, Alocacoes, ContadorDeAlocacoes};
+use resolution::capitulos::{CAPITULOS, Secao};
+use resolution::entrada::{self, Roteiro};
+use resolution::saida;
+
+#[global_allocator]
+static ALOCADOR: ContadorDeAlocacoes = ContadorDeAlocacoes;
+
+/// Roda a seção sem interação e com a saída capturada, medindo só ela
+fn medir(secao: &Secao) -> Alocacoes {
+    let mut medido = Alocacoes::default();
+    entrada::com_entrada(Roteiro::default(), || {
+        saida::capturar(|| medido = alocacoes::medir(secao.executar).1)
+    });
+    medido
+}
+
+#[test]
+fn contador_conta_so_o_que_foi_medido() {
+    assert!(alocacoes::instalado());
+
+    let (texto, medido) = alocacoes::medir(|| String::from("heap"));
+    assert_eq!(medido.alocacoes, 1);
+    assert_eq!(medido.liberacoes, 0);
+    assert_eq!(medido.bytes, 4);
+
+    let ((), medido) = alocacoes::medir(|| drop(texto));
+    assert_eq!(medido.alocacoes, 0);
+    assert_eq!(medido.liberacoes, 1);
+
+    // O buffer do `println!` dos capítulos não entra na conta
+    let saida = saida::capturar(|| {
+        let ((), medido) =
+            alocacoes::medir(|| saida::escrever(format_args!("{}\n", 42)));
+        assert_eq!(medido, Alocacoes::default());
+    });
+    assert_eq!(saida, "42\n");
+}
+
+#[test]
+fn medicao_sobrevive_a_um_panico_aninhado() {
+    let ((), medido) = alocacoes::medir(|| {
+        let antes = std::hint::black_box(vec![0_u8; 1 << 20]);
+        // `resume_unwind` desenrola sem o hook de pânico (que alocaria)
+        let panico = std::panic::catch_unwind(|| {
+            alocacoes::medir(|| std::panic::resume_unwind(Box::new(())))
+        });
+        assert!(panico.is_err());
+        drop(antes);
+    });
+
+    // O que foi contado antes do pânico continua na medição de fora
+    assert!(medido.bytes >= 1 << 20, "{medido:?}");
+    assert!(medido.liberacoes >= 1, "{medido:?}");
+
+    // E a thread parou de contar quando a medição acabou
+    let (texto, medido) = alocacoes::medir(|| String::from("heap"));
+    assert_eq!(medido.alocacoes, 1);
+    drop(texto);
+}
+
+#[test]
+fn secoes_sem_alocacao_nao_alocam() {
+    let sem_alocacao: Vec<_> = CAPITULOS
+        .iter()
+        .flat_map(|c| c.secoes.iter().map(move |s| (c, s)))
+        .filter(|(_, secao)| secao.alocacoes == Some(0))
+        .collect();
+    assert!(!sem_alocacao.is_empty());
+
+    for (capitulo, secao) in sem_alocacao {
+        let medido = medir(secao);
+        assert_eq!(
+            medido,
+            Alocacoes::default(),
+            "{} / {} afirma que não aloca",
+            capitulo.modulo,
+            secao.titulo
+        );
+    }
+}
+
+#[test]
+fn secoes_alocam_o_que_afirmam() {
+    for capitulo in CAPITULOS {
+        for secao in capitulo.secoes {
+            let Some(esperado) = secao.alocacoes else {
+                continue;
+            };
+            let medido = medir(secao);
+
+            assert_eq!(
+                medido.alocacoes,
+                esperado,
+                "{} / {}: {}",
+                capitulo.modulo,
+                secao.titulo,
+                alocacoes::relatorio(medido, secao.alocacoes)
+            );
+            assert_eq!(
+                medido.liberacoes, medido.alocacoes,
+                "{} / {} deixou memória alocada",
+                capitulo.modulo, secao.titulo
+            );
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use resolution::capitulos;
+use resolution::entrada::{self, Roteiro};
+use resolution::executor::{self, Resultado};
+use resolution::saida;
+
+#[test]
+fn panico_vira_resultado_com_mensagem_e_local() {
+    let (resultado, _) = executor::isolar(|| {
+        let v: Vec<i32> = Vec::new();
+        let _ = v[3];
+    });
+
+    let Resultado::Panico { mensagem, local } = resultado else {
+        panic!("esperava pânico, veio {resultado:?}");
+    };
+    assert!(mensagem.contains("index out of bounds"), "{mensagem}");
+    assert!(local.starts_with("tests/executor.rs:"), "{local}");
+}
+
+#[test]
+fn capitulos_continuam_depois_de_um_panico() {
+    let tipos = capitulos::buscar("02");
+    let funcoes = capitulos::buscar("03");
+
+    // índice 10 faz `acesso_invalido` entrar em pânico
+    let execucoes = entrada::com_entrada(Roteiro::new(["10"]), || {
+        let mut execucoes = Vec::new();
+        saida::capturar(|| {
+            execucoes = executor::rodar_todos(tipos.into_iter().chain(funcoes));
+        });
+        execucoes
+    });
+
+    assert_eq!(execucoes.len(), 2);
+    assert!(!execucoes[0].passou());
+    assert!(execucoes[1].passou());
+}
+
+#[test]
+fn panico_nao_deixa_a_saida_nem_a_entrada_trocadas() {
+    let mut lida = None;
+    let saida = saida::capturar(|| {
+        entrada::com_entrada(Roteiro::new(["de fora"]), || {
+            let (resultado, _) = executor::isolar(|| {
+                entrada::com_entrada(Roteiro::default(), || {
+                    saida::capturar(|| panic!("no meio da captura"));
+                });
+            });
+            assert!(matches!(resultado, Resultado::Panico { .. }));
+
+            lida = entrada::ler_linha();
+            saida::escrever(format_args!("depois do pânico\n"));
+        });
+    });
+
+    assert_eq!(lida.as_deref(), Some("de fora"));
+    assert_eq!(saida, "> de fora\ndepois do pânico\n");
+}
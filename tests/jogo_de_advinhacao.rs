@@ -0,0 +1,595 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+use resolution::entrada::{self, Entrada, Roteiro};
+use resolution::jogo_de_advinhacao::estrategias::{
+    self, BinarySearch, HumanLike, Linear,
+};
+use resolution::jogo_de_advinhacao::forca::{
+    self, HangmanError, HangmanGame, Outcome,
+};
+use resolution::jogo_de_advinhacao::gravacao::{Gravacao, Retorno};
+use resolution::jogo_de_advinhacao::multijogador::{self, Comando};
+use resolution::jogo_de_advinhacao::placar::{Placar, Registro};
+use resolution::jogo_de_advinhacao::reverso::ReverseGame;
+use resolution::jogo_de_advinhacao::simulacao::{self, Stats};
+use resolution::jogo_de_advinhacao::{
+    Config, Difficulty, GuessError, GuessingGame, Hint, State, terminal,
+};
+use resolution::saida;
+
+#[test]
+fn mesma_semente_gera_o_mesmo_secreto() {
+    let a = GuessingGame::with_seed(Config::default(), 42);
+    let b = GuessingGame::with_seed(Config::default(), 42);
+
+    assert_eq!(a.secret(), b.secret());
+    assert_eq!(a.seed(), 42);
+}
+
+#[test]
+fn dicas_e_tentativas_seguem_a_comparacao() {
+    let mut game = GuessingGame::with_seed(Config::default(), 7);
+    let secret = game.secret();
+
+    let abaixo = game.guess(secret - 1).unwrap();
+    assert_eq!(abaixo.hint, Hint::Higher);
+    assert_eq!(abaixo.attempts, 1);
+
+    let acima = game.guess(secret + 1).unwrap();
+    assert_eq!(acima.hint, Hint::Lower);
+    assert_eq!(acima.attempts, 2);
+
+    assert!(!game.is_won());
+    assert_eq!(game.guess(secret).unwrap().hint, Hint::Correct);
+    assert!(game.is_won());
+    assert_eq!(game.attempts(), 3);
+}
+
+#[test]
+fn faixa_larga_e_chute_fora_da_faixa() {
+    let config = Config::new(1, 1_000_000_000_000, None).unwrap();
+    let mut game = GuessingGame::with_seed(config, 1);
+
+    assert!(config.contains(game.secret()));
+    assert_eq!(
+        game.guess(0),
+        Err(GuessError::OutOfRange {
+            min: 1,
+            max: 1_000_000_000_000
+        })
+    );
+    assert_eq!(game.attempts(), 0, "chute recusado não conta");
+}
+
+#[test]
+fn perde_quando_as_tentativas_acabam() {
+    let config = Config::new(1, 10, Some(2)).unwrap();
+    let mut game = GuessingGame::with_seed(config, 3);
+    let errado = if game.secret() == 1 { 2 } else { 1 };
+
+    assert_eq!(game.guess(errado).unwrap().remaining, Some(1));
+    assert_eq!(game.guess(errado).unwrap().remaining, Some(0));
+    assert_eq!(game.state(), State::Lost);
+    assert_eq!(game.guess(game.secret()), Err(GuessError::GameOver));
+}
+
+#[test]
+fn dificuldades_pelo_nome() {
+    assert_eq!("dificil".parse::<Difficulty>(), Ok(Difficulty::Hard));
+    assert_eq!(Difficulty::Easy.config().label(), "facil");
+    assert_eq!(Config::new(1, 500, Some(9)).unwrap().label(), "1..=500/9");
+    assert!("impossivel".parse::<Difficulty>().is_err());
+}
+
+#[test]
+fn terminal_explica_entrada_invalida_e_para_no_acerto() {
+    let mut game = GuessingGame::with_seed(Config::default(), 7);
+    let secret = game.secret().to_string();
+
+    let saida = entrada::com_entrada(
+        Roteiro::new(["abc", "99999999999999999999", "200", &secret]),
+        || saida::capturar(|| terminal::jogar(&mut game)),
+    );
+
+    assert!(saida.contains("'abc' não é um número inteiro."));
+    assert!(saida.contains("99999999999999999999 está fora da faixa"));
+    assert!(saida.contains("Chute não contado"));
+    assert!(saida.contains("Parabéns! Você acertou em 1 tentativa(s)."));
+}
+
+#[test]
+fn placar_ordena_e_ignora_linhas_invalidas() {
+    let caminho = std::env::temp_dir()
+        .join(format!("resolution-placar-{}.tsv", std::process::id()));
+    std::fs::write(&caminho, "lixo\nAna\tfacil\tdez\t0\t0\n\u{FFFD}\n")
+        .unwrap();
+
+    let registros = [
+        Registro::new("Ana", "facil", 5, Duration::from_secs(9)),
+        Registro::new("Bia", "facil", 3, Duration::from_secs(20)),
+        Registro::new("Caio", "facil", 5, Duration::from_secs(4)),
+        Registro::new("Duda\tTab", "dificil", 8, Duration::from_secs(30)),
+    ];
+    for registro in &registros {
+        Placar::adicionar(&caminho, registro).unwrap();
+    }
+
+    let placar = Placar::carregar(&caminho).unwrap();
+    std::fs::remove_file(&caminho).unwrap();
+
+    assert_eq!(placar.linhas_invalidas, 3);
+    assert_eq!(placar.dificuldades(), ["facil", "dificil"]);
+
+    let nomes: Vec<&str> = placar
+        .melhores("facil", 2)
+        .iter()
+        .map(|r| r.nome.as_str())
+        .collect();
+    assert_eq!(nomes, ["Bia", "Caio"]);
+    assert_eq!(placar.melhores("dificil", 10)[0].nome, "Duda Tab");
+}
+
+#[test]
+fn placar_inexistente_e_vazio() {
+    let placar =
+        Placar::carregar(Path::new("/caminho/que/nao/existe.tsv")).unwrap();
+    assert!(placar.registros.is_empty());
+}
+
+#[test]
+fn data_do_registro_em_utc() {
+    let mut registro = Registro::new("Ana", "facil", 1, Duration::ZERO);
+    registro.quando = 1_709_164_800; // 2024-02-29 00:00:00 UTC
+    assert_eq!(registro.data(), "2024-02-29");
+}
+
+#[test]
+fn reverso_acha_qualquer_numero_dentro_do_otimo() {
+    let config = Config::new(1, 100, None).unwrap();
+
+    for secreto in 1..=100 {
+        let mut game = ReverseGame::new(config);
+        while !game.is_found() {
+            let hint = Hint::from(game.next_guess().cmp(&secreto));
+            game.answer(hint).unwrap();
+        }
+        assert!(game.attempts() <= game.optimum(), "secreto {secreto}");
+    }
+
+    assert_eq!(ReverseGame::new(config).optimum(), 7);
+}
+
+#[test]
+fn reverso_detecta_respostas_contraditorias() {
+    let config = Config::new(1, 10, None).unwrap();
+    let mut game = ReverseGame::new(config);
+
+    // "maior que 5", depois "menor que 8", "menor que 6": sobra nada
+    game.answer(Hint::Higher).unwrap();
+    game.answer(Hint::Lower).unwrap();
+    assert_eq!(game.remaining_range(), (6, 7));
+    let erro = game.answer(Hint::Lower).unwrap_err();
+
+    assert_eq!(erro.guess, 6);
+    assert_eq!((erro.min, erro.max), (6, 7));
+}
+
+#[test]
+fn simulacao_e_deterministica_e_binaria_respeita_o_otimo() {
+    let config = Config::default();
+
+    for estrategia in estrategias::todas().iter_mut() {
+        let a = simulacao::simulate(estrategia.as_mut(), config, 300, 9);
+        let b = simulacao::simulate(estrategia.as_mut(), config, 300, 9);
+        assert_eq!(a.attempts, b.attempts, "{}", a.strategy);
+        assert_eq!(a.games(), 300);
+    }
+
+    let binaria =
+        simulacao::simulate(&mut BinarySearch::default(), config, 1000, 0);
+    assert!(binaria.max() <= 7);
+
+    let linear = simulacao::simulate(&mut Linear::default(), config, 1000, 0);
+    assert!(linear.mean() > binaria.mean());
+}
+
+#[test]
+fn estrategias_nao_estouram_nos_extremos_do_i64() {
+    for (min, max) in [(i64::MAX - 7, i64::MAX), (i64::MIN, i64::MIN + 7)] {
+        let config = Config::new(min, max, None).unwrap();
+        for estrategia in estrategias::todas().iter_mut() {
+            let stats = simulacao::simulate(estrategia.as_mut(), config, 50, 3);
+            assert_eq!(
+                stats.games(),
+                50,
+                "{} em {min}..={max}",
+                stats.strategy
+            );
+        }
+    }
+
+    // Na faixa inteira do i64: o chute nunca sai da faixa, e a binária
+    // continua com ⌈log₂ n⌉ = 64
+    let config = Config::new(i64::MIN, i64::MAX, None).unwrap();
+    let binaria =
+        simulacao::simulate(&mut BinarySearch::default(), config, 50, 3);
+    assert!(binaria.max() <= 64);
+    simulacao::simulate(&mut HumanLike::default(), config, 50, 3);
+
+    // A linear termina também: cada partida para no limite e conta como
+    // cortada
+    let linear = simulacao::simulate(&mut Linear::default(), config, 3, 3);
+    assert_eq!((linear.games(), linear.failures), (3, 3));
+    assert!(linear.attempts.is_empty());
+}
+
+#[test]
+fn estatisticas_da_simulacao() {
+    let stats = Stats {
+        strategy: "teste",
+        attempts: vec![1, 2, 2, 5],
+        failures: 1,
+    };
+
+    assert_eq!(stats.games(), 5);
+
+    assert_eq!(stats.mean(), 2.5);
+    assert_eq!(stats.median(), 2.0);
+    assert_eq!(stats.max(), 5);
+    assert_eq!(stats.histogram().get(&2), Some(&2));
+}
+
+#[test]
+fn gravacao_salva_e_reproduz_a_partida() {
+    let mut game = GuessingGame::with_seed(Config::default(), 11);
+    let secret = game.secret();
+    let mut gravacao = Gravacao::new(&game);
+
+    // Sem acerto: a partida termina porque a entrada acabou
+    let errado = if secret == 1 { 2 } else { 1 }.to_string();
+    entrada::com_entrada(Roteiro::new(["abc", "", "500", &errado]), || {
+        saida::capturar(|| {
+            terminal::jogar_gravando(&mut game, Some(&mut gravacao))
+        })
+    });
+
+    let retornos: Vec<Retorno> =
+        gravacao.eventos.iter().map(|e| e.retorno).collect();
+    assert_eq!(
+        retornos,
+        [
+            Retorno::Invalida,
+            Retorno::Invalida,
+            Retorno::Recusada,
+            Retorno::Dica(Hint::from(
+                errado.parse::<i64>().unwrap().cmp(&secret)
+            )),
+            Retorno::Fim,
+        ]
+    );
+    assert_eq!(gravacao.eventos[1].linha.as_deref(), Some(""));
+    assert_eq!(gravacao.eventos[4].linha, None);
+
+    let caminho = std::env::temp_dir()
+        .join(format!("resolution-gravacao-{}.txt", std::process::id()));
+    gravacao.salvar(&caminho).unwrap();
+    let carregada = Gravacao::carregar(&caminho).unwrap();
+    std::fs::remove_file(&caminho).unwrap();
+
+    assert_eq!(carregada.seed, 11);
+    assert_eq!(carregada.config, Config::default());
+    assert_eq!(carregada.primeira_divergencia(&gravacao), None);
+
+    let saida = saida::capturar(|| assert!(terminal::reproduzir(&carregada)));
+    assert!(saida.contains("Entrada encerrada."));
+    assert!(saida.contains("A reprodução bateu com a gravação (5 evento(s))."));
+}
+
+#[test]
+fn gravacao_guarda_a_linha_como_foi_lida() {
+    let mut game = GuessingGame::with_seed(Config::default(), 11);
+    let secret = game.secret();
+    let mut gravacao = Gravacao::new(&game);
+
+    let linhas = [
+        "  abc ".to_string(),
+        "\t\\x".to_string(),
+        format!(" {secret}\r"),
+    ];
+    entrada::com_entrada(Roteiro::new(linhas.clone()), || {
+        saida::capturar(|| {
+            terminal::jogar_gravando(&mut game, Some(&mut gravacao))
+        })
+    });
+
+    // O chute com espaços e `\r` conta, mas a gravação não os perde
+    let gravadas: Vec<_> =
+        gravacao.eventos.iter().map(|e| e.linha.clone()).collect();
+    assert_eq!(gravadas, linhas.map(Some));
+    assert_eq!(gravacao.eventos[2].retorno, Retorno::Dica(Hint::Correct));
+
+    let caminho = std::env::temp_dir()
+        .join(format!("resolution-espacos-{}.txt", std::process::id()));
+    gravacao.salvar(&caminho).unwrap();
+    let salvo = std::fs::read_to_string(&caminho).unwrap();
+    let carregada = Gravacao::carregar(&caminho).unwrap();
+    std::fs::remove_file(&caminho).unwrap();
+
+    assert!(salvo.contains("\tinvalida\t  abc \n"));
+    assert!(salvo.contains("\tinvalida\t\\t\\\\x\n"));
+    assert_eq!(carregada.primeira_divergencia(&gravacao), None);
+    assert_eq!(carregada.eventos[2].linha, Some(format!(" {secret}\r")));
+
+    let saida = saida::capturar(|| assert!(terminal::reproduzir(&carregada)));
+    assert!(saida.contains("A reprodução bateu com a gravação"));
+}
+
+#[test]
+fn reproducao_aponta_a_divergencia() {
+    let caminho = std::env::temp_dir()
+        .join(format!("resolution-divergente-{}.txt", std::process::id()));
+    // Chutar o próprio secreto dá "acertou", não o "maior" gravado
+    let secret = GuessingGame::with_seed(Config::default(), 3).secret();
+    let texto =
+        format!("seed\t3\nfaixa\t1\t100\ntentativas\t7\n0\tmaior\t{secret}\n");
+    std::fs::write(&caminho, texto).unwrap();
+    let gravacao = Gravacao::carregar(&caminho).unwrap();
+    std::fs::write(&caminho, "seed\t3\nfaixa\t1\t100\n9\tfim\tlinha\n")
+        .unwrap();
+    let invalida = Gravacao::carregar(&caminho);
+    std::fs::remove_file(&caminho).unwrap();
+
+    let saida = saida::capturar(|| assert!(!terminal::reproduzir(&gravacao)));
+    assert!(saida.contains(&format!(
+        "Divergência no evento 1: gravado '{secret}' → maior, \
+         reproduzido '{secret}' → acertou."
+    )));
+    assert!(invalida.unwrap_err().contains("linha inválida"));
+}
+
+#[test]
+fn comandos_do_protocolo_multijogador() {
+    assert_eq!("PRONTO".parse(), Ok(Comando::Pronto));
+    assert_eq!("chute 42".parse(), Ok(Comando::Chute(42)));
+    assert_eq!(" 7 ".parse(), Ok(Comando::Chute(7)));
+    assert_eq!(
+        "NOME Ana Maria".parse(),
+        Ok(Comando::Nome("Ana_Maria".into()))
+    );
+    assert!("CHUTE abc".parse::<Comando>().is_err());
+    assert!("NOME".parse::<Comando>().is_err());
+    assert!("dança".parse::<Comando>().is_err());
+}
+
+/// Um cliente de teste: manda linhas e espera mensagens do servidor
+struct Cliente {
+    conexao: TcpStream,
+    leitor: BufReader<TcpStream>,
+}
+
+impl Cliente {
+    fn conectar(porta: u16) -> Self {
+        let conexao = TcpStream::connect(("127.0.0.1", porta)).unwrap();
+        conexao
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let leitor = BufReader::new(conexao.try_clone().unwrap());
+        Self { conexao, leitor }
+    }
+
+    fn enviar(&mut self, linha: &str) {
+        writeln!(self.conexao, "{linha}").unwrap();
+    }
+
+    /// Lê até a primeira mensagem que começa com `prefixo`
+    fn esperar(&mut self, prefixo: &str) -> String {
+        loop {
+            let mut linha = String::new();
+            let lidos = self.leitor.read_line(&mut linha).unwrap();
+            assert!(lidos > 0, "conexão fechou antes de {prefixo}");
+            if linha.starts_with(prefixo) {
+                return linha.trim_end().to_string();
+            }
+        }
+    }
+}
+
+#[test]
+fn multijogador_transmite_dicas_e_anuncia_o_vencedor() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let porta = listener.local_addr().unwrap().port();
+    let config = Config::default();
+    let secret = GuessingGame::with_seed(config, 5).secret();
+
+    let servidor = thread::spawn(move || {
+        saida::capturar(|| {
+            multijogador::servir(listener, config, Some(5), Some(1)).unwrap()
+        })
+    });
+
+    let mut ana = Cliente::conectar(porta);
+    assert_eq!(ana.esperar("OLA"), "OLA jogador1");
+    let mut bia = Cliente::conectar(porta);
+    assert_eq!(bia.esperar("OLA"), "OLA jogador2");
+
+    ana.enviar("NOME Ana");
+    assert_eq!(bia.esperar("NOME"), "NOME jogador1 Ana");
+    // Nome repetido ganha sufixo
+    bia.enviar("NOME Ana");
+    assert_eq!(bia.esperar("NOME jogador2"), "NOME jogador2 Ana_2");
+    bia.enviar("NOME Bia");
+    ana.enviar("CHUTE 10");
+    assert!(ana.esperar("ERRO").contains("não começou"));
+
+    ana.enviar("PRONTO");
+    assert_eq!(bia.esperar("LOBBY 1"), "LOBBY 1/2");
+    bia.enviar("PRONTO");
+    assert_eq!(ana.esperar("RODADA"), "RODADA 1 1 100");
+    assert_eq!(bia.esperar("RODADA"), "RODADA 1 1 100");
+
+    // A dica de um jogador chega para os dois
+    let (chute, dica) = if secret > 1 {
+        (secret - 1, "MAIOR")
+    } else {
+        (secret + 1, "MENOR")
+    };
+    ana.enviar(&chute.to_string());
+    let esperada = format!("DICA Ana {chute} {dica}");
+    assert_eq!(ana.esperar("DICA"), esperada);
+    assert_eq!(bia.esperar("DICA"), esperada);
+
+    bia.enviar("CHUTE 1000");
+    assert!(bia.esperar("RECUSADO").contains("entre 1 e 100"));
+
+    bia.enviar(&format!("CHUTE {secret}"));
+    let vencedor = format!("VENCEDOR Bia {secret} 1");
+    assert_eq!(ana.esperar("VENCEDOR"), vencedor);
+    assert_eq!(bia.esperar("VENCEDOR"), vencedor);
+    assert_eq!(ana.esperar("FIM"), "FIM");
+
+    let log = servidor.join().unwrap();
+    assert!(log.contains("» NOME jogador1 Ana"));
+
+    // A thread que aceitava conexões terminou junto e soltou a porta
+    assert!(TcpStream::connect(("127.0.0.1", porta)).is_err());
+}
+
+/// Uma entrada que responde as linhas prontas e depois espera para sempre,
+/// como alguém que não aperta Enter
+struct Parada {
+    linhas: Vec<String>,
+    nunca: Receiver<String>,
+}
+
+impl Entrada for Parada {
+    fn ler_linha(&mut self) -> Option<String> {
+        if self.linhas.is_empty() {
+            return self.nunca.recv().ok();
+        }
+        Some(self.linhas.remove(0))
+    }
+
+    fn ecoar(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn cliente_multijogador_sai_no_fim_sem_esperar_a_entrada() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let porta = listener.local_addr().unwrap().port();
+    let config = Config::default();
+    let secret = GuessingGame::with_seed(config, 5).secret();
+
+    thread::spawn(move || {
+        saida::capturar(|| {
+            multijogador::servir(listener, config, Some(5), Some(1)).unwrap()
+        })
+    });
+
+    let mut ana = Cliente::conectar(porta);
+    ana.esperar("OLA");
+
+    let (mudo, nunca) = mpsc::channel();
+    let (terminou, cliente) = mpsc::channel();
+    let (depois, lida) = mpsc::channel();
+    thread::spawn(move || {
+        let entrada = Parada {
+            linhas: vec!["NOME Bia".into(), "PRONTO".into()],
+            nunca,
+        };
+        let mut conectou = false;
+        entrada::com_entrada(entrada, || {
+            saida::capturar(|| {
+                let endereco = format!("127.0.0.1:{porta}");
+                conectou = multijogador::conectar(&endereco).is_ok();
+            });
+            let _ = terminou.send(conectou);
+            let _ = depois.send(entrada::ler_linha());
+        });
+    });
+
+    ana.esperar("LOBBY 1/2");
+    ana.enviar("PRONTO");
+    ana.esperar("RODADA");
+    ana.enviar(&secret.to_string());
+    ana.esperar("FIM");
+
+    // A entrada da Bia continua parada, mas o cliente já voltou
+    let conectou = cliente.recv_timeout(Duration::from_secs(5));
+    assert_eq!(conectou, Ok(true));
+
+    // E quem chamou tem a entrada de volta: a linha que a thread de leitura
+    // esperava chega para ele
+    mudo.send("depois".to_string()).unwrap();
+    let linha = lida.recv_timeout(Duration::from_secs(5));
+    assert_eq!(linha, Ok(Some("depois".to_string())));
+}
+
+#[test]
+fn forca_conta_letras_acentuadas_como_uma_so() {
+    let palavras = forca::ler_palavras("# comentário\n\n  Maçã \n123\n");
+    assert_eq!(palavras, ["maçã"]);
+
+    let mut game = HangmanGame::with_seed(&palavras, 3, 0).unwrap();
+    assert_eq!(game.mask(), "_ _ _ _");
+    assert_eq!(game.word().len(), 6, "ç e ã ocupam 2 bytes cada");
+
+    // `a` revela o `a` e o `ã`; `c` revela o `ç`
+    assert_eq!(game.guess("a"), Ok(Outcome::Hit(2)));
+    assert_eq!(game.mask(), "_ a _ ã");
+    assert_eq!(game.guess("Ç"), Ok(Outcome::Hit(1)));
+    assert_eq!(game.guess("ã"), Err(HangmanError::AlreadyTried('a')));
+    assert_eq!(game.guess("?"), Err(HangmanError::NotALetter));
+    assert_eq!(game.guess("x"), Ok(Outcome::Miss));
+    assert_eq!(game.guess("m"), Ok(Outcome::Hit(1)));
+
+    assert!(game.is_won());
+    assert_eq!(game.mask(), "m a ç ã");
+    assert_eq!((game.attempts(), game.misses()), (4, 1));
+}
+
+#[test]
+fn forca_aceita_a_palavra_inteira_e_perde_nos_erros() {
+    let palavras = forca::ler_palavras("pé-de-moleque");
+    let mut game = HangmanGame::with_seed(&palavras, 2, 0).unwrap();
+    assert_eq!(game.mask(), "_ _ - _ _ - _ _ _ _ _ _ _");
+
+    assert_eq!(game.guess("pe de moleque"), Ok(Outcome::Miss));
+    assert_eq!(game.guess("PE-DE-MOLEQUE"), Ok(Outcome::Hit(11)));
+    assert!(game.is_won());
+
+    let mut game = HangmanGame::with_seed(&palavras, 2, 0).unwrap();
+    game.guess("z").unwrap();
+    game.guess("y").unwrap();
+    assert_eq!(game.state(), State::Lost);
+    assert_eq!(game.guess("p"), Err(HangmanError::GameOver));
+
+    assert!(HangmanGame::with_seed(&[], 6, 0).is_err());
+}
+
+#[test]
+fn forca_no_terminal() {
+    let palavras = forca::ler_palavras("café");
+    let mut game = HangmanGame::with_seed(&palavras, 6, 0).unwrap();
+
+    let saida =
+        entrada::com_entrada(Roteiro::new(["", "c", "c", "afe"]), || {
+            saida::capturar(|| terminal::jogar_forca(&mut game))
+        });
+
+    assert!(saida.contains("Digite uma letra."));
+    assert!(saida.contains("c _ _ _"));
+    assert!(saida.contains("Chute não contado: a letra 'c' já foi chutada."));
+    assert!(saida.contains("Não tem! Você ainda pode errar 5 vez(es)."));
+    assert!(
+        saida.contains("('café' tem 4 caractere(s) e 5 byte(s) em UTF-8.)")
+    );
+}
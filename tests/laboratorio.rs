@@ -0,0 +1,632 @@
+use std::{mem::size_of, num::FpCategory};
+
+use resolution::laboratorio::bases::{self, Base};
+use resolution::laboratorio::conversoes;
+use resolution::laboratorio::erros::{self, Diagnostico};
+use resolution::laboratorio::flutuante;
+use resolution::laboratorio::layout::{self, Endereco, Regiao};
+use resolution::laboratorio::overflow::{self, Operacao};
+use resolution::laboratorio::simulador;
+use resolution::laboratorio::tipos::{self, Categoria};
+
+#[test]
+fn tabela_de_tipos_vem_das_constantes() {
+    let tabela = tipos::tabela();
+    let tipo = |nome: &str| tabela.iter().find(|t| t.nome == nome).unwrap();
+
+    assert_eq!(tabela.len(), 16);
+
+    let i8 = tipo("i8");
+    assert_eq!((i8.min.as_str(), i8.max.as_str()), ("-128", "127"));
+    assert_eq!(i8.categoria, Categoria::InteiroComSinal);
+
+    let u128 = tipo("u128");
+    assert_eq!(u128.max, u128::MAX.to_string());
+    assert_eq!(
+        (u128.bits(), u128.categoria),
+        (128, Categoria::InteiroSemSinal)
+    );
+
+    assert_eq!(tipo("usize").tamanho, size_of::<usize>());
+    assert_eq!(tipo("char").max, "U+10FFFF");
+    assert_eq!(tipo("bool").tamanho, 1);
+}
+
+#[test]
+fn milhares_e_json() {
+    assert_eq!(tipos::separar_milhares("-2147483648"), "-2.147.483.648");
+    assert_eq!(tipos::separar_milhares("255"), "255");
+    assert_eq!(tipos::separar_milhares("1.5e10"), "1.5e10");
+
+    assert_eq!(tipos::texto_json("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+
+    let json = tipos::json(&tipos::tabela());
+    assert!(json.starts_with("[\n  {\"tipo\": \"i8\""));
+    assert!(json.contains(&format!("\"max\": \"{}\"", u128::MAX)));
+    assert_eq!(json.matches("\"tipo\"").count(), 16);
+}
+
+#[test]
+fn overflow_compara_os_metodos_e_os_perfis() {
+    let linhas = overflow::comparar("u8", "200", "100").unwrap();
+    let soma = &linhas[0];
+    assert_eq!(soma.operacao, Operacao::Soma);
+    assert_eq!(
+        [
+            &soma.checked,
+            &soma.wrapping,
+            &soma.saturating,
+            &soma.overflowing
+        ],
+        ["None", "44", "255", "(44, true)"]
+    );
+    assert_eq!(
+        (soma.debug.as_str(), soma.release.as_str()),
+        ("pânico", "44")
+    );
+    assert_eq!(soma.panico, Some("attempt to add with overflow"));
+    assert_eq!(linhas[1].checked, "Some(100)");
+    assert_eq!(linhas[4].saturating, "(não existe)");
+
+    // MIN / -1 entra em pânico até em release
+    let divisao = &overflow::comparar("i8", "-128", "-1").unwrap()[3];
+    assert_eq!(divisao.wrapping, "-128");
+    assert_eq!(divisao.release, "pânico");
+
+    let por_zero = &overflow::comparar("i32", "5", "0").unwrap()[4];
+    assert_eq!(
+        por_zero.panico,
+        Some("attempt to calculate the remainder with a divisor of zero")
+    );
+    assert_eq!(por_zero.wrapping, "pânico");
+
+    assert!(
+        overflow::comparar("u8", "256", "1")
+            .unwrap_err()
+            .contains("não cabe em u8")
+    );
+    assert!(overflow::comparar("f32", "1", "1").is_err());
+}
+
+#[test]
+fn matriz_de_conversoes_vem_do_compilador() {
+    let matriz = conversoes::matriz();
+    assert_eq!(matriz.len(), 256);
+
+    let par = |o: &str, d: &str| conversoes::par(o, d).unwrap();
+    let u8_u16 = par("u8", "u16");
+    assert!(u8_u16.from && u8_u16.try_from && u8_u16.cast);
+    let i32_u8 = par("i32", "u8");
+    assert!(!i32_u8.from && i32_u8.try_from && i32_u8.cast);
+    let f64_bool = par("f64", "bool");
+    assert!(!f64_bool.from && !f64_bool.try_from && !f64_bool.cast);
+    assert!(par("u8", "char").from);
+    assert!(!par("u16", "char").cast);
+    assert!(conversoes::par("i32", "string").is_err());
+
+    let conversao = i32_u8.converter("300").unwrap();
+    assert_eq!(conversao.cast.as_deref(), Some("44"));
+    assert_eq!(conversao.from, None);
+    assert!(matches!(conversao.try_from, Some(Err(_))));
+    assert!(
+        conversoes::observacao(&conversao)
+            .unwrap()
+            .contains("8 bits menores")
+    );
+
+    let satura = par("f64", "u8").converter("-1.5").unwrap();
+    assert_eq!(satura.cast.as_deref(), Some("0"));
+    assert!(conversoes::observacao(&satura).unwrap().contains("satura"));
+
+    let exato = par("u8", "i32").converter("200").unwrap();
+    assert_eq!(exato.from.as_deref(), Some("200"));
+    assert_eq!(conversoes::observacao(&exato), None);
+
+    assert!(i32_u8.converter("abc").is_err());
+}
+
+#[test]
+fn flutuante_mostra_os_bits_e_os_vizinhos() {
+    let um_decimo = flutuante::inspecionar(0.1_f64);
+    assert_eq!(
+        um_decimo.bits,
+        "0 01111111011 \
+         1001100110011001100110011001100110011001100110011010"
+    );
+    assert_eq!(um_decimo.expoente, Some(-4));
+    assert_eq!(
+        um_decimo.exato,
+        "0.1000000000000000055511151231257827021181583404541015625"
+    );
+    assert_eq!(um_decimo.anterior.as_deref(), Some("0.09999999999999999"));
+    assert_eq!(um_decimo.proximo.as_deref(), Some("0.10000000000000002"));
+
+    let um = flutuante::inspecionar(1.0_f32);
+    assert_eq!(um.bits, "0 01111111 00000000000000000000000");
+    assert_eq!(um.ulp, Some(f64::from(f32::EPSILON)));
+    assert_eq!((um.bits_de_precisao, um.digitos), (24, 6));
+
+    let menor = flutuante::inspecionar_texto("0x1", "f64").unwrap();
+    assert_eq!(menor.categoria, FpCategory::Subnormal);
+    assert_eq!(menor.anterior.as_deref(), Some("0.0"));
+
+    // Os subnormais têm centenas de zeros na frente: a expansão começa no
+    // primeiro dígito que importa
+    assert_eq!(
+        flutuante::exato(5e-324),
+        "4.940656458412465441765687928682213723650…e-324 \
+         (751 dígitos significativos)"
+    );
+    assert_eq!(menor.exato, flutuante::exato(5e-324));
+    assert_eq!(
+        flutuante::exato(-f64::MIN_POSITIVE),
+        "-2.225073858507201383090232717332404064219…e-308 \
+         (715 dígitos significativos)"
+    );
+    assert!(
+        flutuante::exato(1e300).ends_with("e300 (301 dígitos significativos)")
+    );
+
+    let nan = flutuante::inspecionar_texto("NaN", "f32").unwrap();
+    assert_eq!(nan.categoria, FpCategory::Nan);
+    assert_eq!((nan.proximo, nan.ulp), (None, None));
+    let infinito = flutuante::inspecionar_texto("-inf", "f64").unwrap();
+    assert!(infinito.negativo && infinito.expoente.is_none());
+
+    assert_eq!(flutuante::distancia_ulps(0.1 + 0.2, 0.3), Some(1));
+    assert_eq!(flutuante::distancia_ulps(-0.0_f32, 0.0), Some(0));
+    assert_eq!(flutuante::distancia_ulps(f64::NAN, 1.0), None);
+
+    assert!(flutuante::inspecionar_texto("abc", "f64").is_err());
+    assert!(flutuante::inspecionar_texto("0x1ffffffff", "f32").is_err());
+    assert!(flutuante::inspecionar_texto("1", "f16").is_err());
+
+    let surpresas = flutuante::surpresas();
+    assert_eq!(surpresas[0].resultado, "0.30000000000000004");
+    assert_eq!(surpresas[1].resultado, "false");
+}
+
+#[test]
+fn bases_leem_literais_e_mostram_o_complemento_de_dois() {
+    let sufixo = bases::ler("57u8").unwrap();
+    assert_eq!((sufixo.magnitude, sufixo.sufixo), (57, Some("u8")));
+    assert_eq!(bases::ler("0xFF_FF").unwrap().magnitude, 0xffff);
+    assert_eq!(bases::ler("0o17").unwrap().base, Base::Octal);
+    assert_eq!(bases::ler("0b1010_i16").unwrap().sufixo, Some("i16"));
+    assert_eq!(bases::ler("b'A'").unwrap().magnitude, 65);
+    assert_eq!(bases::ler("b'\\x7f'").unwrap().magnitude, 0x7f);
+
+    assert!(bases::ler("256u8").unwrap_err().contains("fora da faixa"));
+    assert!(bases::ler("-1u8").is_err());
+    assert!(bases::ler("0b102").unwrap_err().contains("'2'"));
+    assert!(bases::ler("12f32").is_err());
+
+    let menos_um = bases::converter("-1i8", None).unwrap();
+    assert_eq!(menos_um.padrao, "1111_1111");
+    assert_eq!(
+        (menos_um.sem_sinal.as_str(), menos_um.com_sinal.as_str()),
+        ("255", "-1")
+    );
+    assert_eq!(menos_um.byte, None);
+
+    let doze = bases::converter("-6", Some(12)).unwrap();
+    assert_eq!(doze.padrao, "1111_1111_1010");
+    assert_eq!(doze.sem_sinal, "4090");
+
+    let grande = bases::converter("1_000_000", None).unwrap();
+    assert_eq!(grande.tipo, Some("i32"));
+    assert_eq!(grande.hexadecimal, "0xf_4240");
+    assert_eq!(grande.decimal, "1_000_000");
+    assert_eq!(
+        bases::converter("0xff", None).unwrap().byte.as_deref(),
+        Some("b'\\xff'")
+    );
+    assert!(
+        bases::converter("u64::MAX", None)
+            .unwrap_err()
+            .contains("sufixo desconhecido")
+    );
+    assert!(bases::converter("300", Some(8)).is_err());
+
+    // Nos extremos: o negativo não vira u128, o positivo não cabe em nada
+    let minimo =
+        bases::converter("-170141183460469231731687303715884105728", None)
+            .unwrap();
+    assert_eq!(
+        (minimo.tipo, minimo.com_sinal.as_str()),
+        (Some("i128"), "-170141183460469231731687303715884105728")
+    );
+    assert!(
+        bases::converter("-170141183460469231731687303715884105729", None)
+            .unwrap_err()
+            .contains("não cabe em i128")
+    );
+    let maximo =
+        bases::converter("340282366920938463463374607431768211455", None)
+            .unwrap();
+    assert_eq!(maximo.tipo, Some("u128"));
+    assert!(
+        bases::converter("340282366920938463463374607431768211456", None)
+            .is_err()
+    );
+}
+
+#[test]
+fn layout_mede_os_tipos_do_projeto() {
+    let user = layout::buscar("User").unwrap();
+    assert_eq!(
+        user.tamanho,
+        user.campos.iter().map(|c| c.tamanho).sum::<usize>() + 7
+    );
+    let campo =
+        |nome: &str| user.campos.iter().find(|c| c.nome == nome).unwrap();
+    assert_eq!(campo("username").tipo, "String");
+    assert_eq!(campo("sign_in_count").tamanho, 8);
+    // Os campos não se sobrepõem e cabem no tipo
+    let mut fim = 0;
+    for campo in &user.campos {
+        assert!(campo.offset >= fim);
+        fim = campo.fim();
+    }
+    assert!(fim <= user.tamanho);
+
+    let rectangle = layout::buscar("rectangle").unwrap();
+    assert_eq!((rectangle.tamanho, rectangle.alinhamento), (8, 4));
+    assert_eq!(layout::faixa(&rectangle, &rectangle.campos), "[wi][he]");
+
+    let message = layout::buscar("Message").unwrap();
+    let nomes: Vec<&str> = message.variantes.iter().map(|v| v.nome).collect();
+    assert_eq!(nomes, ["Quit", "Move", "Write", "ChangeColor"]);
+    let mover = &message.variantes[1];
+    assert_eq!(mover.campos[1].offset - mover.campos[0].offset, 4);
+    assert!(mover.campos.iter().all(|c| c.fim() <= message.tamanho));
+
+    let opcional = layout::buscar("Option<User>").unwrap();
+    assert_eq!(opcional.variantes[1].campos[0].tipo, "User");
+    assert!(layout::buscar("Vec").is_err());
+}
+
+#[test]
+fn ponteiros_caem_na_regiao_certa() {
+    let local = 42_u64;
+    let texto = String::from("na heap");
+    // Grande o bastante para o malloc pedir um `mmap` só para ele
+    let grande = vec![0_u8; 8 << 20];
+    let literal = "na memória estática";
+
+    assert_eq!(layout::classificar(&local), Regiao::Stack);
+    // A thread do teste não é a principal: o `String` cai numa arena
+    // própria do malloc, fora do `[heap]`
+    assert_eq!(layout::classificar(texto.as_ptr()), Regiao::Heap);
+    assert_eq!(layout::classificar(grande.as_ptr()), Regiao::Heap);
+    assert_eq!(layout::classificar(literal.as_ptr()), Regiao::Estatica);
+
+    let diagrama = layout::diagrama(&[
+        Endereco::new("local", &local),
+        Endereco::new("literal", literal.as_ptr()),
+    ]);
+    let posicao =
+        |texto: &str| diagrama.iter().position(|l| l.contains(texto)).unwrap();
+    assert!(posicao("STACK") < posicao("local"));
+    assert!(posicao("local") < posicao("HEAP"));
+    assert!(posicao("ESTÁTICA") < posicao("literal"));
+}
+
+#[test]
+fn layout_where_classifica_os_enderecos_pedidos() {
+    assert_eq!(layout::ler_endereco("0x1f"), Ok(0x1f));
+    assert_eq!(layout::ler_endereco("1F"), Ok(0x1f));
+    assert!(layout::ler_endereco("0xzz").is_err());
+
+    let texto = String::from("na heap");
+    let lista = layout::onde(&[texto.as_ptr() as usize]);
+    let regioes: Vec<Regiao> = lista.iter().map(|e| e.regiao).collect();
+    assert_eq!(
+        regioes,
+        [Regiao::Stack, Regiao::Heap, Regiao::Estatica, Regiao::Heap]
+    );
+    assert_eq!(lista[3].nome, "pedido");
+}
+
+#[test]
+fn regioes_pelos_trechos_do_proc_maps() {
+    let mapas = "\
+5000-6000 r-xp 00000000 08:01 42 /usr/bin/resolution
+6000-7000 rw-p 00000000 00:00 0 [heap]
+7000-8000 rw-p 00000000 00:00 0
+8000-9000 r-xp 00000000 08:01 7 /usr/lib/libc.so.6
+9000-a000 rw-p 00000000 00:00 0 [stack]
+b000-c000 ---p 00000000 00:00 0
+c000-d000 rw-p 00000000 00:00 0
+";
+    // Pilha em 0x9800, `Box` de marco em 0xc800, literal em 0x5100
+    let onde =
+        |endereco| layout::pelos_mapas(mapas, endereco, 0x9800, 0xc800, 0x5100);
+
+    assert_eq!(onde(0x5800), (Regiao::Estatica, "/usr/bin/resolution"));
+    assert_eq!(onde(0x6800), (Regiao::Heap, "[heap]"));
+    // Anônimo de leitura e escrita: arena de outra thread ou bloco grande
+    assert_eq!(onde(0x7800), (Regiao::Heap, ""));
+    assert_eq!(onde(0xc100), (Regiao::Heap, ""));
+    assert_eq!(onde(0x8800), (Regiao::Outro, "/usr/lib/libc.so.6"));
+    assert_eq!(onde(0x9100), (Regiao::Stack, "[stack]"));
+    // Página de guarda: anônima, mas sem permissão nenhuma
+    assert_eq!(onde(0xb800), (Regiao::Outro, ""));
+    assert_eq!(onde(0xf000), (Regiao::Outro, ""));
+
+    let diagrama = layout::diagrama(&[Endereco {
+        nome: "na libc".to_string(),
+        endereco: 0x8800,
+        regiao: Regiao::Outro,
+        mapeamento: "/usr/lib/libc.so.6".to_string(),
+    }]);
+    let texto = diagrama.join("\n");
+    assert!(texto.contains("OUTRO MAPEAMENTO"));
+    assert!(texto.contains("↳ /usr/lib/libc.so.6"));
+}
+
+#[test]
+fn erros_explicam_codigos_e_leem_o_json_do_rustc() {
+    assert_eq!(erros::normalizar("e502").unwrap(), "E0502");
+    assert!(erros::normalizar("E05x2").is_err());
+
+    let e0502 = erros::buscar("0502").unwrap();
+    assert_eq!(e0502.doc(), Some("docs/06-referencias-e-borrowing.md"));
+    assert!(
+        erros::buscar("E0001")
+            .unwrap_err()
+            .contains("rustc --explain")
+    );
+
+    // Todo exemplo do catálogo aponta para um capítulo registrado
+    for explicacao in erros::CATALOGO {
+        assert!(explicacao.doc().is_some(), "{}", explicacao.exemplo);
+    }
+
+    // Uma linha do cargo, uma do rustc direto, um aviso e uma que não é JSON
+    let saida = r#"{"reason":"compiler-artifact","target":{}}
+{"reason":"compiler-message","message":{"$message_type":"diagnostic","message":"cannot borrow `s` as mutable","code":{"code":"E0502","explanation":"A variable already borrowed\u0021"},"level":"error","spans":[{"file_name":"src/main.rs","line_start":9,"column_start":14,"is_primary":true}],"children":[],"rendered":"error[E0502]"}}
+{"$message_type":"diagnostic","message":"uso de \"s1\" \ud83d\ude3b","code":{"code":"E0382"},"level":"error","spans":[],"children":[]}
+{"$message_type":"diagnostic","message":"unused variable","code":null,"level":"warning","spans":[]}
+error: could not compile `quebrado`"#;
+
+    assert_eq!(
+        erros::diagnosticos(saida),
+        [
+            Diagnostico {
+                nivel: "error".into(),
+                codigo: Some("E0502".into()),
+                mensagem: "cannot borrow `s` as mutable".into(),
+                local: Some("src/main.rs:9:14".into()),
+            },
+            Diagnostico {
+                nivel: "error".into(),
+                codigo: Some("E0382".into()),
+                mensagem: "uso de \"s1\" 😻".into(),
+                local: None,
+            },
+            Diagnostico {
+                nivel: "warning".into(),
+                codigo: None,
+                mensagem: "unused variable".into(),
+                local: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn simulador_acompanha_donos_e_emprestimos() {
+    let codigos = |fonte: &str| -> Vec<&str> {
+        let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+        simulacao.erros.iter().map(|e| e.codigo).collect()
+    };
+
+    // Os exemplos dos capítulos dão o mesmo erro que o rustc
+    let esperados = [
+        ("move", vec!["E0382"]),
+        ("funcao", vec!["E0382"]),
+        ("clone", vec![]),
+        ("mutaveis", vec!["E0499"]),
+        ("mistura", vec!["E0502"]),
+        ("emprestado", vec!["E0505"]),
+        ("nll", vec![]),
+        ("pendurada", vec!["E0106"]),
+        ("escopo", vec!["E0597"]),
+    ];
+    assert_eq!(simulador::EXEMPLOS.len(), esperados.len());
+    for (nome, codigo) in esperados {
+        let fonte = simulador::exemplo(nome).unwrap();
+        assert_eq!(codigos(fonte), codigo, "{nome}");
+    }
+    assert!(simulador::exemplo("nenhum").is_err());
+
+    let fonte = "\
+let k = 5;
+k = 6;
+let v = String::from(\"a\");
+v.push_str(\"b\");
+let mut s = String::from(\"x\");
+let r = &s;
+let t = s;
+println!(\"{}\", r);
+let u;
+println!(\"{}\", u);
+";
+    assert_eq!(codigos(fonte), ["E0384", "E0596", "E0505", "E0381"]);
+
+    // A linha do tempo: quem é dono, o move, e onde o empréstimo acaba
+    let fonte = simulador::exemplo("nll").unwrap();
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    let eventos = |linha: usize| -> Vec<&str> {
+        simulacao
+            .passos
+            .iter()
+            .filter(|p| p.linha == linha)
+            .flat_map(|p| p.eventos.iter().map(String::as_str))
+            .collect()
+    };
+    assert_eq!(eventos(2), ["s é dono de \"olá\""]);
+    assert_eq!(eventos(5), ["último uso de r1", "último uso de r2"]);
+    assert_eq!(eventos(7), ["s agora vale \"olá!!!\""]);
+    assert_eq!(eventos(9), ["drop s (\"olá!!!\")"]);
+
+    // Erros de sintaxe vêm com a linha
+    let erro = simulador::ler("let x = ;").unwrap_err();
+    assert!(erro.starts_with("linha 1:"), "{erro}");
+    assert!(simulador::ler("fn f() {\n let a = 1\n}").is_err());
+}
+
+/// Os códigos dos erros que o simulador acha em `fonte`
+fn codigos_simulados(fonte: &str) -> Vec<&'static str> {
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    simulacao.erros.iter().map(|e| e.codigo).collect()
+}
+
+#[test]
+fn simulador_separa_e0106_de_e0515() {
+    // Sem parâmetro referência, o rustc para na assinatura: de quem empresta?
+    let sem_parametro = "\
+fn dangle() -> &String {
+    let s = String::from(\"hello\");
+    &s
+}
+let r = dangle();
+";
+    assert_eq!(codigos_simulados(sem_parametro), ["E0106"]);
+
+    // Com um, a assinatura vale e o erro é devolver a variável local
+    let com_parametro = "\
+fn maior(a: &String) -> &String {
+    let s = String::from(\"local\");
+    &s
+}
+let x = String::from(\"x\");
+let r = maior(&x);
+println!(\"{}\", r);
+";
+    assert_eq!(codigos_simulados(com_parametro), ["E0515"]);
+
+    // Devolver o próprio parâmetro está certo
+    let devolve_parametro = "\
+fn mesmo(a: &String) -> &String {
+    a
+}
+let x = String::from(\"x\");
+let r = mesmo(&x);
+println!(\"{}\", r);
+";
+    assert!(codigos_simulados(devolve_parametro).is_empty());
+}
+
+#[test]
+fn simulador_passa_mut_pela_chamada() {
+    let fonte = "\
+fn muda(s: &mut String) {
+    s.push_str(\"!\");
+}
+let mut s = String::from(\"oi\");
+muda(&mut s);
+println!(\"{}\", s);
+";
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    assert!(simulacao.erros.is_empty(), "{:?}", simulacao.erros);
+    let dentro: Vec<_> =
+        simulacao.passos.iter().filter(|p| p.linha == 2).collect();
+    assert_eq!(dentro.len(), 1);
+    assert_eq!(dentro[0].profundidade, 1);
+    assert_eq!(
+        dentro[0].eventos,
+        ["s agora vale \"oi!\"", "último uso de s em muda"]
+    );
+
+    // Sem `mut` na variável, não dá para emprestar como &mut
+    let sem_mut = fonte.replace("let mut s", "let s");
+    assert_eq!(codigos_simulados(&sem_mut), ["E0596"]);
+
+    // Com `&` no lugar de `&mut`, a função não pode mudar o valor
+    let so_leitura = "\
+fn muda(s: &String) {
+    s.push_str(\"!\");
+}
+let s = String::from(\"oi\");
+muda(&s);
+";
+    assert_eq!(codigos_simulados(so_leitura), ["E0596"]);
+}
+
+#[test]
+fn simulador_fecha_blocos_aninhados_de_dentro_para_fora() {
+    let fonte = "\
+let a = String::from(\"fora\");
+{
+    let b = String::from(\"meio\");
+    {
+        let c = String::from(\"dentro\");
+    }
+    println!(\"{}\", b);
+}
+println!(\"{}\", a);
+";
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    assert!(simulacao.erros.is_empty(), "{:?}", simulacao.erros);
+
+    let drops: Vec<(usize, &str)> = simulacao
+        .passos
+        .iter()
+        .flat_map(|p| {
+            p.eventos
+                .iter()
+                .filter(|e| e.starts_with("drop"))
+                .map(move |e| (p.linha, e.as_str()))
+        })
+        .collect();
+    assert_eq!(
+        drops,
+        [
+            (6, "drop c (\"dentro\")"),
+            (8, "drop b (\"meio\")"),
+            (10, "drop a (\"fora\")"),
+        ]
+    );
+
+    // Fora do bloco, a variável de dentro não existe mais
+    let fora = "{\n    let x = 1;\n}\nprintln!(\"{}\", x);\n";
+    assert_eq!(codigos_simulados(fora), ["E0425"]);
+}
+
+#[test]
+fn simulador_para_na_recursao_sem_inventar_erro() {
+    let fonte = "\
+fn f(s: &String) {
+    f(s);
+}
+let s = String::from(\"x\");
+f(&s);
+";
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    assert!(simulacao.erros.is_empty(), "{:?}", simulacao.erros);
+    let motivo = simulacao.interrompida.expect("esperava parar na recursão");
+    assert!(motivo.starts_with("linha 2:"), "{motivo}");
+    assert!(motivo.contains(" f"), "{motivo}");
+
+    // Sem recursão, nada é interrompido
+    let fonte = simulador::exemplo("funcao").unwrap();
+    let simulacao = simulador::simular(&simulador::ler(fonte).unwrap());
+    assert_eq!(simulacao.interrompida, None);
+}
+
+#[test]
+fn simulador_aponta_a_linha_dos_erros_de_sintaxe() {
+    let casos = [
+        ("let x = ;", "linha 1:"),
+        ("let a = 1;\nlet b = 2\nlet c = 3;", "linha 3:"),
+        ("let a = 1;\n\nfn f( {\n}", "linha 3:"),
+        ("fn f() {\n    let a = 1\n}", "linha 3:"),
+        ("let s = String::from(\"sem fim);", "linha 1:"),
+    ];
+    for (fonte, linha) in casos {
+        let erro = simulador::ler(fonte).unwrap_err();
+        assert!(erro.starts_with(linha), "{fonte:?}: {erro}");
+    }
+}
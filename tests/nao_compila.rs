@@ -0,0 +1,249 @@
+// ============================================================================
+// NÃO COMPILA: os exemplos "❌ ERRO" dos capítulos, conferidos pelo rustc
+// ============================================================================
+//
+// O código quebrado dos capítulos vive em comentários, e ninguém confere se
+// ele continua quebrado (nem se quebra pelo motivo que o comentário diz).
+// Cada um virou um programa em `tests/nao_compila/*.rs`, com a origem e o
+// código de erro esperado no cabeçalho:
+//
+//   // Origem: ownership::referencias_e_borrowing::referencias_mutaveis_...
+//   // Esperado: E0499 (duas referências mutáveis ao mesmo tempo)
+//
+// No capítulo, o trecho quebrado aponta para o seu caso numa linha só:
+//
+//   // error[E0499]: tests/nao_compila/borrowing_duas_mutaveis.rs
+//
+// Este teste compila cada arquivo com o `rustc` local e exige que a
+// compilação falhe com todos os códigos da linha `Esperado:`. A explicação
+// de cada código: `resolution explain E0499` (o catálogo de
+// `laboratorio::erros`, que também é conferido aqui).
+//
+// ============================================================================
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use resolution::laboratorio::erros;
+
+/// Os códigos `E0000` de um texto, na ordem em que aparecem
+fn codigos(texto: &str) -> Vec<String> {
+    texto
+        .match_indices('E')
+        .map(|(i, _)| &texto[i..])
+        .filter(|resto| {
+            resto.len() >= 5 && resto[1..5].bytes().all(|b| b.is_ascii_digit())
+        })
+        .map(|resto| resto[..5].to_string())
+        .collect()
+}
+
+/// Os códigos da linha `// Esperado:` do caso
+fn esperados(fonte: &str) -> Vec<String> {
+    fonte
+        .lines()
+        .find_map(|linha| linha.strip_prefix("// Esperado:"))
+        .map(|linha| codigos(linha.split('(').next().unwrap_or(linha)))
+        .unwrap_or_default()
+}
+
+fn rustc(caso: &Path, opcoes: &[&str]) -> Output {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let saida_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("nao_compila")
+        .join(caso.file_stem().expect("caso sem nome"));
+
+    Command::new(rustc)
+        .args(["--edition", "2024", "--crate-type", "bin"])
+        .args(["--emit", "metadata", "-A", "warnings", "--out-dir"])
+        .arg(&saida_dir)
+        .args(opcoes)
+        .arg(caso)
+        .output()
+        .expect("falha ao rodar o rustc")
+}
+
+/// Compila o caso e devolve (compilou?, códigos `error[E....]` do stderr)
+fn compilar(caso: &Path) -> (bool, Vec<String>) {
+    let saida = rustc(caso, &[]);
+    let stderr = String::from_utf8_lossy(&saida.stderr);
+    let erros = stderr
+        .lines()
+        .filter_map(|linha| linha.strip_prefix("error["))
+        .flat_map(codigos)
+        .collect();
+
+    (saida.status.success(), erros)
+}
+
+fn casos() -> Vec<PathBuf> {
+    let dir =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/nao_compila");
+    let mut casos: Vec<_> = fs::read_dir(&dir)
+        .expect("falta tests/nao_compila")
+        .map(|entrada| entrada.expect("entrada ilegível").path())
+        .filter(|caminho| caminho.extension().is_some_and(|e| e == "rs"))
+        .collect();
+    casos.sort();
+    casos
+}
+
+#[test]
+fn exemplos_quebrados_continuam_quebrados() {
+    let casos = casos();
+    assert!(!casos.is_empty(), "nenhum caso em tests/nao_compila");
+
+    let mut divergentes = Vec::new();
+    for caso in &casos {
+        let nome = caso.file_name().unwrap_or_default().to_string_lossy();
+        let fonte = fs::read_to_string(caso).expect("caso ilegível");
+        let esperados = esperados(&fonte);
+        if esperados.is_empty() {
+            divergentes.push(format!("{nome}: falta a linha `// Esperado:`"));
+            continue;
+        }
+
+        let (compilou, obtidos) = compilar(caso);
+        let faltando: Vec<_> =
+            esperados.iter().filter(|e| !obtidos.contains(e)).collect();
+
+        if compilou {
+            divergentes.push(format!("{nome}: compilou sem erro"));
+        } else if !faltando.is_empty() {
+            divergentes.push(format!(
+                "{nome}: esperava {faltando:?}, o rustc deu {obtidos:?}"
+            ));
+        }
+    }
+
+    assert!(
+        divergentes.is_empty(),
+        "exemplos que não falham como o comentário diz:\n{}",
+        divergentes.join("\n")
+    );
+}
+
+#[test]
+fn codigos_saem_do_cabecalho_e_do_stderr() {
+    assert_eq!(
+        esperados("// Origem: x\n// Esperado: E0277 (a E0308 é do texto)\n"),
+        ["E0277"]
+    );
+    assert_eq!(codigos("E0499]: cannot borrow"), ["E0499"]);
+    assert!(codigos("Esperado").is_empty());
+}
+
+#[test]
+fn catalogo_de_erros_bate_com_os_casos() {
+    let dir =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/nao_compila");
+    let correcoes = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("nao_compila")
+        .join("correcoes");
+    fs::create_dir_all(&correcoes).expect("falha ao criar o diretório");
+
+    for explicacao in erros::CATALOGO {
+        let codigo = explicacao.codigo;
+
+        // O caso existe e espera o mesmo código
+        let caso = dir.join(explicacao.caso);
+        let fonte = fs::read_to_string(&caso)
+            .unwrap_or_else(|_| panic!("{codigo}: falta {}", caso.display()));
+        assert!(esperados(&fonte).iter().any(|e| e == codigo), "{codigo}");
+
+        // O JSON do rustc para o caso passa pelo parser e dá o mesmo código
+        let saida = rustc(&caso, &["--error-format=json"]);
+        let diagnosticos =
+            erros::diagnosticos(&String::from_utf8_lossy(&saida.stderr));
+        assert!(
+            diagnosticos
+                .iter()
+                .any(|d| d.codigo.as_deref() == Some(codigo)),
+            "{codigo}: {diagnosticos:?}"
+        );
+
+        // E a versão corrigida compila
+        let corrigido = correcoes.join(format!("{codigo}.rs"));
+        fs::write(&corrigido, explicacao.correcao).expect("falha ao gravar");
+        let saida = rustc(&corrigido, &[]);
+        assert!(
+            saida.status.success(),
+            "a correção de {codigo} não compila:\n{}",
+            String::from_utf8_lossy(&saida.stderr)
+        );
+    }
+}
+
+/// Os arquivos `.rs` de `dir`, incluindo os das subpastas
+fn fontes(dir: &Path) -> Vec<PathBuf> {
+    let mut arquivos = Vec::new();
+    for entrada in fs::read_dir(dir).expect("diretório ilegível") {
+        let caminho = entrada.expect("entrada ilegível").path();
+        if caminho.is_dir() {
+            arquivos.extend(fontes(&caminho));
+        } else if caminho.extension().is_some_and(|e| e == "rs") {
+            arquivos.push(caminho);
+        }
+    }
+    arquivos
+}
+
+/// As anotações `// error[E0499]: tests/nao_compila/<caso>.rs` do código:
+/// (onde está, código, caso)
+fn anotacoes(fonte: &str) -> Vec<(usize, String, String)> {
+    fonte
+        .lines()
+        .enumerate()
+        .filter_map(|(i, linha)| {
+            let resto = linha.trim_start().strip_prefix("// error[")?;
+            let (codigo, caso) = resto.split_once("]: tests/nao_compila/")?;
+            Some((i + 1, codigo.to_string(), caso.trim().to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn trechos_dos_capitulos_apontam_para_os_casos() {
+    let raiz = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let dir = raiz.join("tests/nao_compila");
+
+    let mut divergentes = Vec::new();
+    let mut apontados = Vec::new();
+    for arquivo in fontes(&raiz.join("src")) {
+        let fonte = fs::read_to_string(&arquivo).expect("fonte ilegível");
+        let arquivo = arquivo.strip_prefix(&raiz).unwrap_or(&arquivo);
+        for (linha, codigo, caso) in anotacoes(&fonte) {
+            let onde = format!("{}:{linha}", arquivo.display());
+            match fs::read_to_string(dir.join(&caso)) {
+                Ok(fonte) if esperados(&fonte).contains(&codigo) => {}
+                Ok(_) => divergentes
+                    .push(format!("{onde}: {caso} não espera {codigo}")),
+                Err(_) => divergentes.push(format!("{onde}: falta {caso}")),
+            }
+            apontados.push(caso);
+        }
+    }
+
+    // Todo caso que veio de um capítulo (e não só da documentação) tem a
+    // anotação no trecho de origem
+    for caso in casos() {
+        let fonte = fs::read_to_string(&caso).expect("caso ilegível");
+        let nome = caso.file_name().unwrap_or_default().to_string_lossy();
+        let do_capitulo = fonte
+            .lines()
+            .find_map(|linha| linha.strip_prefix("// Origem: "))
+            .is_some_and(|origem| !origem.starts_with("docs/"));
+        if do_capitulo && !apontados.iter().any(|a| *a == nome) {
+            divergentes.push(format!("{nome}: nenhum trecho aponta para ele"));
+        }
+    }
+
+    assert!(
+        divergentes.is_empty(),
+        "anotações `// error[E....]: tests/nao_compila/...` divergentes:\n{}",
+        divergentes.join("\n")
+    );
+}
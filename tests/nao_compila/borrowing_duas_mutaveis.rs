@@ -0,0 +1,11 @@
+// Origem: ownership::referencias_e_borrowing::referencias_mutaveis_simultaneas
+// Esperado: E0499 (duas referências mutáveis ao mesmo tempo)
+
+fn main() {
+    let mut s = String::from("olá");
+
+    let r1 = &mut s;
+    let r2 = &mut s;
+
+    println!("{}, {}", r1, r2);
+}
@@ -0,0 +1,11 @@
+// Origem: ownership::referencias_e_borrowing::tentativa_modificacao_imutavel
+// Esperado: E0596 (não dá para pegar `&mut` através de um `&`)
+
+fn main() {
+    let s = String::from("hello");
+    change(&s);
+}
+
+fn change(s: &String) {
+    s.push_str(", world");
+}
@@ -0,0 +1,11 @@
+// Origem: ownership::referencias_e_borrowing::mover_enquanto_emprestado
+// Esperado: E0505 (move de `s` enquanto `r` ainda empresta)
+
+fn main() {
+    let s = String::from("olá");
+
+    let r = &s;
+    let t = s;
+
+    println!("{}, {}", r, t);
+}
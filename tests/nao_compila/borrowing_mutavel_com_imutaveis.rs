@@ -0,0 +1,12 @@
+// Origem: ownership::referencias_e_borrowing::mistura_referencias
+// Esperado: E0502 (referência mutável com imutáveis ainda em uso)
+
+fn main() {
+    let mut s = String::from("olá");
+
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &mut s;
+
+    println!("{}, {}, {}", r1, r2, r3);
+}
@@ -0,0 +1,12 @@
+// Origem: ownership::referencias_e_borrowing::referencia_pendurada
+// Esperado: E0106 (falta o lifetime: não há de quem emprestar)
+
+fn main() {
+    let r = dangle();
+    println!("{r}");
+}
+
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
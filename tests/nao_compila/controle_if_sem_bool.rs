@@ -0,0 +1,9 @@
+// Origem: conceitos_comuns::controle_de_fluxo (`if number` sem comparação)
+// Esperado: E0308 (a condição do `if` precisa ser `bool`)
+
+fn main() {
+    let number = 3;
+    if number {
+        println!("Isso dá erro: `number` precisa ser bool!");
+    }
+}
@@ -0,0 +1,7 @@
+// Origem: conceitos_comuns::conversoes::from_sem_perda e docs/13-conversoes.md
+// Esperado: E0277 (`u8: From<i32>` não existe: nem todo i32 cabe em u8)
+
+fn main() {
+    let n = u8::from(300_i32);
+    println!("{n}");
+}
@@ -0,0 +1,9 @@
+// Origem: docs/13-conversoes.md (somar u8 com i32)
+// Esperado: E0308
+
+fn main() {
+    let a: u8 = 10;
+    let b: i32 = 20;
+    let c = a + b;
+    println!("{c}");
+}
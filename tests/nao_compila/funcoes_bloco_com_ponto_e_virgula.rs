@@ -0,0 +1,10 @@
+// Origem: conceitos_comuns::funcoes (bloco terminado em `;` vira `()`)
+// Esperado: E0308
+
+fn main() {
+    let resultado: i32 = {
+        let x = 2;
+        x * 3;
+    };
+    println!("{resultado}");
+}
@@ -0,0 +1,28 @@
+// Origem: structs::metodos::tipos_de_self
+// Esperado: E0382 (`into_square(self)` consumiu rect3)
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn into_square(self) -> Rectangle {
+        let lado = self.width.max(self.height);
+        Rectangle {
+            width: lado,
+            height: lado,
+        }
+    }
+}
+
+fn main() {
+    let rect3 = Rectangle {
+        width: 10,
+        height: 20,
+    };
+    let square = rect3.into_square();
+    println!("{:?}", square);
+    println!("{:?}", rect3);
+}
@@ -0,0 +1,10 @@
+// Origem: enums::option_enum::option_precisa_tratamento
+// Esperado: E0277 (cannot add `Option<i8>` to `i8`)
+
+fn main() {
+    let x: i8 = 5;
+    let y: Option<i8> = Some(5);
+
+    let sum = x + y;
+    println!("{sum}");
+}
@@ -0,0 +1,12 @@
+// Origem: ownership::o_que_e::ownership_com_funcao e docs/05-ownership.md
+// Esperado: E0382 (o valor foi movido para a função)
+
+fn main() {
+    let nome = String::from("Rustacean");
+    imprime_nome(nome);
+    println!("{}", nome);
+}
+
+fn imprime_nome(s: String) {
+    println!("Nome recebido: {}", s);
+}
@@ -0,0 +1,9 @@
+// Origem: ownership::o_que_e::ownership_basico e docs/05-ownership.md
+// Esperado: E0382 (borrow of moved value: `s1`)
+
+fn main() {
+    let s1 = String::from("olá");
+    let s2 = s1;
+    println!("{}", s1);
+    println!("{}", s2);
+}
@@ -0,0 +1,13 @@
+// Origem: ownership::slice::exemplo_bloqueio
+// Esperado: E0502 (a slice ainda empresta `s` quando o push_str tenta mutar)
+//
+// No exemplo `s` nem é `mut`; aqui ele é, para sobrar só o erro da slice.
+
+fn main() {
+    let mut s = String::from("hello");
+
+    let slice: &str = &s[..];
+    s.push_str(" world");
+
+    println!("Primeira palavra (slice): {}", slice);
+}
@@ -0,0 +1,24 @@
+// Origem: structs::definindo_structs (struct update syntax com `..user1`)
+// Esperado: E0382 (username foi movido para user2)
+
+struct User {
+    active: bool,
+    username: String,
+    email: String,
+}
+
+fn main() {
+    let user1 = User {
+        active: true,
+        username: String::from("someusername123"),
+        email: String::from("someone@example.com"),
+    };
+
+    let user2 = User {
+        email: String::from("novo@email.com"),
+        ..user1
+    };
+
+    println!("{} {} {}", user2.active, user2.email, user2.username);
+    println!("{}", user1.username);
+}
@@ -0,0 +1,15 @@
+// Origem: structs::definindo_structs::ownership_em_structs
+// Esperado: E0106 (campo `&str` sem lifetime)
+
+struct UserRef {
+    username: &str,
+    email: &str,
+}
+
+fn main() {
+    let user = UserRef {
+        username: "alguem",
+        email: "alguem@example.com",
+    };
+    println!("{} {}", user.username, user.email);
+}
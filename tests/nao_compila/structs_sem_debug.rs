@@ -0,0 +1,15 @@
+// Origem: structs::exemplo_retangulo::debug_trait
+// Esperado: E0277 (`{:?}` precisa de `Debug`)
+
+struct RectangleSemDebug {
+    width: u32,
+    height: u32,
+}
+
+fn main() {
+    let rect_sem = RectangleSemDebug {
+        width: 10,
+        height: 20,
+    };
+    println!("{:?}", rect_sem);
+}
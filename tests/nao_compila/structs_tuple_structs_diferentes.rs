@@ -0,0 +1,14 @@
+// Origem: structs::definindo_structs::tuple_structs
+// Esperado: E0308 (Color e Point têm os mesmos campos, mas são tipos diferentes)
+
+struct Color(i32, i32, i32);
+struct Point(i32, i32, i32);
+
+fn move_point(p: Point) {
+    println!("{} {} {}", p.0, p.1, p.2);
+}
+
+fn main() {
+    let black = Color(0, 0, 0);
+    move_point(black);
+}
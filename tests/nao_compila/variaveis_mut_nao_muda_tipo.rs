@@ -0,0 +1,8 @@
+// Origem: conceitos_comuns::variaveis_e_mutabilidade::shadowing_muda_tipo
+// Esperado: E0308 (mismatched types: `mut` não troca o tipo, só o valor)
+
+fn main() {
+    let mut spaces = "   ";
+    spaces = spaces.len();
+    println!("spaces: {spaces}");
+}
@@ -0,0 +1,9 @@
+// Origem: conceitos_comuns::variaveis_e_mutabilidade::imutavel_e_mutavel
+// Esperado: E0384 (cannot assign twice to immutable variable)
+
+fn main() {
+    let x = 5;
+    println!("The value of x is: {x}");
+    x = 6;
+    println!("The value of x is: {x}");
+}
@@ -0,0 +1,9 @@
+// Origem: conceitos_comuns::variaveis_e_mutabilidade::valor_depois
+// Esperado: E0381 (usa `x` antes de ele receber um valor)
+
+fn main() {
+    let x: i32;
+    println!("The value of x is: {x}");
+    x = 5;
+    println!("The value of x is: {x}");
+}
@@ -0,0 +1,167 @@
+// ============================================================================
+// OWNERSHIP: as ferramentas do capítulo 05 (tabela de traços e rastreio)
+// ============================================================================
+//
+// A tabela de `ownership::tracos` tem de cobrir os tipos públicos do
+// projeto (`pub` e `pub(crate)`; os privados ficam de fora). Como não dá
+// para listar os tipos de um crate em Rust, o teste lê os fontes de `src/` e
+// compara com a tabela: um tipo público novo sem linha na tabela (ou uma
+// linha de um tipo que sumiu) quebra o teste.
+//
+// O `Tracked` é conferido pelo trace que ele imprime: a ordem dos eventos de
+// move, clone e drop é a que o compilador decide.
+//
+// ============================================================================
+
+use std::{fs, path::Path};
+
+use resolution::ownership::rastreio::{self, Tracked};
+use resolution::ownership::tracos;
+use resolution::saida;
+
+/// `arquivo::Tipo` de cada `pub struct`/`pub enum` (e `pub(crate)`) em `src/`
+fn tipos_declarados(dir: &Path, achados: &mut Vec<String>) {
+    for entrada in fs::read_dir(dir).expect("diretório ilegível") {
+        let caminho = entrada.expect("entrada ilegível").path();
+        if caminho.is_dir() {
+            tipos_declarados(&caminho, achados);
+            continue;
+        }
+        if caminho.extension().is_none_or(|e| e != "rs") {
+            continue;
+        }
+
+        let arquivo = caminho.file_stem().unwrap_or_default().to_string_lossy();
+        let fonte = fs::read_to_string(&caminho).expect("fonte ilegível");
+        for linha in fonte.lines() {
+            let resto = linha.trim_start();
+            let Some(resto) = resto
+                .strip_prefix("pub(crate) ")
+                .or_else(|| resto.strip_prefix("pub "))
+            else {
+                continue;
+            };
+            let Some(resto) = resto
+                .strip_prefix("struct ")
+                .or_else(|| resto.strip_prefix("enum "))
+            else {
+                continue;
+            };
+            let nome: String = resto
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            achados.push(format!("{arquivo}::{nome}"));
+        }
+    }
+}
+
+#[test]
+fn tabela_de_tracos_tem_os_tipos_publicos_do_projeto() {
+    let mut declarados = Vec::new();
+    tipos_declarados(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut declarados,
+    );
+    declarados.sort();
+
+    // `rastreio::Tracked<String>` conta como `rastreio::Tracked`
+    let mut na_tabela: Vec<_> = tracos::do_projeto()
+        .iter()
+        .map(|t| t.nome.split('<').next().unwrap_or(t.nome).to_string())
+        .collect();
+    na_tabela.sort();
+
+    let faltando: Vec<_> = declarados
+        .iter()
+        .filter(|t| !na_tabela.contains(t))
+        .collect();
+    let sobrando: Vec<_> = na_tabela
+        .iter()
+        .filter(|t| !declarados.contains(t))
+        .collect();
+    assert!(
+        faltando.is_empty() && sobrando.is_empty(),
+        "tracos::do_projeto desatualizado\nfaltando: {faltando:?}\n\
+         sobrando: {sobrando:?}"
+    );
+}
+
+#[test]
+fn tracos_vem_do_compilador() {
+    let std = tracos::da_std();
+    let tipo = |nome: &str| *std.iter().find(|t| t.nome == nome).unwrap();
+
+    assert!(tipo("i32").copy && !tipo("String").copy);
+    assert!(tipo("String").clone && tipo("String").drop);
+    assert!(!tipo("&mut String").clone);
+    assert!(!tipo("str").sized && !tipo("[i32]").sized);
+    assert!(!tipo("Rc<i32>").send && !tipo("Cell<i32>").sync);
+
+    // Quem tem algo a liberar no drop nunca é Copy, e todo Copy é Clone
+    for t in std.iter().chain(&tracos::do_projeto()) {
+        assert!(!(t.copy && t.drop), "{} é Copy e tem drop", t.nome);
+        assert!(!t.copy || t.clone, "{} é Copy sem Clone", t.nome);
+    }
+}
+
+/// Recebe o valor (move) e o descarta no fim do próprio escopo
+fn consome(t: Tracked<String>) {
+    rastreio::escopo("consome", move || {
+        let _t = t;
+    })
+}
+
+fn exemplo() -> String {
+    saida::capturar(|| {
+        rastreio::escopo("exemplo", || {
+            let s = Tracked::new("s", String::from("oi"));
+            consome(s.mover("t"));
+
+            let a = Tracked::new("a", String::from("x"));
+            let _b = a.clone();
+        })
+    })
+}
+
+/// Os ids (`#17`) de cada linha, na ordem em que aparecem
+fn ids(trace: &str) -> Vec<u64> {
+    trace
+        .split('#')
+        .skip(1)
+        .map(|resto| {
+            let digitos: String =
+                resto.chars().take_while(char::is_ascii_digit).collect();
+            digitos.parse().unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn tracked_mostra_move_clone_e_drop_na_ordem() {
+    let trace = exemplo();
+
+    // Os ids dependem do que já rodou: o primeiro valor é o `s`
+    let s = ids(&trace)[0];
+    let (a, b) = (s + 1, s + 2);
+    let esperado = format!(
+        "\
+┌ exemplo
+│ #{s} s = \"oi\"
+│ #{s} s → t (move)
+│ ┌ consome
+│ │ #{s} t drop
+│ └ consome
+│ #{a} a = \"x\"
+│ #{b} a′ = clone de #{a} a
+│ #{b} a′ drop
+│ #{a} a drop
+└ exemplo
+"
+    );
+    assert_eq!(trace, esperado);
+
+    // Outro escopo de fora não reaproveita os ids
+    let outro = exemplo();
+    assert!(ids(&outro).iter().all(|id| !ids(&trace).contains(id)));
+}
@@ -0,0 +1,120 @@
+// ============================================================================
+// SNAPSHOTS: a saída de cada capítulo comparada com `tests/snapshots/*.txt`
+// ============================================================================
+//
+// Cada capítulo roda sem interação (`Roteiro` vazio = respostas padrão) e com
+// a saída capturada. Endereços de memória (`{:p}`) mudam a cada execução, então
+// viram `0x<endereço>` antes da comparação.
+//
+// Quando uma mudança na saída é intencional, regrave os snapshots com:
+//
+//   BLESS=1 cargo test --test snapshots
+//
+// ============================================================================
+
+use std::{env, fs, io, path::PathBuf};
+
+use resolution::capitulos::{self, CAPITULOS, Capitulo};
+use resolution::entrada::{self, Roteiro};
+use resolution::saida;
+
+#[test]
+fn capitulos_batem_com_os_snapshots() {
+    let regravar = env::var_os("BLESS").is_some();
+    let mut divergentes = Vec::new();
+
+    for capitulo in CAPITULOS {
+        let obtido = normalizar(&rodar(capitulo));
+        let caminho = caminho_snapshot(capitulo);
+
+        if regravar {
+            fs::write(&caminho, &obtido).expect("falha ao gravar snapshot");
+            continue;
+        }
+
+        let esperado = match fs::read_to_string(&caminho) {
+            Ok(esperado) => esperado,
+            Err(erro) if erro.kind() == io::ErrorKind::NotFound => {
+                divergentes.push(format!(
+                    "{}: snapshot faltando ({}), rode com BLESS=1",
+                    capitulo.modulo,
+                    caminho.display()
+                ));
+                continue;
+            }
+            Err(erro) => panic!("falha ao ler {}: {erro}", caminho.display()),
+        };
+        if let Some(diferenca) = primeira_diferenca(&esperado, &obtido) {
+            divergentes.push(format!("{}: {diferenca}", capitulo.modulo));
+        }
+    }
+
+    assert!(
+        divergentes.is_empty(),
+        "saída diferente do snapshot (rode com BLESS=1 se foi intencional):\n{}",
+        divergentes.join("\n")
+    );
+}
+
+fn rodar(capitulo: &Capitulo) -> String {
+    entrada::com_entrada(Roteiro::default(), || {
+        saida::capturar(|| capitulos::executar_secoes(capitulo.secoes))
+    })
+}
+
+fn caminho_snapshot(capitulo: &Capitulo) -> PathBuf {
+    let nome = capitulo.modulo.replace("::", "__");
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{nome}.txt"))
+}
+
+/// Troca endereços como `0x7ffd5a3c1b2c` por `0x<endereço>`
+fn normalizar(texto: &str) -> String {
+    let mut resultado = String::with_capacity(texto.len());
+    let mut resto = texto;
+
+    while let Some(inicio) = resto.find("0x") {
+        let depois = &resto[inicio + 2..];
+        let digitos = depois
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(depois.len());
+
+        resultado.push_str(&resto[..inicio]);
+        if digitos > 0 {
+            resultado.push_str("0x<endereço>");
+        } else {
+            resultado.push_str("0x");
+        }
+        resto = &depois[digitos..];
+    }
+
+    resultado.push_str(resto);
+    resultado
+}
+
+/// Descreve a primeira linha diferente, ou `None` se os textos são iguais
+fn primeira_diferenca(esperado: &str, obtido: &str) -> Option<String> {
+    if esperado == obtido {
+        return None;
+    }
+
+    let mut esperadas = esperado.lines();
+    let mut obtidas = obtido.lines();
+
+    for linha in 1.. {
+        match (esperadas.next(), obtidas.next()) {
+            (Some(e), Some(o)) if e == o => continue,
+            (None, None) => break,
+            (e, o) => {
+                return Some(format!(
+                    "linha {linha}: esperado {:?}, obtido {:?}",
+                    e.unwrap_or("<fim>"),
+                    o.unwrap_or("<fim>")
+                ));
+            }
+        }
+    }
+
+    Some("diferença no fim do arquivo (quebra de linha)".to_string())
+}